@@ -93,6 +93,11 @@ export async function accessReadOnlyMethods(): Promise<void> {
     const onChainNotarization = await notarizationClientReadOnly
         .getNotarizationById(dynamicNotarization.id);
     console.log("📦 Complete dynamic OnChainNotarization:", onChainNotarization);
+    console.log("📅 Created at (Date):", onChainNotarization.createdAtDate().toISOString());
+    assert(
+        onChainNotarization.createdAtDate().getTime() === Number(createdAt),
+        "createdAtDate must match the createdAt timestamp in milliseconds",
+    );
 
     // Update the state to demonstrate version tracking
     console.log("\n🔄 Updating state to demonstrate version tracking...");
@@ -170,8 +175,32 @@ export async function accessReadOnlyMethods(): Promise<void> {
     assert(!lockedDestroyAllowed, "Destroying a delete-locked locked Notarization must be forbidden");
     console.log("🔐 Lock metadata present:", lockedLockMetadata !== undefined);
     assert(lockedLockMetadata !== undefined, "A locked Notarization must have lock metadata");
+
+    const remainingDeleteLock = await notarizationClientReadOnly
+        .remainingDeleteLock(lockedNotarization.id);
+    const remainingTransferLock = await notarizationClientReadOnly
+        .remainingTransferLock(lockedNotarization.id);
+    console.log("⏳ Delete lock remaining seconds:", remainingDeleteLock);
+    assert(
+        remainingDeleteLock !== undefined && remainingDeleteLock > 0n,
+        "The delete lock of the locked Notarization must not have expired yet",
+    );
+    console.log("⏳ Transfer lock remaining seconds:", remainingTransferLock);
+    assert(remainingTransferLock === undefined, "An 'UntilDestroyed' transfer lock has no remaining time");
     console.log("📦 Complete locked OnChainNotarization:", lockedOnChainNotarization);
 
+    // The same information in a single call, resolved against a single clock reading
+    const lockedLockStatus = await notarizationClientReadOnly
+        .lockStatus(lockedNotarization.id);
+    console.log("🧾 Lock status (single call):", lockedLockStatus);
+    assert(
+        lockedLockStatus.updateLocked === lockedUpdateLocked
+            && lockedLockStatus.transferLocked === lockedTransferLocked
+            && lockedLockStatus.destroyAllowed === lockedDestroyAllowed,
+        "The lock status must match the individual lock checks",
+    );
+    assert(lockedLockStatus.lockMetadata !== undefined, "A locked Notarization must have lock metadata");
+
     // Compare methods between dynamic and locked
     console.log("\n📊 Comparison Summary:");
     console.log("┌─────────────────────┬─────────────┬─────────────┐");
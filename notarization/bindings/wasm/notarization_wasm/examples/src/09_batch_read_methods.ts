@@ -0,0 +1,68 @@
+// Copyright 2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+import { TimeLock } from "@iota/notarization/node";
+import { strict as assert } from "assert";
+import { getFundedClient } from "./util";
+
+/** Demonstrates reading several notarizations with a single call. */
+export async function batchReadMethods(): Promise<void> {
+    console.log("Demonstrating batch read methods for notarization inspection");
+
+    const notarizationClient = await getFundedClient();
+
+    const { output: dynamicNotarization } = await notarizationClient
+        .createDynamic()
+        .withStringState("Dynamic document content", undefined)
+        .withImmutableDescription("Dynamic document")
+        .finish()
+        .buildAndExecute(notarizationClient);
+    console.log("✅ Created dynamic notarization:", dynamicNotarization.id);
+
+    const { output: lockedNotarization } = await notarizationClient
+        .createLocked()
+        .withStringState("Locked document content", undefined)
+        .withDeleteLock(TimeLock.withNone())
+        .finish()
+        .buildAndExecute(notarizationClient);
+    console.log("✅ Created locked notarization:", lockedNotarization.id);
+
+    const ids = [dynamicNotarization.id, lockedNotarization.id];
+    const notarizationClientReadOnly = notarizationClient.readOnly();
+
+    // 1. Get both notarizations at once
+    const notarizations = await notarizationClientReadOnly.getNotarizationsByIds(ids);
+    console.log("📦 Notarizations:", notarizations);
+    assert(notarizations.length === 2, "one notarization per ID must be returned");
+    assert(notarizations[0].id === dynamicNotarization.id, "notarizations must follow the order of the IDs");
+    assert(notarizations[1].id === lockedNotarization.id, "notarizations must follow the order of the IDs");
+
+    const unknownId = "0x" + "ab".repeat(32);
+    const withUnknown = await notarizationClientReadOnly.getNotarizationsByIds([unknownId, lockedNotarization.id]);
+    assert(withUnknown[0] === undefined, "unknown objects must be returned as undefined");
+    assert(withUnknown[1].id === lockedNotarization.id, "known objects must still be returned");
+
+    // 2. Get both lock states at once
+    const lockStates = await notarizationClientReadOnly.lockStates(ids);
+    console.log("🔒 Lock states:", lockStates);
+    assert(!lockStates[0].updateLocked, "a dynamic Notarization must not be update locked");
+    assert(!lockStates[0].transferLocked, "a dynamic Notarization without transfer lock must not be transfer locked");
+    assert(lockStates[0].destroyAllowed, "destroying a dynamic Notarization must be allowed");
+    assert(lockStates[1].updateLocked, "a locked Notarization must be update locked");
+    assert(lockStates[1].transferLocked, "a locked Notarization must be transfer locked");
+    assert(lockStates[1].destroyAllowed, "destroying a locked Notarization without delete lock must be allowed");
+
+    // 3. Get both descriptions at once
+    const descriptions = await notarizationClientReadOnly.descriptions(ids);
+    console.log("📝 Descriptions:", descriptions);
+    assert(descriptions[0] === "Dynamic document", "the description of the dynamic Notarization must be returned");
+    assert(descriptions[1] === undefined, "a Notarization without description must yield undefined");
+
+    // 4. Invalid IDs are reported with their position
+    try {
+        await notarizationClientReadOnly.descriptions([dynamicNotarization.id, "not-an-id"]);
+        assert.fail("an invalid ID must be rejected");
+    } catch (error) {
+        console.log("❌ Invalid ID rejected as expected:", error);
+    }
+}
@@ -9,6 +9,7 @@ import { updateState } from "./05_update_state";
 import { updateMetadata } from "./06_update_metadata";
 import { transferNotarization } from "./07_transfer_notarization";
 import { accessReadOnlyMethods } from "./08_access_read_only_methods";
+import { batchReadMethods } from "./09_batch_read_methods";
 import { notarizeUniversityDocument, notarizeUniversityTranscript, notarizeUniversityCertificate } from "./university_document_notarization";
 
 export async function main(example?: string) {
@@ -35,6 +36,8 @@ export async function main(example?: string) {
             return await transferNotarization();
         case "08_access_read_only_methods":
             return await accessReadOnlyMethods();
+        case "09_batch_read_methods":
+            return await batchReadMethods();
         case "university_degree":
             return await notarizeUniversityDocument();
         case "university_transcript":
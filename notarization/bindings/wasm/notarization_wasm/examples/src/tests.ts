@@ -9,6 +9,7 @@ import { updateState } from "./05_update_state";
 import { updateMetadata } from "./06_update_metadata";
 import { transferNotarization } from "./07_transfer_notarization";
 import { accessReadOnlyMethods } from "./08_access_read_only_methods";
+import { batchReadMethods } from "./09_batch_read_methods";
 
 import { afterEach } from "mocha";
 
@@ -43,4 +44,7 @@ describe("Test node examples", function() {
     it("Should access ReadOnlyMethods", async () => {
         await accessReadOnlyMethods();
     });
+    it("Should access batch read methods", async () => {
+        await batchReadMethods();
+    });
 });
@@ -4,11 +4,12 @@
 use iota_interaction_ts::bindings::{WasmIotaTransactionBlockEffects, WasmIotaTransactionBlockEvents};
 use iota_interaction_ts::core_client::WasmCoreClientReadOnly;
 use iota_interaction_ts::wasm_error::Result;
+use js_sys::Date;
 use notarization::core::builder::{Dynamic, Locked};
 use notarization::core::transactions::{
     CreateNotarization, DestroyNotarization, TransferNotarization, UpdateMetadata, UpdateState,
 };
-use notarization::core::types::OnChainNotarization;
+use notarization::core::types::{NotarizationPage, OnChainNotarization};
 use product_common::bindings::utils::{
     apply_with_events, build_programmable_transaction, parse_wasm_iota_address, parse_wasm_object_id,
 };
@@ -16,7 +17,10 @@ use product_common::bindings::{WasmIotaAddress, WasmObjectID};
 use wasm_bindgen::prelude::*;
 
 use crate::wasm_notarization_builder::{WasmNotarizationBuilderDynamic, WasmNotarizationBuilderLocked};
-use crate::wasm_types::{WasmEmpty, WasmImmutableMetadata, WasmNotarizationMethod, WasmState};
+use crate::wasm_types::{
+    date_from_timestamp_ms, WasmEmpty, WasmImmutableMetadata, WasmNotarizationDestroyed, WasmNotarizationMethod,
+    WasmNotarizationTransferred, WasmState, WasmUpdatedState,
+};
 
 /// Represents an on-chain notarization object.
 ///
@@ -76,12 +80,30 @@ impl WasmOnChainNotarization {
     ///
     /// # Returns
     /// A `number` value representing the timestamp,
-    /// the time in seconds since the Unix epoch.
+    /// the time in milliseconds since the Unix epoch.
     #[wasm_bindgen(js_name = lastStateChangeAt, getter)]
     pub fn last_state_change_at(&self) -> u64 {
         self.0.last_state_change_at
     }
 
+    /// Retrieves the time of the last state change as a `Date`.
+    ///
+    /// # Returns
+    /// A `Date` representing the time of the last state change.
+    #[wasm_bindgen(js_name = lastStateChangeAtDate)]
+    pub fn last_state_change_at_date(&self) -> Date {
+        date_from_timestamp_ms(self.0.last_state_change_at)
+    }
+
+    /// Retrieves the creation time of the notarization as a `Date`.
+    ///
+    /// # Returns
+    /// A `Date` representing the time the notarization was created.
+    #[wasm_bindgen(js_name = createdAtDate)]
+    pub fn created_at_date(&self) -> Date {
+        date_from_timestamp_ms(self.0.immutable_metadata.created_at)
+    }
+
     /// Retrieves the count of state versions.
     ///
     /// # Returns
@@ -108,6 +130,47 @@ impl From<OnChainNotarization> for WasmOnChainNotarization {
     }
 }
 
+/// Represents a page of notarizations returned by a paginated query.
+#[wasm_bindgen(js_name = NotarizationPage, inspectable)]
+#[derive(Clone)]
+pub struct WasmNotarizationPage(pub(crate) NotarizationPage);
+
+#[wasm_bindgen(js_class = NotarizationPage)]
+impl WasmNotarizationPage {
+    /// Retrieves the notarizations of this page.
+    ///
+    /// # Returns
+    /// An array of `OnChainNotarization` objects.
+    #[wasm_bindgen(getter)]
+    pub fn data(&self) -> Vec<WasmOnChainNotarization> {
+        self.0.data.iter().cloned().map(Into::into).collect()
+    }
+
+    /// Retrieves the cursor pointing to the next page.
+    ///
+    /// # Returns
+    /// A hexadecimal string to pass as `cursor` to the next query, if existing.
+    #[wasm_bindgen(js_name = nextCursor, getter)]
+    pub fn next_cursor(&self) -> Option<String> {
+        self.0.next_cursor.map(|cursor| cursor.to_string())
+    }
+
+    /// Indicates whether there are more pages after this one.
+    ///
+    /// # Returns
+    /// `true` if there is a next page.
+    #[wasm_bindgen(js_name = hasNextPage, getter)]
+    pub fn has_next_page(&self) -> bool {
+        self.0.has_next_page
+    }
+}
+
+impl From<NotarizationPage> for WasmNotarizationPage {
+    fn from(page: NotarizationPage) -> Self {
+        WasmNotarizationPage(page)
+    }
+}
+
 /// Represents a transaction for creating locked notarization's.
 ///
 /// Locked notarization's cannot be modified after creation, ensuring data permanence.
@@ -247,13 +310,16 @@ impl WasmUpdateState {
     /// # Arguments
     /// * `effects` - The transaction block effects to apply.
     /// * `events` - The transaction block events to apply.
+    ///
+    /// # Returns
+    /// The new state version count and the checkpoint time of the update, if reported.
     #[wasm_bindgen(js_name = applyWithEvents)]
     pub async fn apply_with_events(
         self,
         wasm_effects: &WasmIotaTransactionBlockEffects,
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
-    ) -> Result<WasmEmpty> {
+    ) -> Result<WasmUpdatedState> {
         apply_with_events(self.0, wasm_effects, wasm_events, client).await
     }
 }
@@ -339,13 +405,16 @@ impl WasmDestroyNotarization {
     /// # Arguments
     /// * `effects` - The transaction block effects to apply.
     /// * `events` - The transaction block events to apply.
+    ///
+    /// # Returns
+    /// The ID of the destroyed notarization.
     #[wasm_bindgen(js_name = applyWithEvents)]
     pub async fn apply_with_events(
         self,
         wasm_effects: &WasmIotaTransactionBlockEffects,
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
-    ) -> Result<WasmEmpty> {
+    ) -> Result<WasmNotarizationDestroyed> {
         apply_with_events(self.0, wasm_effects, wasm_events, client).await
     }
 }
@@ -391,13 +460,16 @@ impl WasmTransferNotarization {
     /// # Arguments
     /// * `effects` - The transaction block effects to apply.
     /// * `events` - The transaction block events to apply.
+    ///
+    /// # Returns
+    /// The ID of the transferred notarization and its new owner.
     #[wasm_bindgen(js_name = applyWithEvents)]
     pub async fn apply_with_events(
         self,
         wasm_effects: &WasmIotaTransactionBlockEffects,
         wasm_events: &WasmIotaTransactionBlockEvents,
         client: &WasmCoreClientReadOnly,
-    ) -> Result<WasmEmpty> {
+    ) -> Result<WasmNotarizationTransferred> {
         apply_with_events(self.0, wasm_effects, wasm_events, client).await
     }
 }
@@ -1,7 +1,10 @@
 // Copyright 2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_interaction_ts::wasm_error::Result;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use iota_interaction_ts::wasm_error::{wasm_error, Result, WasmResult};
 use js_sys::Uint8Array;
 use notarization::core::builder::{Dynamic, Locked, NotarizationBuilder};
 use product_common::bindings::transaction::WasmTransactionBuilder;
@@ -47,6 +50,20 @@ impl WasmNotarizationBuilderLocked {
         self.0.with_string_state(data, metadata).into()
     }
 
+    /// Adds a state to the notarization using the canonical JSON encoding of a value.
+    ///
+    /// The object keys are sorted, so equal values always produce the same state.
+    /// Unlike the other `with*` methods, this method throws if `value` cannot be encoded as JSON.
+    ///
+    /// # Arguments
+    /// * `value` - The value to notarize.
+    /// * `metadata` - Optional metadata associated with the state.
+    #[wasm_bindgen(js_name = withJsonState)]
+    pub fn with_json_state(self, value: JsValue, metadata: Option<String>) -> Result<Self> {
+        let value = json_value(value)?;
+        Ok(self.0.with_json_state(&value, metadata).map_err(wasm_error)?.into())
+    }
+
     /// Adds an immutable description to the notarization.
     ///
     /// # Arguments
@@ -80,6 +97,21 @@ impl WasmNotarizationBuilderLocked {
         self.0.with_delete_lock(lock.0).into()
     }
 
+    /// Allows destroying the notarization once a number of seconds has passed.
+    ///
+    /// The seconds are counted from the on-chain clock time at which the transaction is built,
+    /// so the result does not depend on the local system time. The lock is stored as an
+    /// `UnlockAt` delete lock.
+    ///
+    /// # Arguments
+    /// * `seconds` - The number of seconds after which the notarization can be destroyed.
+    #[wasm_bindgen(js_name = withDeleteLockAfter)]
+    pub fn with_delete_lock_after(self, seconds: u32) -> Self {
+        self.0
+            .with_delete_lock_after(Duration::from_secs(seconds.into()))
+            .into()
+    }
+
     /// Finalizes the notarization builder and returns a transaction builder
     /// that can be used to build and execute the final transaction on the ledger.
     ///
@@ -127,6 +159,20 @@ impl WasmNotarizationBuilderDynamic {
         self.0.with_string_state(data, metadata).into()
     }
 
+    /// Adds a state to the notarization using the canonical JSON encoding of a value.
+    ///
+    /// The object keys are sorted, so equal values always produce the same state.
+    /// Unlike the other `with*` methods, this method throws if `value` cannot be encoded as JSON.
+    ///
+    /// # Arguments
+    /// * `value` - The value to notarize.
+    /// * `metadata` - Optional metadata associated with the state.
+    #[wasm_bindgen(js_name = withJsonState)]
+    pub fn with_json_state(self, value: JsValue, metadata: Option<String>) -> Result<Self> {
+        let value = json_value(value)?;
+        Ok(self.0.with_json_state(&value, metadata).map_err(wasm_error)?.into())
+    }
+
     /// Adds an immutable description to the notarization.
     ///
     /// # Arguments
@@ -160,6 +206,21 @@ impl WasmNotarizationBuilderDynamic {
         self.0.with_transfer_lock(lock.0).into()
     }
 
+    /// Locks transfers of the notarization until a number of seconds has passed.
+    ///
+    /// The seconds are counted from the on-chain clock time at which the transaction is built,
+    /// so the result does not depend on the local system time. The lock is stored as an
+    /// `UnlockAt` transfer lock.
+    ///
+    /// # Arguments
+    /// * `seconds` - The number of seconds after which the notarization can be transferred.
+    #[wasm_bindgen(js_name = withTransferLockAfter)]
+    pub fn with_transfer_lock_after(self, seconds: u32) -> Self {
+        self.0
+            .with_transfer_lock_after(Duration::from_secs(seconds.into()))
+            .into()
+    }
+
     /// Finalizes the notarization builder and returns a transaction builder
     /// that can be used to build and execute the final transaction on the ledger.
     ///
@@ -171,3 +232,10 @@ impl WasmNotarizationBuilderDynamic {
         Ok(WasmTransactionBuilder::new(js_value.unchecked_into()))
     }
 }
+
+/// Converts a JS value into a JSON value.
+fn json_value(value: JsValue) -> Result<serde_json::Value> {
+    serde_wasm_bindgen::from_value(value)
+        .map_err(|e| anyhow!("value is not representable as JSON: {e}"))
+        .wasm_result()
+}
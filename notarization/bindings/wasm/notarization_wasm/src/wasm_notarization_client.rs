@@ -3,6 +3,7 @@
 
 use iota_interaction_ts::bindings::{WasmIotaClient, WasmPublicKey, WasmTransactionSigner};
 use iota_interaction_ts::wasm_error::{Result, WasmResult};
+use js_sys::Uint8Array;
 use notarization::NotarizationClient;
 use product_common::bindings::transaction::WasmTransactionBuilder;
 use product_common::bindings::utils::{into_transaction_builder, parse_wasm_iota_address, parse_wasm_object_id};
@@ -154,6 +155,76 @@ impl WasmNotarizationClient {
         Ok(into_transaction_builder(WasmUpdateState(tx)))
     }
 
+    /// Creates a transaction to update the state of a dynamic notarization with text data.
+    ///
+    /// # Arguments
+    /// * `text` - The new text data.
+    /// * `metadata` - Optional metadata for the new state.
+    /// * `object_id` - The ID of the dynamic notarization object.
+    ///
+    /// # Returns
+    /// A `TransactionBuilder` to build and execute the transaction.
+    #[wasm_bindgen(js_name = updateStateString)]
+    pub fn update_state_string(
+        &self,
+        text: String,
+        metadata: Option<String>,
+        object_id: WasmObjectID,
+    ) -> Result<WasmTransactionBuilder> {
+        let obj_id = parse_wasm_object_id(&object_id)?;
+        let tx = self.0.update_state_string(text, metadata, obj_id).into_inner();
+        Ok(into_transaction_builder(WasmUpdateState(tx)))
+    }
+
+    /// Creates a transaction to update the state of a dynamic notarization with binary data.
+    ///
+    /// # Arguments
+    /// * `data` - The new byte array data.
+    /// * `metadata` - Optional metadata for the new state.
+    /// * `object_id` - The ID of the dynamic notarization object.
+    ///
+    /// # Returns
+    /// A `TransactionBuilder` to build and execute the transaction.
+    #[wasm_bindgen(js_name = updateStateBytes)]
+    pub fn update_state_bytes(
+        &self,
+        data: Uint8Array,
+        metadata: Option<String>,
+        object_id: WasmObjectID,
+    ) -> Result<WasmTransactionBuilder> {
+        let obj_id = parse_wasm_object_id(&object_id)?;
+        let tx = self.0.update_state_bytes(data.to_vec(), metadata, obj_id).into_inner();
+        Ok(into_transaction_builder(WasmUpdateState(tx)))
+    }
+
+    /// Creates a transaction to update the state of a dynamic notarization, unless it was
+    /// updated concurrently.
+    ///
+    /// Building the transaction fails if the state version count of the notarization is not
+    /// `expected_version` anymore.
+    ///
+    /// # Arguments
+    /// * `state` - The new state to update.
+    /// * `object_id` - The ID of the dynamic notarization object.
+    /// * `expected_version` - The state version count the update is based on.
+    ///
+    /// # Returns
+    /// A `TransactionBuilder` to build and execute the transaction.
+    #[wasm_bindgen(js_name = updateStateIfVersion)]
+    pub fn update_state_if_version(
+        &self,
+        state: WasmState,
+        object_id: WasmObjectID,
+        expected_version: u64,
+    ) -> Result<WasmTransactionBuilder> {
+        let obj_id = parse_wasm_object_id(&object_id)?;
+        let tx = self
+            .0
+            .update_state_if_version(state.0, obj_id, expected_version)
+            .into_inner();
+        Ok(into_transaction_builder(WasmUpdateState(tx)))
+    }
+
     /// Creates a transaction to update the metadata of a notarization.
     ///
     /// # Arguments
@@ -5,16 +5,23 @@ use std::str::FromStr;
 
 use anyhow::anyhow;
 use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::object::Owner;
 use iota_interaction_ts::bindings::WasmIotaClient;
 use iota_interaction_ts::wasm_error::{wasm_error, Result, WasmResult};
+use js_sys::Array;
+use notarization::core::types::{AvailableActions, OnChainNotarization};
 use notarization::NotarizationClientReadOnly;
-use product_common::bindings::utils::parse_wasm_object_id;
-use product_common::bindings::WasmObjectID;
+use product_common::bindings::utils::{parse_wasm_iota_address, parse_wasm_object_id};
+use product_common::bindings::{WasmIotaAddress, WasmObjectID};
 use product_common::core_client::CoreClientReadOnly;
+use product_common::network_name::NetworkName;
 use wasm_bindgen::prelude::*;
 
-use crate::wasm_notarization::WasmOnChainNotarization;
-use crate::wasm_types::{WasmLockMetadata, WasmNotarizationMethod, WasmState};
+use crate::wasm_notarization::{WasmNotarizationPage, WasmOnChainNotarization};
+use crate::wasm_types::{
+    WasmImmutableMetadata, WasmLockMetadata, WasmLockState, WasmLockStatus, WasmNotarizationMethod, WasmRawState,
+    WasmState,
+};
 
 /// A client to interact with Notarization objects on the IOTA ledger.
 ///
@@ -64,6 +71,98 @@ impl WasmNotarizationClientReadOnly {
         Ok(WasmNotarizationClientReadOnly(inner_client))
     }
 
+    /// Creates a new instance of `NotarizationClientReadOnly`, falling back to a specific package ID
+    /// if the connected network is unknown to the internal package registry.
+    ///
+    /// # Arguments
+    /// * `iota_client` - The IOTA client used for interacting with the ledger.
+    /// * `iota_notarization_pkg_id` - The notarization package ID to use for unknown networks.
+    ///
+    /// # Returns
+    /// A new `NotarizationClientReadOnly` instance.
+    #[wasm_bindgen(js_name = createWithFallbackPkgId)]
+    pub async fn new_with_fallback_pkg_id(
+        iota_client: WasmIotaClient,
+        iota_notarization_pkg_id: String,
+    ) -> Result<WasmNotarizationClientReadOnly> {
+        let inner_client = NotarizationClientReadOnly::with_fallback_package_id(
+            iota_client,
+            ObjectID::from_str(&iota_notarization_pkg_id)
+                .map_err(|e| anyhow!("Could not parse iota_notarization_pkg_id: {}", e.to_string()))
+                .wasm_result()?,
+        )
+        .await
+        .map_err(wasm_error)?;
+        Ok(WasmNotarizationClientReadOnly(inner_client))
+    }
+
+    /// Creates a new instance of `NotarizationClientReadOnly` for a known network and package
+    /// without contacting the node, e.g. to build transactions without outbound RPC access.
+    ///
+    /// Read methods still require connectivity. The package ID is kept on the returned client
+    /// only and not registered for the network.
+    ///
+    /// # Arguments
+    /// * `iota_client` - The IOTA client used for interacting with the ledger; not contacted here.
+    /// * `chain_id` - The chain identifier of the network.
+    /// * `iota_notarization_pkg_id` - The notarization package ID.
+    ///
+    /// # Returns
+    /// A new `NotarizationClientReadOnly` instance.
+    #[wasm_bindgen(js_name = createOffline)]
+    pub fn new_offline(
+        iota_client: WasmIotaClient,
+        chain_id: String,
+        iota_notarization_pkg_id: String,
+    ) -> Result<WasmNotarizationClientReadOnly> {
+        let inner_client = NotarizationClientReadOnly::new_offline(
+            iota_client,
+            NetworkName::try_from(chain_id)
+                .map_err(|e| anyhow!("Invalid chain_id: {}", e.to_string()))
+                .wasm_result()?,
+            ObjectID::from_str(&iota_notarization_pkg_id)
+                .map_err(|e| anyhow!("Could not parse iota_notarization_pkg_id: {}", e.to_string()))
+                .wasm_result()?,
+        );
+        Ok(WasmNotarizationClientReadOnly(inner_client))
+    }
+
+    /// Uses a custom clock object instead of the system clock, e.g. on forked or custom networks
+    /// whose clock is shared at a different version.
+    ///
+    /// # Arguments
+    /// * `clock_id` - The ID of the shared clock object of the network.
+    ///
+    /// # Returns
+    /// A `NotarizationClientReadOnly` instance using the given clock.
+    #[wasm_bindgen(js_name = withClockObjectId)]
+    pub async fn with_clock_object_id(&self, clock_id: WasmObjectID) -> Result<WasmNotarizationClientReadOnly> {
+        let clock_id = parse_wasm_object_id(&clock_id)?;
+        let inner_client = self
+            .0
+            .clone()
+            .with_clock_object_id(clock_id)
+            .await
+            .map_err(wasm_error)?;
+        Ok(WasmNotarizationClientReadOnly(inner_client))
+    }
+
+    /// Uses `sender` as the sender of the transactions that read calls inspect, instead of the
+    /// zero address.
+    ///
+    /// # Arguments
+    /// * `sender` - The address to inspect read calls with.
+    ///
+    /// # Returns
+    /// A `NotarizationClientReadOnly` instance inspecting read calls with the given sender.
+    #[wasm_bindgen(js_name = withInspectionSender)]
+    pub fn with_inspection_sender(&self, sender: WasmIotaAddress) -> Result<WasmNotarizationClientReadOnly> {
+        let sender = parse_wasm_iota_address(&sender)?;
+        Ok(WasmNotarizationClientReadOnly(
+            self.0.clone().with_inspection_sender(sender),
+        ))
+    }
+
     /// Retrieves the package ID of the used notarization package.
     ///
     /// # Returns
@@ -86,6 +185,16 @@ impl WasmNotarizationClientReadOnly {
             .collect()
     }
 
+    /// Retrieves the ID of the original notarization package, which defines the types of all
+    /// notarization objects and events, including those created before a package upgrade.
+    ///
+    /// # Returns
+    /// A string representing the original package ID.
+    #[wasm_bindgen(js_name = originalPackageId)]
+    pub fn original_package_id(&self) -> String {
+        self.0.original_package_id().to_string()
+    }
+
     /// Retrieves the underlying IOTA client used by this client.
     ///
     /// # Returns
@@ -133,6 +242,53 @@ impl WasmNotarizationClientReadOnly {
             .map(Into::into)
     }
 
+    /// Retrieves all notarizations owned by an address.
+    ///
+    /// # Arguments
+    /// * `owner` - The address owning the notarizations.
+    ///
+    /// # Returns
+    /// An array of the owned `OnChainNotarization` objects.
+    #[wasm_bindgen(js_name = getNotarizationsOwnedBy)]
+    pub async fn get_notarizations_owned_by(&self, owner: WasmIotaAddress) -> Result<Vec<WasmOnChainNotarization>> {
+        let owner = parse_wasm_iota_address(&owner)?;
+        self.0
+            .get_notarizations_owned_by(owner)
+            .await
+            .map_err(wasm_error)
+            .wasm_result()
+            .map(|notarizations| notarizations.into_iter().map(Into::into).collect())
+    }
+
+    /// Retrieves one page of the notarizations owned by an address.
+    ///
+    /// # Arguments
+    /// * `owner` - The address owning the notarizations.
+    /// * `cursor` - The `nextCursor` of the previous page, or `undefined` to start from the first page.
+    /// * `limit` - The maximum number of notarizations in the page.
+    ///
+    /// # Returns
+    /// A `NotarizationPage` containing the notarizations and the cursor to the next page.
+    #[wasm_bindgen(js_name = getNotarizationsOwnedByPaginated)]
+    pub async fn get_notarizations_owned_by_paginated(
+        &self,
+        owner: WasmIotaAddress,
+        cursor: Option<String>,
+        limit: Option<usize>,
+    ) -> Result<WasmNotarizationPage> {
+        let owner = parse_wasm_iota_address(&owner)?;
+        let cursor = cursor
+            .map(|cursor| ObjectID::from_str(&cursor).map_err(|e| anyhow!("invalid cursor '{cursor}': {e}")))
+            .transpose()
+            .wasm_result()?;
+        self.0
+            .get_notarizations_owned_by_paginated(owner, cursor, limit)
+            .await
+            .map_err(wasm_error)
+            .wasm_result()
+            .map(Into::into)
+    }
+
     /// Retrieves the timestamp of the last state change for a notarization.
     ///
     /// # Arguments
@@ -270,6 +426,61 @@ impl WasmNotarizationClientReadOnly {
         Ok(state)
     }
 
+    /// Retrieves the metadata of the state of a notarization.
+    ///
+    /// Reads the whole notarization object, including the state data.
+    ///
+    /// # Arguments
+    /// * `notarized_object_id` - The ID of a notarization object.
+    ///
+    /// # Returns
+    /// The state metadata string, if existing.
+    #[wasm_bindgen(js_name = stateMetadata)]
+    pub async fn state_metadata(&self, notarized_object_id: WasmObjectID) -> Result<Option<String>> {
+        let notarized_object_id = parse_wasm_object_id(&notarized_object_id)?;
+        self.0
+            .state_metadata(notarized_object_id)
+            .await
+            .map_err(wasm_error)
+            .wasm_result()
+    }
+
+    /// Retrieves the raw state of a notarization, to decode states of custom types.
+    ///
+    /// The state is returned as BCS bytes, together with the Move type of its data; see
+    /// `RawState` for the layout.
+    ///
+    /// # Arguments
+    /// * `notarized_object_id` - The ID of a notarization object.
+    ///
+    /// # Returns
+    /// The `RawState` of the notarization.
+    #[wasm_bindgen(js_name = stateAs)]
+    pub async fn state_as(&self, notarized_object_id: WasmObjectID) -> Result<WasmRawState> {
+        let notarized_object_id = parse_wasm_object_id(&notarized_object_id)?;
+        let type_tag = self.0.state_type(notarized_object_id).await.map_err(wasm_error)?;
+        let bytes = self.0.state_raw(notarized_object_id).await.map_err(wasm_error)?;
+
+        Ok(WasmRawState {
+            bytes,
+            type_tag: type_tag.to_string(),
+        })
+    }
+
+    /// Retrieves the SHA-256 hash of the state data of a notarization object.
+    ///
+    /// # Arguments
+    /// * `notarized_object_id` - The ID of a notarization object.
+    ///
+    /// # Returns
+    /// The hash as a `0x` prefixed hex `string`.
+    #[wasm_bindgen(js_name = stateHash)]
+    pub async fn state_hash(&self, notarized_object_id: WasmObjectID) -> Result<String> {
+        let notarized_object_id = parse_wasm_object_id(&notarized_object_id)?;
+        let hash = self.0.state_hash(notarized_object_id).await.map_err(wasm_error)?;
+        Ok(prefix_hex::encode(hash))
+    }
+
     /// Checks if updates are locked for a notarization object.
     ///
     /// # Arguments
@@ -323,4 +534,274 @@ impl WasmNotarizationClientReadOnly {
             .map_err(wasm_error)
             .wasm_result()
     }
+
+    /// Indicates if an object currently exists.
+    ///
+    /// Only the existence of the object is checked, its content is not fetched.
+    ///
+    /// # Arguments
+    /// * `object_id` - The ID of the object.
+    ///
+    /// # Returns
+    /// `true` if the object exists.
+    #[wasm_bindgen]
+    pub async fn exists(&self, object_id: WasmObjectID) -> Result<bool> {
+        let object_id = parse_wasm_object_id(&object_id)?;
+        self.0.exists(object_id).await.map_err(wasm_error).wasm_result()
+    }
+
+    /// Indicates if an object was deleted, e.g. because the notarization was destroyed.
+    ///
+    /// Distinguishes deleted objects from objects that never existed, as far as the node still
+    /// knows about the deletion.
+    ///
+    /// # Arguments
+    /// * `object_id` - The ID of the object.
+    ///
+    /// # Returns
+    /// `true` if the object was deleted.
+    #[wasm_bindgen(js_name = isDestroyed)]
+    pub async fn is_destroyed(&self, object_id: WasmObjectID) -> Result<bool> {
+        let object_id = parse_wasm_object_id(&object_id)?;
+        self.0.is_destroyed(object_id).await.map_err(wasm_error).wasm_result()
+    }
+
+    /// Retrieves the current owner of a notarization object.
+    ///
+    /// # Arguments
+    /// * `notarized_object_id` - The ID of a notarization object.
+    ///
+    /// # Returns
+    /// The address of the owning account or object, `"shared"` for shared objects or
+    /// `"immutable"` for immutable objects.
+    #[wasm_bindgen]
+    pub async fn owner(&self, notarized_object_id: WasmObjectID) -> Result<String> {
+        let notarized_object_id = parse_wasm_object_id(&notarized_object_id)?;
+        let owner = self
+            .0
+            .owner(notarized_object_id)
+            .await
+            .map_err(wasm_error)
+            .wasm_result()?;
+
+        Ok(match owner {
+            Owner::AddressOwner(address) | Owner::ObjectOwner(address) => address.to_string(),
+            Owner::Shared { .. } => "shared".to_string(),
+            Owner::Immutable => "immutable".to_string(),
+        })
+    }
+
+    /// Retrieves the full Move type of a notarization object.
+    ///
+    /// # Arguments
+    /// * `notarized_object_id` - The ID of a notarization object.
+    ///
+    /// # Returns
+    /// The type string, e.g. `0x…::notarization::Notarization<0x1::string::String>`.
+    #[wasm_bindgen(js_name = objectType)]
+    pub async fn object_type(&self, notarized_object_id: WasmObjectID) -> Result<String> {
+        let notarized_object_id = parse_wasm_object_id(&notarized_object_id)?;
+        self.0
+            .object_type(notarized_object_id)
+            .await
+            .map_err(wasm_error)
+            .wasm_result()
+    }
+
+    /// Retrieves the immutable metadata of a notarization object.
+    ///
+    /// The creation timestamp, the description and the lock metadata are decoded from a single
+    /// object read.
+    ///
+    /// # Arguments
+    /// * `notarized_object_id` - The ID of a notarization object.
+    ///
+    /// # Returns
+    /// The `ImmutableMetadata` of the object.
+    #[wasm_bindgen(js_name = immutableMetadata)]
+    pub async fn immutable_metadata(&self, notarized_object_id: WasmObjectID) -> Result<WasmImmutableMetadata> {
+        let notarized_object_id = parse_wasm_object_id(&notarized_object_id)?;
+        self.0
+            .immutable_metadata(notarized_object_id)
+            .await
+            .map(WasmImmutableMetadata)
+            .map_err(wasm_error)
+            .wasm_result()
+    }
+
+    /// Retrieves the lock metadata of a notarization object together with its resolved lock flags.
+    ///
+    /// The object and the on-chain clock are read once, so the flags are consistent with each
+    /// other and with the returned lock metadata.
+    ///
+    /// # Arguments
+    /// * `notarized_object_id` - The ID of a notarization object.
+    ///
+    /// # Returns
+    /// The `LockStatus` of the object.
+    #[wasm_bindgen(js_name = lockStatus)]
+    pub async fn lock_status(&self, notarized_object_id: WasmObjectID) -> Result<WasmLockStatus> {
+        let notarized_object_id = parse_wasm_object_id(&notarized_object_id)?;
+        self.0
+            .lock_status(notarized_object_id)
+            .await
+            .map(WasmLockStatus::from)
+            .map_err(wasm_error)
+            .wasm_result()
+    }
+
+    /// Retrieves the number of seconds until the update lock of a notarization object is released.
+    ///
+    /// # Arguments
+    /// * `notarized_object_id` - The ID of a notarization object.
+    ///
+    /// # Returns
+    /// The remaining seconds for an `UnlockAt` lock (`0` once it expired), or `undefined` if the
+    /// object has no such lock (`None`, `UntilDestroyed` or no lock metadata at all).
+    #[wasm_bindgen(js_name = remainingUpdateLock)]
+    pub async fn remaining_update_lock(&self, notarized_object_id: WasmObjectID) -> Result<Option<u64>> {
+        let notarized_object_id = parse_wasm_object_id(&notarized_object_id)?;
+        self.0
+            .remaining_update_lock(notarized_object_id)
+            .await
+            .map_err(wasm_error)
+            .wasm_result()
+    }
+
+    /// Retrieves the number of seconds until the delete lock of a notarization object is released.
+    ///
+    /// # Arguments
+    /// * `notarized_object_id` - The ID of a notarization object.
+    ///
+    /// # Returns
+    /// The remaining seconds for an `UnlockAt` lock (`0` once it expired), or `undefined` if the
+    /// object has no such lock (`None`, `UntilDestroyed` or no lock metadata at all).
+    #[wasm_bindgen(js_name = remainingDeleteLock)]
+    pub async fn remaining_delete_lock(&self, notarized_object_id: WasmObjectID) -> Result<Option<u64>> {
+        let notarized_object_id = parse_wasm_object_id(&notarized_object_id)?;
+        self.0
+            .remaining_delete_lock(notarized_object_id)
+            .await
+            .map_err(wasm_error)
+            .wasm_result()
+    }
+
+    /// Retrieves the number of seconds until the transfer lock of a notarization object is released.
+    ///
+    /// # Arguments
+    /// * `notarized_object_id` - The ID of a notarization object.
+    ///
+    /// # Returns
+    /// The remaining seconds for an `UnlockAt` lock (`0` once it expired), or `undefined` if the
+    /// object has no such lock (`None`, `UntilDestroyed` or no lock metadata at all).
+    #[wasm_bindgen(js_name = remainingTransferLock)]
+    pub async fn remaining_transfer_lock(&self, notarized_object_id: WasmObjectID) -> Result<Option<u64>> {
+        let notarized_object_id = parse_wasm_object_id(&notarized_object_id)?;
+        self.0
+            .remaining_transfer_lock(notarized_object_id)
+            .await
+            .map_err(wasm_error)
+            .wasm_result()
+    }
+
+    /// Retrieves the [`OnChainNotarization`]s of several notarized objects.
+    ///
+    /// The objects are fetched in batches rather than one request per object.
+    ///
+    /// # Arguments
+    /// * `notarized_object_ids` - The IDs of the notarization objects.
+    ///
+    /// # Returns
+    /// An array containing the [`OnChainNotarization`] of each object, in the order of the given IDs,
+    /// or `undefined` for objects that do not exist (anymore).
+    #[wasm_bindgen(js_name = getNotarizationsByIds)]
+    pub async fn get_notarizations_by_ids(&self, notarized_object_ids: Vec<String>) -> Result<Array> {
+        let notarized_object_ids = parse_object_ids(&notarized_object_ids)?;
+        let notarizations = Array::new();
+        for notarization in self
+            .0
+            .get_notarizations_by_ids(&notarized_object_ids)
+            .await
+            .map_err(wasm_error)?
+        {
+            let notarization = notarization.map_or(JsValue::UNDEFINED, |notarization| {
+                WasmOnChainNotarization::from(notarization).into()
+            });
+            notarizations.push(&notarization);
+        }
+
+        Ok(notarizations)
+    }
+
+    /// Retrieves the resolved lock states of several notarized objects.
+    ///
+    /// The objects are fetched in batches and their locks are evaluated against a single reading
+    /// of the on-chain clock.
+    ///
+    /// # Arguments
+    /// * `notarized_object_ids` - The IDs of the notarization objects.
+    ///
+    /// # Returns
+    /// An array containing the `LockState` of each object, in the order of the given IDs.
+    /// Fails with an error naming the offending ID if any of the objects cannot be read.
+    #[wasm_bindgen(js_name = lockStates)]
+    pub async fn lock_states(&self, notarized_object_ids: Vec<String>) -> Result<Vec<WasmLockState>> {
+        let notarizations = self.existing_notarizations(&notarized_object_ids).await?;
+        let now = self.0.now_ts().await.map_err(wasm_error)?;
+
+        Ok(notarizations
+            .iter()
+            .map(|notarization| AvailableActions::from(&notarization.lock_status_at(now)).into())
+            .collect())
+    }
+
+    /// Retrieves the descriptions of several notarized objects.
+    ///
+    /// The objects are fetched in batches rather than one request per object.
+    ///
+    /// # Arguments
+    /// * `notarized_object_ids` - The IDs of the notarization objects.
+    ///
+    /// # Returns
+    /// An array containing the description of each object (or `undefined` if it has none),
+    /// in the order of the given IDs.
+    /// Fails with an error naming the offending ID if any of the objects cannot be read.
+    #[wasm_bindgen]
+    pub async fn descriptions(&self, notarized_object_ids: Vec<String>) -> Result<Array> {
+        let descriptions = Array::new();
+        for notarization in self.existing_notarizations(&notarized_object_ids).await? {
+            descriptions.push(&JsValue::from(notarization.immutable_metadata.description));
+        }
+
+        Ok(descriptions)
+    }
+}
+
+impl WasmNotarizationClientReadOnly {
+    /// Fetches the notarizations of all `ids` in batches, failing with an error naming the
+    /// first object that does not exist.
+    async fn existing_notarizations(&self, ids: &[String]) -> Result<Vec<OnChainNotarization>> {
+        let object_ids = parse_object_ids(ids)?;
+        let notarizations = self.0.get_notarizations_by_ids(&object_ids).await.map_err(wasm_error)?;
+
+        object_ids
+            .into_iter()
+            .zip(notarizations)
+            .enumerate()
+            .map(|(index, (object_id, notarization))| {
+                notarization
+                    .ok_or_else(|| {
+                        anyhow!("failed to read notarization {object_id} at index {index}: object not found")
+                    })
+                    .wasm_result()
+            })
+            .collect()
+    }
+}
+
+/// Parses all `ids`, failing with an error naming the first invalid ID.
+fn parse_object_ids(ids: &[String]) -> Result<Vec<ObjectID>> {
+    ids.iter()
+        .map(|id| parse_wasm_object_id(&JsValue::from_str(id).unchecked_into::<WasmObjectID>()))
+        .collect()
 }
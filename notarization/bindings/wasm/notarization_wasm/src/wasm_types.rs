@@ -1,8 +1,14 @@
 // Copyright 2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use js_sys::Uint8Array;
-use notarization::core::types::{Data, ImmutableMetadata, LockMetadata, NotarizationMethod, State};
+use anyhow::anyhow;
+use iota_interaction_ts::wasm_error::{wasm_error, Result, WasmResult};
+use js_sys::{Date, Uint8Array};
+use notarization::core::transactions::UpdatedState;
+use notarization::core::types::{
+    AvailableActions, Data, ImmutableMetadata, LockMetadata, LockStatus, NotarizationDestroyed, NotarizationMethod,
+    NotarizationTransferred, State,
+};
 use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
@@ -17,6 +23,82 @@ impl From<()> for WasmEmpty {
     }
 }
 
+/// Represents the outcome of a state update.
+#[wasm_bindgen(js_name = UpdatedState, inspectable)]
+#[derive(Debug, Clone, Copy)]
+pub struct WasmUpdatedState {
+    /// The state version count after the update.
+    #[wasm_bindgen(js_name = newVersion)]
+    pub new_version: u64,
+    /// The timestamp of the checkpoint that included the update, in milliseconds since the Unix
+    /// epoch, if reported by the node.
+    #[wasm_bindgen(js_name = timestampMs)]
+    pub timestamp_ms: Option<u64>,
+}
+
+impl From<UpdatedState> for WasmUpdatedState {
+    fn from(value: UpdatedState) -> Self {
+        WasmUpdatedState {
+            new_version: value.new_version,
+            timestamp_ms: value.timestamp_ms,
+        }
+    }
+}
+
+/// Represents the outcome of a transfer.
+#[wasm_bindgen(js_name = NotarizationTransferred, getter_with_clone, inspectable)]
+#[derive(Debug, Clone)]
+pub struct WasmNotarizationTransferred {
+    /// The ID of the transferred notarization.
+    #[wasm_bindgen(js_name = notarizationId)]
+    pub notarization_id: String,
+    /// The address of the new owner.
+    pub recipient: String,
+}
+
+impl From<NotarizationTransferred> for WasmNotarizationTransferred {
+    fn from(value: NotarizationTransferred) -> Self {
+        WasmNotarizationTransferred {
+            notarization_id: value.notarization_id.to_string(),
+            recipient: value.recipient.to_string(),
+        }
+    }
+}
+
+/// Represents the outcome of a destruction.
+#[wasm_bindgen(js_name = NotarizationDestroyed, getter_with_clone, inspectable)]
+#[derive(Debug, Clone)]
+pub struct WasmNotarizationDestroyed {
+    /// The ID of the destroyed notarization.
+    #[wasm_bindgen(js_name = notarizationId)]
+    pub notarization_id: String,
+}
+
+impl From<NotarizationDestroyed> for WasmNotarizationDestroyed {
+    fn from(value: NotarizationDestroyed) -> Self {
+        WasmNotarizationDestroyed {
+            notarization_id: value.notarization_id.to_string(),
+        }
+    }
+}
+
+/// The raw BCS encoded state of a notarization, along with the Move type of its data.
+///
+/// The bytes are the BCS encoding of the Move struct `State<D> { data: D, metadata: Option<String> }`:
+/// 1. `data`, encoded according to `typeTag` (e.g. a ULEB128 length followed by the bytes for `vector<u8>` and
+///    `0x1::string::String`, or the fields of a custom struct in declaration order),
+/// 2. `metadata`, encoded as `0x00` if absent, or `0x01` followed by a ULEB128 length and the UTF-8 bytes of the
+///    string.
+#[wasm_bindgen(js_name = RawState, getter_with_clone, inspectable)]
+#[derive(Debug, Clone)]
+pub struct WasmRawState {
+    /// The BCS encoded `State<D>`.
+    pub bytes: Vec<u8>,
+    /// The Move type `D` of the state data, e.g. `vector<u8>`.
+    #[wasm_bindgen(js_name = typeTag)]
+    pub type_tag: String,
+}
+
 /// Represents the different types of data that can be notarized.
 #[wasm_bindgen(js_name = Data, inspectable)]
 pub struct WasmData(pub(crate) Data);
@@ -58,6 +140,27 @@ impl WasmData {
             Data::Text(text) => text.clone().as_bytes().to_vec(),
         }
     }
+
+    /// Parses the data as JSON.
+    ///
+    /// Text is parsed as is; bytes are parsed if they contain UTF-8 encoded JSON. As `toJSON`
+    /// is used by `JSON.stringify`, a `Data` holding JSON is serialized as the JSON it contains.
+    ///
+    /// # Returns
+    /// The parsed JSON as a JS value (e.g. a plain object or an array).
+    ///
+    /// # Errors
+    /// Throws if the data is not valid JSON.
+    #[wasm_bindgen(js_name = toJSON)]
+    pub fn to_json(&self) -> Result<JsValue> {
+        self.0
+            .as_json_value()
+            .map_err(wasm_error)
+            .wasm_result()?
+            .serialize(&serde_wasm_bindgen::Serializer::json_compatible())
+            .map_err(|e| anyhow!("failed to convert JSON data: {e}"))
+            .wasm_result()
+    }
 }
 
 impl From<Data> for WasmData {
@@ -100,6 +203,18 @@ impl WasmState {
         self.0.metadata.clone()
     }
 
+    /// Retrieves the SHA-256 hash of the state data.
+    ///
+    /// The hash is computed over the raw bytes for byte data and over the UTF-8 bytes for
+    /// text data; the metadata is not part of the hash.
+    ///
+    /// # Returns
+    /// The hash as a `0x` prefixed hex `string`.
+    #[wasm_bindgen(js_name = contentHash, getter)]
+    pub fn content_hash(&self) -> String {
+        prefix_hex::encode(self.0.content_hash())
+    }
+
     /// Creates a new state from a string.
     ///
     /// Use this for text data like documents, JSON, or configuration.
@@ -158,6 +273,89 @@ pub struct WasmLockMetadata {
     pub transfer_lock: WasmTimeLock,
 }
 
+#[wasm_bindgen(js_class = LockMetadata)]
+impl WasmLockMetadata {
+    /// Checks if updates are locked at `now`, following the same rules as the Move contract.
+    ///
+    /// # Arguments
+    /// * `now` - The time to evaluate the locks at, in seconds since the Unix epoch.
+    #[wasm_bindgen(js_name = isUpdateLockedAt)]
+    pub fn is_update_locked_at(&self, now: u64) -> bool {
+        self.evaluate(now).is_update_locked
+    }
+
+    /// Checks if the delete lock is active at `now`.
+    ///
+    /// Unlike {@link LockMetadata.isDestroyAllowedAt}, this only considers the delete lock.
+    ///
+    /// # Arguments
+    /// * `now` - The time to evaluate the lock at, in seconds since the Unix epoch.
+    #[wasm_bindgen(js_name = isDeleteLockedAt)]
+    pub fn is_delete_locked_at(&self, now: u64) -> bool {
+        self.delete_lock.0.is_locked_at(now)
+    }
+
+    /// Checks if transfers are locked at `now`, following the same rules as the Move contract.
+    ///
+    /// # Arguments
+    /// * `now` - The time to evaluate the locks at, in seconds since the Unix epoch.
+    #[wasm_bindgen(js_name = isTransferLockedAt)]
+    pub fn is_transfer_locked_at(&self, now: u64) -> bool {
+        self.evaluate(now).is_transfer_locked
+    }
+
+    /// Checks if the notarization can be destroyed at `now`, following the same rules as the
+    /// Move contract: destruction is only prevented by `UnlockAt` locks that are still active.
+    ///
+    /// # Arguments
+    /// * `now` - The time to evaluate the locks at, in seconds since the Unix epoch.
+    #[wasm_bindgen(js_name = isDestroyAllowedAt)]
+    pub fn is_destroy_allowed_at(&self, now: u64) -> bool {
+        self.evaluate(now).is_destroy_allowed
+    }
+
+    /// Retrieves the number of seconds from `now` until the update lock is released.
+    ///
+    /// # Returns
+    /// The remaining seconds for an `UnlockAt` lock (`0` once it expired), or `undefined` for
+    /// locks that are not time based.
+    #[wasm_bindgen(js_name = remainingUpdateSeconds)]
+    pub fn remaining_update_seconds(&self, now: u64) -> Option<u64> {
+        self.update_lock.0.remaining_secs(now)
+    }
+
+    /// Retrieves the number of seconds from `now` until the delete lock is released.
+    ///
+    /// # Returns
+    /// The remaining seconds for an `UnlockAt` lock (`0` once it expired), or `undefined` for
+    /// locks that are not time based.
+    #[wasm_bindgen(js_name = remainingDeleteSeconds)]
+    pub fn remaining_delete_seconds(&self, now: u64) -> Option<u64> {
+        self.delete_lock.0.remaining_secs(now)
+    }
+
+    /// Retrieves the number of seconds from `now` until the transfer lock is released.
+    ///
+    /// # Returns
+    /// The remaining seconds for an `UnlockAt` lock (`0` once it expired), or `undefined` for
+    /// locks that are not time based.
+    #[wasm_bindgen(js_name = remainingTransferSeconds)]
+    pub fn remaining_transfer_seconds(&self, now: u64) -> Option<u64> {
+        self.transfer_lock.0.remaining_secs(now)
+    }
+}
+
+impl WasmLockMetadata {
+    fn evaluate(&self, now: u64) -> LockStatus {
+        LockMetadata {
+            update_lock: self.update_lock.0.clone(),
+            delete_lock: self.delete_lock.0.clone(),
+            transfer_lock: self.transfer_lock.0.clone(),
+        }
+        .evaluate(now)
+    }
+}
+
 impl From<LockMetadata> for WasmLockMetadata {
     fn from(value: LockMetadata) -> Self {
         WasmLockMetadata {
@@ -174,6 +372,60 @@ impl From<WasmLockMetadata> for LockMetadata {
     }
 }
 
+/// Represents the resolved lock state of a notarization.
+#[wasm_bindgen(js_name = LockState, inspectable)]
+#[derive(Debug, Clone, Copy)]
+pub struct WasmLockState {
+    /// Indicates whether updates are locked.
+    #[wasm_bindgen(js_name = updateLocked)]
+    pub update_locked: bool,
+    /// Indicates whether transfers are locked.
+    #[wasm_bindgen(js_name = transferLocked)]
+    pub transfer_locked: bool,
+    /// Indicates whether destruction is allowed.
+    #[wasm_bindgen(js_name = destroyAllowed)]
+    pub destroy_allowed: bool,
+}
+
+impl From<AvailableActions> for WasmLockState {
+    fn from(value: AvailableActions) -> Self {
+        WasmLockState {
+            update_locked: !value.can_update,
+            transfer_locked: !value.can_transfer,
+            destroy_allowed: value.can_destroy,
+        }
+    }
+}
+
+/// Represents the lock metadata of a notarization together with its resolved lock flags.
+#[wasm_bindgen(js_name = LockStatus, getter_with_clone, inspectable)]
+#[derive(Debug, Clone)]
+pub struct WasmLockStatus {
+    /// The lock metadata, if existing.
+    #[wasm_bindgen(js_name = lockMetadata)]
+    pub lock_metadata: Option<WasmLockMetadata>,
+    /// Indicates whether updates are locked.
+    #[wasm_bindgen(js_name = updateLocked)]
+    pub update_locked: bool,
+    /// Indicates whether transfers are locked.
+    #[wasm_bindgen(js_name = transferLocked)]
+    pub transfer_locked: bool,
+    /// Indicates whether destruction is allowed.
+    #[wasm_bindgen(js_name = destroyAllowed)]
+    pub destroy_allowed: bool,
+}
+
+impl From<LockStatus> for WasmLockStatus {
+    fn from(value: LockStatus) -> Self {
+        WasmLockStatus {
+            lock_metadata: value.lock_metadata.map(WasmLockMetadata::from),
+            update_locked: value.is_update_locked,
+            transfer_locked: value.is_transfer_locked,
+            destroy_allowed: value.is_destroy_allowed,
+        }
+    }
+}
+
 /// Represents immutable metadata of a notarization.
 #[wasm_bindgen(js_name = ImmutableMetadata, inspectable)]
 pub struct WasmImmutableMetadata(pub(crate) ImmutableMetadata);
@@ -183,12 +435,21 @@ impl WasmImmutableMetadata {
     /// Retrieves the timestamp when the notarization was created.
     ///
     /// # Returns
-    /// The timestamp as `number` value representing the seconds since the Unix epoch.
+    /// The timestamp as `number` value representing the milliseconds since the Unix epoch.
     #[wasm_bindgen(js_name = createdAt, getter)]
     pub fn created_at(&self) -> u64 {
         self.0.created_at
     }
 
+    /// Retrieves the time when the notarization was created as a `Date`.
+    ///
+    /// # Returns
+    /// A `Date` representing the creation time.
+    #[wasm_bindgen(js_name = createdAtDate)]
+    pub fn created_at_date(&self) -> Date {
+        date_from_timestamp_ms(self.0.created_at)
+    }
+
     /// Retrieves the description of the notarization.
     ///
     /// # Returns
@@ -238,3 +499,8 @@ impl From<WasmNotarizationMethod> for NotarizationMethod {
         }
     }
 }
+
+/// Converts an on-chain timestamp (milliseconds since the Unix epoch) into a JS `Date`.
+pub(crate) fn date_from_timestamp_ms(timestamp_ms: u64) -> Date {
+    Date::new(&JsValue::from_f64(timestamp_ms as f64))
+}
@@ -31,7 +31,7 @@ async fn main() -> Result<()> {
         .output;
 
     println!("✅ Simple dynamic notarization created!");
-    println!("ID: {:?}", simple_notarization.id);
+    println!("{simple_notarization}");
 
     // Calculate unlock time for transfer lock example
     let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
@@ -51,7 +51,7 @@ async fn main() -> Result<()> {
         .output;
 
     println!("✅ Transfer-locked dynamic notarization created!");
-    println!("ID: {:?}", locked_transfer_notarization.id);
+    println!("{locked_transfer_notarization}");
 
     // Verify both are dynamic notarizations
     assert_eq!(simple_notarization.method, NotarizationMethod::Dynamic);
@@ -3,6 +3,7 @@
 
 use anyhow::Result;
 use examples::get_funded_client;
+use notarization::client::{Confirmation, TransactionBuilderExt};
 use notarization::core::types::State;
 
 #[tokio::main]
@@ -22,6 +23,8 @@ async fn main() -> Result<()> {
         ))
         .with_immutable_description("Evolving document".to_string())
         .finish()
+        // Wait until the notarization can be read back before reading its state
+        .with_confirmation(Confirmation::Readable)
         .build_and_execute(&notarization_client)
         .await?
         .output
@@ -53,20 +56,19 @@ async fn main() -> Result<()> {
         );
 
         // Update the state
-        let _ = notarization_client
+        let version_count = notarization_client
             .update_state(new_state.clone(), *notarization_id.object_id())
+            .with_confirmation(Confirmation::Readable)
             .build_and_execute(&notarization_client)
-            .await?;
+            .await?
+            .output
+            .new_version;
 
         println!("✅ State update {i} completed");
 
         // Verify the update
         let current_state = notarization_client.state(*notarization_id.object_id()).await?;
 
-        let version_count = notarization_client
-            .state_version_count(*notarization_id.object_id())
-            .await?;
-
         println!("Updated content: {}", current_state.data.as_text()?);
         println!("Updated metadata: {:?}", current_state.metadata);
         println!("New version count: {version_count}");
@@ -185,6 +185,12 @@ async fn main() -> Result<()> {
     println!("🔐 Lock metadata present: {}", locked_lock_metadata.is_some());
     println!("📦 Complete locked OnChainNotarization:\n{whole_locked_notarization:#?}");
 
+    // The same information in a single call, resolved against a single clock reading
+    let locked_lock_status = notarization_client
+        .lock_status(*locked_notarization_id.object_id())
+        .await?;
+    println!("🧾 Lock status (single call): {locked_lock_status:#?}");
+
     // Compare methods between dynamic and locked
     println!("\n📊 Comparison Summary:");
     println!("┌─────────────────────┬─────────────┬─────────────┐");
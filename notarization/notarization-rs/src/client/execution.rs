@@ -0,0 +1,848 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Transaction Execution
+//!
+//! Execution strategies on top of the [`TransactionBuilder`] flow.
+//!
+//! ## Idempotent Submission
+//!
+//! The digest of a transaction is derived from its signed data, so it is known before the
+//! transaction is submitted. With [`TransactionBuilderExt::with_idempotent_submission`] the
+//! transaction is built and signed once; when a submission fails (e.g. the node accepted the
+//! transaction but the connection dropped before the response arrived), the digest is looked
+//! up on the network before resubmitting. A transaction that already landed is never
+//! submitted twice, which prevents duplicate notarizations on retry.
+//!
+//! ```rust,ignore
+//! # use notarization::NotarizationClient;
+//! # use notarization::client::TransactionBuilderExt;
+//! # use notarization::core::types::State;
+//! # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>) -> Result<(), Box<dyn std::error::Error>> {
+//! let notarization = client
+//!     .create_dynamic_notarization()
+//!     .with_state(State::from_string("Initial data".to_string(), None))
+//!     .finish()
+//!     .with_idempotent_submission()
+//!     .with_max_attempts(5)
+//!     .build_and_execute(&client)
+//!     .await?
+//!     .output;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! Idempotent submission, [timeouts](#timeouts) and [confirmation](#confirmation) are options of
+//! the same [`ConfiguredTransactionBuilder`] and can be combined, e.g.
+//! `.with_idempotent_submission().with_confirmation(Confirmation::Readable)`.
+//!
+//! ## Sponsored Transactions
+//!
+//! With [`TransactionBuilderExt::with_sponsorship`] the gas of a transaction is paid by a
+//! sponsor instead of the sender. The transaction is built with the sponsor as gas owner and
+//! signed by the sender; the sponsor's signing callback then receives the built
+//! [`TransactionData`] and returns the gas owner signature. Both signatures are attached
+//! before the transaction is submitted.
+//!
+//! ```rust,ignore
+//! # use notarization::NotarizationClient;
+//! # use notarization::client::TransactionBuilderExt;
+//! # use notarization::core::types::State;
+//! # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, sponsor: &Sponsor) -> Result<(), Box<dyn std::error::Error>> {
+//! let notarization = client
+//!     .create_dynamic_notarization()
+//!     .with_state(State::from_string("Paid by the sponsor".to_string(), None))
+//!     .finish()
+//!     .with_sponsorship(sponsor.address(), |tx_data| async move { sponsor.sign(tx_data).await })
+//!     .build_and_execute(&client)
+//!     .await?
+//!     .output;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Timeouts
+//!
+//! With [`TransactionBuilderExt::with_timeout`] the execution of a transaction is bounded in
+//! time, so that a degraded network cannot block the caller indefinitely. If the transaction is
+//! not built, submitted and confirmed in time, [`Error::Timeout`] is returned. The transaction
+//! may still land afterwards; combine the timeout with a lookup of the transaction digest
+//! before retrying.
+//!
+//! ```rust,ignore
+//! # use std::time::Duration;
+//! # use notarization::NotarizationClient;
+//! # use notarization::client::TransactionBuilderExt;
+//! # use notarization::core::types::State;
+//! # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, object_id: iota_interaction::types::base_types::ObjectID) -> Result<(), Box<dyn std::error::Error>> {
+//! client
+//!     .update_state(State::from_string("Status: Active".to_string(), None), object_id)
+//!     .with_timeout(Duration::from_secs(10))
+//!     .build_and_execute(&client)
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Confirmation
+//!
+//! Transactions are executed with `WaitForLocalExecution`: the node that executed the
+//! transaction has applied its effects when `build_and_execute` returns. A read that is served
+//! by another node (e.g. behind a load balancer) may not reflect the write yet. With
+//! [`TransactionBuilderExt::with_confirmation`] and [`Confirmation::Readable`], execution only
+//! returns once the transaction can be read back from the network, so that subsequent reads see
+//! the write.
+//!
+//! Waiting costs at least one more request per transaction, and possibly several polls on a
+//! busy network. Use [`Confirmation::LocalExecution`] (the default) when latency matters more
+//! than reading your own writes, e.g. for fire-and-forget notarizations. The lookups follow a
+//! [`RetryPolicy`], so there is no delay between them on `wasm32`.
+//!
+//! ```rust,ignore
+//! # use notarization::NotarizationClient;
+//! # use notarization::client::{Confirmation, TransactionBuilderExt};
+//! # use notarization::core::types::State;
+//! # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>) -> Result<(), Box<dyn std::error::Error>> {
+//! let notarization_id = client
+//!     .create_dynamic_notarization()
+//!     .with_state(State::from_string("Initial data".to_string(), None))
+//!     .finish()
+//!     .with_confirmation(Confirmation::Readable)
+//!     .build_and_execute(&client)
+//!     .await?
+//!     .output
+//!     .id;
+//! let state = client.state(*notarization_id.object_id()).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! ## Gas Estimation
+//!
+//! [`NotarizationClient::estimate_gas`](super::NotarizationClient::estimate_gas) inspects a
+//! transaction without submitting it and returns a [`GasEstimate`], e.g. to show the expected
+//! cost to a user before they confirm.
+//!
+//! ## Previews
+//!
+//! The `build_and_preview` method of the notarization transactions (e.g.
+//! [`DestroyNotarization::build_and_preview`](crate::core::transactions::DestroyNotarization::build_and_preview))
+//! dry runs a transaction and returns its effects, including a failed execution status. Use it
+//! to check whether a lock or the ownership of a notarization would reject a transaction before
+//! it is submitted.
+//!
+//! ## Tracing
+//!
+//! With the `tracing` feature, the `build_and_execute` methods of this module emit a span
+//! covering the submission of the transaction. The plain `build_and_execute` of
+//! [`TransactionBuilder`] is provided by `product_common` and emits no span for the submission;
+//! only the building of the programmable transaction is traced. To trace a submission without
+//! changing how it is executed, go through a [`ConfiguredTransactionBuilder`], e.g. with
+//! `.with_confirmation(Confirmation::LocalExecution)`, which submits the transaction once and
+//! returns as soon as the node executed it, like the plain path.
+
+use std::future::Future;
+use std::time::Duration;
+
+use iota_interaction::rpc_types::{
+    IotaExecutionStatus, IotaTransactionBlockEffectsAPI, IotaTransactionBlockResponse,
+    IotaTransactionBlockResponseOptions,
+};
+use iota_interaction::types::base_types::IotaAddress;
+use iota_interaction::types::crypto::Signature;
+use iota_interaction::types::gas::GasCostSummary;
+use iota_interaction::types::quorum_driver_types::ExecuteTransactionRequestType;
+use iota_interaction::types::transaction::{TransactionData, TransactionDataAPI};
+use iota_interaction::{IotaClientTrait, IotaKeySignature, OptionalSend, OptionalSync};
+use product_common::core_client::CoreClient;
+use product_common::transaction::TransactionOutput;
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+use secret_storage::Signer;
+
+use super::gas;
+use super::retry::RetryPolicy;
+use crate::error::Error;
+
+/// The default number of submissions attempted with [`TransactionBuilderExt::with_idempotent_submission`].
+const DEFAULT_MAX_ATTEMPTS: usize = 3;
+/// The default number of lookups of a transaction that is awaited with [`Confirmation::Readable`].
+const DEFAULT_CONFIRMATION_POLLS: usize = 10;
+/// The default delay before the second lookup of an awaited transaction.
+const DEFAULT_CONFIRMATION_DELAY: Duration = Duration::from_millis(250);
+
+/// How long `build_and_execute` waits for an executed transaction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Confirmation {
+    /// Waits until the node that executed the transaction applied its effects.
+    ///
+    /// Reads served by other nodes may not reflect the transaction yet.
+    #[default]
+    LocalExecution,
+    /// Additionally waits until the transaction can be read back from the network.
+    ///
+    /// Subsequent reads reflect the transaction, at the cost of one or more extra requests.
+    Readable,
+}
+
+/// The estimated gas cost of a transaction, in NANOS.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GasEstimate {
+    /// The cost of executing the transaction.
+    pub computation_cost: u64,
+    /// The cost of storing the objects written by the transaction.
+    pub storage_cost: u64,
+    /// The refund for the storage of the objects deleted or mutated by the transaction.
+    pub storage_rebate: u64,
+    /// The part of the storage fee that is not refunded.
+    pub non_refundable_storage_fee: u64,
+}
+
+impl GasEstimate {
+    /// Returns the net cost of the transaction, `computation_cost + storage_cost - storage_rebate`.
+    ///
+    /// The net cost is negative if the storage rebate outweighs the costs, e.g. when a
+    /// notarization is destroyed.
+    pub fn net_cost(&self) -> i64 {
+        self.computation_cost as i64 + self.storage_cost as i64 - self.storage_rebate as i64
+    }
+}
+
+impl From<&GasCostSummary> for GasEstimate {
+    fn from(summary: &GasCostSummary) -> Self {
+        Self {
+            computation_cost: summary.computation_cost,
+            storage_cost: summary.storage_cost,
+            storage_rebate: summary.storage_rebate,
+            non_refundable_storage_fee: summary.non_refundable_storage_fee,
+        }
+    }
+}
+
+/// Extends [`TransactionBuilder`] with notarization specific execution strategies.
+pub trait TransactionBuilderExt<Tx> {
+    /// Makes the execution of the transaction idempotent.
+    ///
+    /// See the [module docs](self) for details.
+    fn with_idempotent_submission(self) -> ConfiguredTransactionBuilder<Tx>;
+
+    /// Has `sponsor` pay for the gas of the transaction.
+    ///
+    /// `sign` is called with the built transaction and must return the signature of `sponsor`
+    /// over it. See the [module docs](self) for details.
+    fn with_sponsorship<F, Fut>(self, sponsor: IotaAddress, sign: F) -> SponsoredTransactionBuilder<Tx, F>
+    where
+        F: FnOnce(TransactionData) -> Fut,
+        Fut: Future<Output = Result<Signature, Error>>;
+
+    /// Sets how long execution waits for the transaction to be confirmed.
+    ///
+    /// See the [module docs](self) for the tradeoff between latency and reading your own writes.
+    fn with_confirmation(self, confirmation: Confirmation) -> ConfiguredTransactionBuilder<Tx>;
+
+    /// Fails with [`Error::Timeout`] if the transaction is not executed within `timeout`.
+    ///
+    /// See the [module docs](self) for details.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn with_timeout(self, timeout: Duration) -> ConfiguredTransactionBuilder<Tx>;
+}
+
+impl<Tx> TransactionBuilderExt<Tx> for TransactionBuilder<Tx>
+where
+    Tx: Transaction,
+{
+    fn with_idempotent_submission(self) -> ConfiguredTransactionBuilder<Tx> {
+        ConfiguredTransactionBuilder::new(self).with_max_attempts(DEFAULT_MAX_ATTEMPTS)
+    }
+
+    fn with_sponsorship<F, Fut>(self, sponsor: IotaAddress, sign: F) -> SponsoredTransactionBuilder<Tx, F>
+    where
+        F: FnOnce(TransactionData) -> Fut,
+        Fut: Future<Output = Result<Signature, Error>>,
+    {
+        SponsoredTransactionBuilder {
+            builder: self,
+            sponsor,
+            sign,
+        }
+    }
+
+    fn with_confirmation(self, confirmation: Confirmation) -> ConfiguredTransactionBuilder<Tx> {
+        ConfiguredTransactionBuilder::new(self).with_confirmation(confirmation)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn with_timeout(self, timeout: Duration) -> ConfiguredTransactionBuilder<Tx> {
+        ConfiguredTransactionBuilder::new(self).with_timeout(timeout)
+    }
+}
+
+/// How a signed transaction is submitted and awaited.
+#[derive(Debug, Clone, Copy)]
+struct Submission {
+    max_attempts: usize,
+    confirmation: Confirmation,
+    poll_policy: RetryPolicy,
+}
+
+impl Default for Submission {
+    fn default() -> Self {
+        Self {
+            max_attempts: 1,
+            confirmation: Confirmation::default(),
+            poll_policy: RetryPolicy::new(DEFAULT_CONFIRMATION_POLLS).with_base_delay(DEFAULT_CONFIRMATION_DELAY),
+        }
+    }
+}
+
+/// A [`TransactionBuilder`] with execution options: idempotent retries, a confirmation level
+/// and, outside of `wasm32`, a timeout.
+///
+/// The options can be combined, e.g. a transaction can be awaited with
+/// [`Confirmation::Readable`] within a timeout.
+pub struct ConfiguredTransactionBuilder<Tx> {
+    builder: TransactionBuilder<Tx>,
+    submission: Submission,
+    timeout: Option<Duration>,
+}
+
+impl<Tx> ConfiguredTransactionBuilder<Tx> {
+    fn new(builder: TransactionBuilder<Tx>) -> Self {
+        Self {
+            builder,
+            submission: Submission::default(),
+            timeout: None,
+        }
+    }
+
+    /// Sets the maximum number of submission attempts (default: 1, or 3 with
+    /// [`TransactionBuilderExt::with_idempotent_submission`]).
+    ///
+    /// Before every resubmission, the transaction digest is looked up, so that a transaction
+    /// that already landed is not sent again. Values lower than one are treated as one.
+    pub fn with_max_attempts(mut self, max_attempts: usize) -> Self {
+        self.submission.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Sets how long execution waits for the transaction to be confirmed (default:
+    /// [`Confirmation::LocalExecution`]).
+    pub fn with_confirmation(mut self, confirmation: Confirmation) -> Self {
+        self.submission.confirmation = confirmation;
+        self
+    }
+
+    /// Sets how often, and how patiently, an executed transaction is looked up with
+    /// [`Confirmation::Readable`] (default: 10 lookups, starting with a delay of 250ms).
+    pub fn with_poll_policy(mut self, poll_policy: RetryPolicy) -> Self {
+        self.submission.poll_policy = poll_policy;
+        self
+    }
+
+    /// Fails with [`Error::Timeout`] if the transaction is not executed within `timeout`
+    /// (default: no timeout).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Returns the wrapped [`TransactionBuilder`], dropping the execution options.
+    pub fn into_inner(self) -> TransactionBuilder<Tx> {
+        self.builder
+    }
+}
+
+impl<Tx> ConfiguredTransactionBuilder<Tx>
+where
+    Tx: Transaction<Error = Error> + OptionalSend,
+{
+    /// Builds, signs and executes the transaction with the configured options.
+    ///
+    /// If a submission fails, the transaction digest is queried before the transaction is
+    /// resubmitted; a transaction that already landed is picked up instead of being sent again.
+    /// The timeout covers building the transaction, its submission and the wait for the
+    /// configured confirmation.
+    ///
+    /// ## Errors
+    ///
+    /// - Returns [`Error::InsufficientGas`] if the transaction was built without a gas payment and the coins of the
+    ///   sender do not cover its gas budget. A gas owner set with `with_gas_owner` is not taken into account.
+    /// - Returns the last submission error if the transaction did not land within the configured number of attempts, or
+    ///   an error if it was executed but failed on-chain.
+    /// - With [`Confirmation::Readable`], returns an [`Error::RpcError`] if the executed transaction could not be read
+    ///   back within the configured lookups; the transaction was executed nonetheless.
+    /// - Returns [`Error::Timeout`] if the timeout elapsed, in which case the transaction may or may not have been
+    ///   executed.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "execute_configured", level = "debug", skip_all, err)
+    )]
+    pub async fn build_and_execute<C, S>(self, client: &C) -> Result<TransactionOutput<Tx::Output>, Error>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let Self {
+            builder,
+            submission,
+            timeout,
+        } = self;
+
+        within(timeout, async move {
+            let (tx_data, signatures, tx) = build(builder, client.sender_address(), client).await?;
+            execute_signed(tx, tx_data, signatures, submission, client).await
+        })
+        .await
+    }
+}
+
+/// A [`TransactionBuilder`] whose gas is paid by a sponsor.
+pub struct SponsoredTransactionBuilder<Tx, F> {
+    builder: TransactionBuilder<Tx>,
+    sponsor: IotaAddress,
+    sign: F,
+}
+
+impl<Tx, F, Fut> SponsoredTransactionBuilder<Tx, F>
+where
+    Tx: Transaction<Error = Error> + OptionalSend,
+    F: FnOnce(TransactionData) -> Fut,
+    Fut: Future<Output = Result<Signature, Error>>,
+{
+    /// Builds the transaction with the sponsor as gas owner, signs it as sender, has the
+    /// sponsor sign it as gas owner and executes it.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the transaction cannot be built, e.g. [`Error::InsufficientGas`] if the
+    /// coins of the sponsor do not cover the gas budget, if the sponsor's signing callback fails,
+    /// or if the transaction fails to execute.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(name = "execute_sponsored", level = "debug", skip_all, err)
+    )]
+    pub async fn build_and_execute<C, S>(self, client: &C) -> Result<TransactionOutput<Tx::Output>, Error>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        let (tx_data, mut signatures, tx) =
+            build(self.builder.with_gas_owner(self.sponsor), self.sponsor, client).await?;
+        if tx_data.gas_owner() != self.sponsor {
+            return Err(Error::InvalidArgument(format!(
+                "transaction gas is owned by {} instead of the sponsor {}",
+                tx_data.gas_owner(),
+                self.sponsor
+            )));
+        }
+
+        let sponsor_signature = (self.sign)(tx_data.clone()).await?;
+        signatures.push(sponsor_signature);
+
+        execute_signed(tx, tx_data, signatures, Submission::default(), client).await
+    }
+}
+
+/// Builds the transaction of `builder` and signs it as sender.
+///
+/// If the build fails because no gas coins of `gas_owner` covering the gas budget could be
+/// selected, the failure is reported as [`Error::InsufficientGas`].
+async fn build<Tx, C, S>(
+    builder: TransactionBuilder<Tx>,
+    gas_owner: IotaAddress,
+    client: &C,
+) -> Result<(TransactionData, Vec<Signature>, Tx), Error>
+where
+    Tx: Transaction<Error = Error> + OptionalSend,
+    C: CoreClient<S> + OptionalSync,
+    S: Signer<IotaKeySignature> + OptionalSync,
+{
+    let error = match builder.build(client).await {
+        Ok(built) => return Ok(built),
+        Err(error) => error.to_string(),
+    };
+
+    // The balance is only looked up for a failed coin selection; if the lookup fails as well,
+    // the original failure is reported.
+    let available = if is_gas_selection_failure(&error) {
+        gas::gas_coins_of(client, gas_owner, None)
+            .await
+            .ok()
+            .map(|coins| gas::payable_balance(&coins))
+    } else {
+        None
+    };
+
+    Err(build_error(error, gas_owner, available))
+}
+
+/// The messages with which the builder reports that no gas coins covering the gas budget could
+/// be selected; it does not report this failure as a distinct error.
+const GAS_SELECTION_FAILURES: [&str; 2] = [
+    "Cannot find gas coin for signer address",
+    "Insufficient fund for address",
+];
+
+/// Returns whether the build failure `error` is a failed gas coin selection.
+fn is_gas_selection_failure(error: &str) -> bool {
+    GAS_SELECTION_FAILURES.iter().any(|failure| error.contains(failure))
+}
+
+/// Returns the error for the build failure `error`: [`Error::InsufficientGas`] if the balance
+/// `available` of `gas_owner` was looked up for a failed gas coin selection, the original
+/// failure otherwise.
+fn build_error(error: String, gas_owner: IotaAddress, available: Option<u64>) -> Error {
+    match available {
+        Some(available) => Error::InsufficientGas {
+            owner: gas_owner,
+            required: None,
+            available,
+        },
+        None => Error::GenericError(format!("failed to build transaction: {error}")),
+    }
+}
+
+/// The outcome of [`execute`].
+pub(crate) enum Execution<T> {
+    /// The transaction was not executed: it could not be built, did not land or failed on-chain.
+    Failed(Error),
+    /// The transaction was executed, with the result of reading its output.
+    Executed(Result<TransactionOutput<T>, Error>),
+}
+
+/// Builds, signs and executes the transaction of `builder` like an idempotent
+/// [`ConfiguredTransactionBuilder`], telling a transaction that was not executed from one whose
+/// output could not be read after it was executed.
+pub(crate) async fn execute<Tx, C, S>(builder: TransactionBuilder<Tx>, client: &C) -> Execution<Tx::Output>
+where
+    Tx: Transaction<Error = Error> + OptionalSend,
+    C: CoreClient<S> + OptionalSync,
+    S: Signer<IotaKeySignature> + OptionalSync,
+{
+    let submission = Submission {
+        max_attempts: DEFAULT_MAX_ATTEMPTS,
+        ..Submission::default()
+    };
+
+    let (tx_data, signatures, tx) = match build(builder, client.sender_address(), client).await {
+        Ok(built) => built,
+        Err(error) => return Execution::Failed(error),
+    };
+    match submit_signed(tx_data, signatures, submission, client).await {
+        Ok(response) => Execution::Executed(confirm_and_apply(tx, response, submission, client).await),
+        Err(error) => Execution::Failed(error),
+    }
+}
+
+/// Submits a signed transaction as told by `submission`, waits for its confirmation and applies
+/// its effects.
+///
+/// This is the one submission path of the execution strategies of this module.
+async fn execute_signed<Tx, C, S>(
+    tx: Tx,
+    tx_data: TransactionData,
+    signatures: Vec<Signature>,
+    submission: Submission,
+    client: &C,
+) -> Result<TransactionOutput<Tx::Output>, Error>
+where
+    Tx: Transaction<Error = Error> + OptionalSend,
+    C: CoreClient<S> + OptionalSync,
+    S: Signer<IotaKeySignature> + OptionalSync,
+{
+    let response = submit_signed(tx_data, signatures, submission, client).await?;
+
+    confirm_and_apply(tx, response, submission, client).await
+}
+
+/// Submits a signed transaction as told by `submission`.
+///
+/// Returns the response of the transaction if it was executed successfully, and an error if it
+/// did not land or failed on-chain.
+async fn submit_signed<C, S>(
+    tx_data: TransactionData,
+    signatures: Vec<Signature>,
+    submission: Submission,
+    client: &C,
+) -> Result<IotaTransactionBlockResponse, Error>
+where
+    C: CoreClient<S> + OptionalSync,
+    S: Signer<IotaKeySignature> + OptionalSync,
+{
+    let digest = tx_data.digest();
+    #[cfg(feature = "tracing")]
+    tracing::debug!(%digest, "submitting transaction");
+    let options = IotaTransactionBlockResponseOptions::full_content();
+
+    let response = submit_idempotently(
+        submission.max_attempts,
+        || {
+            let tx_data = tx_data.clone();
+            let signatures = signatures.clone();
+            let options = options.clone();
+            async move {
+                client
+                    .client_adapter()
+                    .quorum_driver_api()
+                    .execute_transaction_block(
+                        tx_data,
+                        signatures,
+                        Some(options),
+                        Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+                    )
+                    .await
+                    .map_err(|e| Error::RpcError(format!("failed to submit transaction {digest}: {e}")))
+            }
+        },
+        || {
+            let options = options.clone();
+            async move {
+                // The node reports unknown digests as errors, so any failure is treated as
+                // "not landed (yet)".
+                Ok(client
+                    .client_adapter()
+                    .read_api()
+                    .get_transaction_with_options(digest, options)
+                    .await
+                    .ok())
+            }
+        },
+    )
+    .await?
+    .clone_native_response();
+
+    let effects = response.effects.as_ref().ok_or_else(|| {
+        Error::TransactionUnexpectedResponse(format!("transaction {digest} response is missing its effects"))
+    })?;
+    if let IotaExecutionStatus::Failure { error } = effects.status() {
+        return Err(match Error::from_execution_failure(error) {
+            locked @ Error::Locked { .. } => locked,
+            _ => Error::TransactionUnexpectedResponse(format!("transaction {digest} failed on-chain: {error}")),
+        });
+    }
+
+    Ok(response)
+}
+
+/// Waits for the confirmation of a successfully executed transaction as told by `submission`
+/// and applies its effects.
+async fn confirm_and_apply<Tx, C, S>(
+    tx: Tx,
+    response: IotaTransactionBlockResponse,
+    submission: Submission,
+    client: &C,
+) -> Result<TransactionOutput<Tx::Output>, Error>
+where
+    Tx: Transaction<Error = Error> + OptionalSend,
+    C: CoreClient<S> + OptionalSync,
+    S: Signer<IotaKeySignature> + OptionalSync,
+{
+    let digest = response.digest;
+    if submission.confirmation == Confirmation::Readable {
+        submission
+            .poll_policy
+            .retry_if(
+                || async {
+                    client
+                        .client_adapter()
+                        .read_api()
+                        .get_transaction_with_options(digest, IotaTransactionBlockResponseOptions::new())
+                        .await
+                        .map(|_| ())
+                        .map_err(|e| Error::RpcError(format!("executed transaction {digest} is not readable: {e}")))
+                },
+                // The node reports unknown digests as errors, so any failure means "not yet".
+                |_| true,
+            )
+            .await?;
+    }
+
+    let mut effects = response.effects.clone().ok_or_else(|| {
+        Error::TransactionUnexpectedResponse(format!("transaction {digest} response is missing its effects"))
+    })?;
+    // Creation transactions parse their output from the emitted events.
+    let mut events = response.events.clone().unwrap_or_default();
+    let output = tx.apply_with_events(&mut effects, &mut events, client).await?;
+
+    Ok(TransactionOutput { output, response })
+}
+
+/// Runs `execution`, failing with [`Error::Timeout`] if it does not complete within `timeout`.
+async fn within<T>(timeout: Option<Duration>, execution: impl Future<Output = Result<T, Error>>) -> Result<T, Error> {
+    // Timeouts cannot be configured on `wasm32`.
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Some(timeout) = timeout {
+        return tokio::time::timeout(timeout, execution)
+            .await
+            .map_err(|_| Error::Timeout(timeout))?;
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = timeout;
+
+    execution.await
+}
+
+/// Drives the submission loop of an idempotent execution.
+///
+/// `submit` sends the (already signed) transaction; `lookup` checks whether the transaction
+/// landed on the network. After every failed submission the transaction is looked up first
+/// and only resubmitted if it cannot be found.
+pub(crate) async fn submit_idempotently<R, S, SF, L, LF>(
+    max_attempts: usize,
+    mut submit: S,
+    mut lookup: L,
+) -> Result<R, Error>
+where
+    S: FnMut() -> SF,
+    SF: Future<Output = Result<R, Error>>,
+    L: FnMut() -> LF,
+    LF: Future<Output = Result<Option<R>, Error>>,
+{
+    let mut attempt = 1;
+    loop {
+        let error = match submit().await {
+            Ok(response) => return Ok(response),
+            Err(error) => error,
+        };
+
+        if let Some(response) = lookup().await? {
+            return Ok(response);
+        }
+
+        if attempt >= max_attempts {
+            return Err(error);
+        }
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[test]
+    fn test_gas_estimate_net_cost() {
+        let estimate = GasEstimate {
+            computation_cost: 1_000,
+            storage_cost: 2_000,
+            storage_rebate: 500,
+            non_refundable_storage_fee: 5,
+        };
+        assert_eq!(estimate.net_cost(), 2_500);
+
+        let rebate_only = GasEstimate {
+            computation_cost: 1_000,
+            storage_rebate: 3_000,
+            ..Default::default()
+        };
+        assert_eq!(rebate_only.net_cost(), -2_000);
+    }
+
+    #[test]
+    fn test_other_build_failures_are_not_reported_as_insufficient_gas() {
+        let error = "object 0x1 not found".to_string();
+        assert!(!is_gas_selection_failure(&error));
+        assert!(!is_gas_selection_failure("insufficient permissions to read the object"));
+
+        let result = build_error(error, IotaAddress::ZERO, None);
+        assert!(
+            matches!(result, Error::GenericError(message) if message == "failed to build transaction: object 0x1 not found")
+        );
+    }
+
+    #[test]
+    fn test_failed_gas_coin_selection_is_reported_as_insufficient_gas() {
+        let error = format!(
+            "Cannot find gas coin for signer address {} with amount sufficient for the required gas budget 10000000",
+            IotaAddress::ZERO
+        );
+        assert!(is_gas_selection_failure(&error));
+
+        // The balance could not be looked up.
+        let result = build_error(error.clone(), IotaAddress::ZERO, None);
+        assert!(matches!(result, Error::GenericError(_)));
+
+        let result = build_error(error, IotaAddress::ZERO, Some(500));
+        assert!(matches!(
+            result,
+            Error::InsufficientGas {
+                required: None,
+                available: 500,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_retry_detects_landed_transaction() {
+        let submissions = AtomicUsize::new(0);
+        let landed = AtomicUsize::new(0);
+
+        // The first submission reaches the network but the response is lost.
+        let result = submit_idempotently(
+            3,
+            || async {
+                submissions.fetch_add(1, Ordering::SeqCst);
+                landed.fetch_add(1, Ordering::SeqCst);
+                Err::<&str, _>(Error::RpcError("connection reset".to_string()))
+            },
+            || async { Ok((landed.load(Ordering::SeqCst) > 0).then_some("effects")) },
+        )
+        .await;
+
+        assert_eq!(result.unwrap(), "effects");
+        assert_eq!(submissions.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_resubmits_transaction_that_did_not_land() {
+        let submissions = AtomicUsize::new(0);
+
+        let result = submit_idempotently(
+            3,
+            || async {
+                if submissions.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Err(Error::RpcError("connection refused".to_string()))
+                } else {
+                    Ok("effects")
+                }
+            },
+            || async { Ok(None) },
+        )
+        .await;
+
+        assert_eq!(result.unwrap(), "effects");
+        assert_eq!(submissions.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_times_out_pending_execution() {
+        let timeout = Duration::from_millis(10);
+
+        let result = within(Some(timeout), std::future::pending::<Result<(), Error>>()).await;
+        assert!(matches!(result, Err(Error::Timeout(elapsed)) if elapsed == timeout));
+
+        let result = within(Some(timeout), async { Ok("effects") }).await;
+        assert_eq!(result.unwrap(), "effects");
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let submissions = AtomicUsize::new(0);
+
+        let result = submit_idempotently(
+            2,
+            || async {
+                submissions.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(Error::RpcError("connection refused".to_string()))
+            },
+            || async { Ok(None) },
+        )
+        .await;
+
+        assert!(matches!(result, Err(Error::RpcError(_))));
+        assert_eq!(submissions.load(Ordering::SeqCst), 2);
+    }
+}
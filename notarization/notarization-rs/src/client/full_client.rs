@@ -37,13 +37,21 @@
 //! ## Available Configuration Methods
 //!
 //! The [`TransactionBuilder`] provides these configuration methods:
-//! - `with_gas_budget(amount)` - Set gas budget (default: estimated)
-//! - `with_gas_payment(coins)` - Use specific coins for gas payment
+//! - `with_gas_budget(amount)` - Set gas budget (default: the budget of the client's [`GasBudgetPolicy`] for the
+//!   operation if set, otherwise estimated; creations and state updates can opt in to budgets scaled with the size of
+//!   the notarized state with [`GasBudgetPolicy::with_size_scaled_budgets`], see [`suggested_gas_budget`])
+//! - `with_gas_payment(coins)` - Use specific coins for gas payment (default: coins of the gas owner covering the
+//!   budget, selected when the transaction is built; executions through
+//!   [`TransactionBuilderExt`](super::TransactionBuilderExt) report coins not covering it as
+//!   [`Error::InsufficientGas`]; see [`NotarizationClient::select_gas_coins`] to select them up front)
 //! - `with_gas_owner(address)` - Set gas payer (default: sender)
 //! - `with_gas_price(price)` - Override gas price (default: network price)
 //! - `with_sender(address)` - Override transaction sender
 //! - `with_sponsor(callback)` - Have another party pay for gas
 //!
+//! For sponsored transactions where the sponsor signs as gas owner, see
+//! [`TransactionBuilderExt::with_sponsorship`](super::TransactionBuilderExt::with_sponsorship).
+//!
 //! ## Example: Complete Notarization Workflow
 //!
 //! ```rust,ignore
@@ -78,19 +86,35 @@
 //! ```
 
 use std::ops::Deref;
+use std::sync::Arc;
 
-use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+#[cfg(not(target_arch = "wasm32"))]
+use iota_interaction::IotaClient;
+use iota_interaction::rpc_types::IotaTransactionBlockEffectsAPI;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID, ObjectRef};
 use iota_interaction::types::crypto::PublicKey;
-use iota_interaction::{IotaKeySignature, OptionalSync};
+use iota_interaction::types::object::Owner;
+use iota_interaction::types::transaction::TransactionKind;
+use iota_interaction::{IotaClientTrait, IotaKeySignature, OptionalSend, OptionalSync};
+#[cfg(target_arch = "wasm32")]
+use iota_interaction_ts::bindings::WasmIotaClient;
 use product_common::core_client::{CoreClient, CoreClientReadOnly};
 use product_common::network_name::NetworkName;
-use product_common::transaction::transaction_builder::TransactionBuilder;
+use product_common::transaction::TransactionOutput;
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
 use secret_storage::Signer;
 
+use super::execution::{self, Confirmation, Execution, GasEstimate, TransactionBuilderExt};
+use super::gas::{self, GasBudgetPolicy};
 use super::read_only::NotarizationClientReadOnly;
 use crate::core::builder::{Dynamic, Locked, NotarizationBuilder};
-use crate::core::transactions::{DestroyNotarization, TransferNotarization, UpdateMetadata, UpdateState};
-use crate::core::types::State;
+use crate::core::transactions::{
+    CreateNotarization, CreateNotarizationBatch, DestroyNotarization, DestroyNotarizationBatch, TransferNotarization,
+    UpdateMetadata, UpdateState, with_default_gas_budget,
+};
+use crate::core::types::{
+    NotarizationMethod, NotarizationTransferred, OnChainNotarization, State, suggested_gas_budget,
+};
 use crate::error::Error;
 use crate::iota_interaction_adapter::IotaClientAdapter;
 
@@ -102,14 +126,37 @@ use crate::iota_interaction_adapter::IotaClientAdapter;
 /// ## Type Parameter
 ///
 /// - `S`: The signer type that implements [`Signer<IotaKeySignature>`]
-#[derive(Clone)]
+///
+/// ## Sharing
+///
+/// The read client and the signer are reference counted, so cloning a client is cheap and does
+/// not require `S: Clone`. Clones share the connection and the resolved network and package;
+/// nothing is fetched from the node again. In servers handling many concurrent requests, create
+/// the client once at startup and hand a clone to every request handler, e.g. as shared
+/// application state. Settings changed on a clone, like the
+/// [gas budget policy](Self::with_gas_budget_policy) or a [reconnection](Self::reconnect), only
+/// apply to that clone.
 pub struct NotarizationClient<S> {
     /// [`NotarizationClientReadOnly`] instance, used for read-only operations.
-    read_client: NotarizationClientReadOnly,
+    read_client: Arc<NotarizationClientReadOnly>,
     /// The public key of the client.
     public_key: PublicKey,
     /// The signer of the client.
-    signer: S,
+    signer: Arc<S>,
+    /// The default gas budgets per kind of operation.
+    gas_budget_policy: GasBudgetPolicy,
+}
+
+// Implemented by hand, as deriving `Clone` would require `S: Clone`.
+impl<S> Clone for NotarizationClient<S> {
+    fn clone(&self) -> Self {
+        Self {
+            read_client: Arc::clone(&self.read_client),
+            public_key: self.public_key.clone(),
+            signer: Arc::clone(&self.signer),
+            gas_budget_policy: self.gas_budget_policy,
+        }
+    }
 }
 
 impl<S> Deref for NotarizationClient<S> {
@@ -153,10 +200,102 @@ where
 
         Ok(Self {
             public_key,
-            read_client: client,
-            signer,
+            read_client: Arc::new(client),
+            signer: Arc::new(signer),
+            gas_budget_policy: GasBudgetPolicy::default(),
         })
     }
+
+    /// Replaces the connection of the client with `iota_client`, keeping the signer.
+    ///
+    /// Use this to recover from a lost RPC connection (e.g. in long-running services) without
+    /// rebuilding the signer. The read client is rebuilt and the network is resolved again from
+    /// the node, like in [`NotarizationClientReadOnly::new`]; the package ID used so far serves
+    /// as fallback if the registry does not know the network. The retry policy and the
+    /// inspection sender of read calls are kept, as is the gas budget policy.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the node cannot be reached or its network cannot be resolved. The
+    /// client keeps its previous connection in that case.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # async fn example(client: &mut NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let iota_client = iota_sdk::IotaClientBuilder::default().build_testnet().await?;
+    /// client.reconnect(iota_client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reconnect(
+        &mut self,
+        #[cfg(target_arch = "wasm32")] iota_client: WasmIotaClient,
+        #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
+    ) -> Result<(), Error> {
+        let read_client =
+            NotarizationClientReadOnly::with_fallback_package_id(iota_client, self.read_client.package_id())
+                .await?
+                .with_retry_policy(self.read_client.retry_policy())
+                .with_inspection_sender(self.read_client.inspection_sender());
+        self.read_client = Arc::new(read_client);
+
+        Ok(())
+    }
+
+    /// Sets the default gas budgets per kind of operation.
+    ///
+    /// The budgets apply to the transaction builders returned by this client from then on; a
+    /// budget set with `with_gas_budget` on a transaction builder takes precedence. See
+    /// [`GasBudgetPolicy`] for the operations covered.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::GasBudgetPolicy;
+    /// let client = client.with_gas_budget_policy(GasBudgetPolicy::new().with_create(50_000_000).with_destroy(5_000_000));
+    /// ```
+    pub fn with_gas_budget_policy(mut self, gas_budget_policy: GasBudgetPolicy) -> Self {
+        self.gas_budget_policy = gas_budget_policy;
+        self
+    }
+
+    /// Returns the default gas budgets per kind of operation.
+    pub fn gas_budget_policy(&self) -> GasBudgetPolicy {
+        self.gas_budget_policy
+    }
+
+    /// Selects coins of the sender to pay a gas budget of `budget` NANOS.
+    ///
+    /// Transactions executed without `with_gas_payment` get their gas coins selected when they
+    /// are built. Use this method to select them up front, e.g. to fail early with a clear error
+    /// or to give concurrent transactions distinct coins. The largest coins are picked first; the
+    /// network merges several coins of a gas payment into one.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::InsufficientGas`] if the sender has no IOTA coins or their balance does
+    /// not cover `budget`. At most 256 coins, the maximum of a gas payment, are taken into account.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let gas_budget = 10_000_000;
+    /// let gas_coins = client.select_gas_coins(gas_budget).await?;
+    /// client
+    ///     .transfer_notarization(object_id, recipient)
+    ///     .with_gas_budget(gas_budget)
+    ///     .with_gas_payment(gas_coins)
+    ///     .build_and_execute(&client)
+    ///     .await?;
+    /// ```
+    pub async fn select_gas_coins(&self, budget: u64) -> Result<Vec<ObjectRef>, Error> {
+        let owner = IotaAddress::from(&self.public_key);
+        let coins = gas::gas_coins_of(self, owner, Some(budget)).await?;
+
+        gas::select_gas_coins(owner, coins, budget)
+    }
 }
 
 impl<S> NotarizationClient<S> {
@@ -183,6 +322,8 @@ impl<S> NotarizationClient<S> {
     /// See [`NotarizationBuilder<Locked>`] for configuration options.
     pub fn create_locked_notarization(&self) -> NotarizationBuilder<Locked> {
         NotarizationBuilder::locked()
+            .with_gas_budget_policy(&self.gas_budget_policy)
+            .with_clock(self.read_client.clock())
     }
 
     /// Creates a builder for a dynamic notarization.
@@ -208,6 +349,100 @@ impl<S> NotarizationClient<S> {
     /// See [`NotarizationBuilder<Dynamic>`] for configuration options.
     pub fn create_dynamic_notarization(&self) -> NotarizationBuilder<Dynamic> {
         NotarizationBuilder::dynamic()
+            .with_gas_budget_policy(&self.gas_budget_policy)
+            .with_clock(self.read_client.clock())
+    }
+
+    /// Creates several locked notarizations in a single transaction.
+    ///
+    /// The builders are validated when the transaction is built; one invalid builder
+    /// fails the whole batch. The output contains the created notarizations in the order
+    /// of `builders`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use notarization::core::builder::NotarizationBuilder;
+    /// # use notarization::core::types::TimeLock;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let builders = ["invoice-1.pdf", "invoice-2.pdf"]
+    ///     .into_iter()
+    ///     .map(|name| {
+    ///         NotarizationBuilder::locked()
+    ///             .with_string_state(name.to_string(), None)
+    ///             .with_delete_lock(TimeLock::None)
+    ///     })
+    ///     .collect();
+    ///
+    /// let notarizations = client
+    ///     .create_locked_batch(builders)
+    ///     .build_and_execute(&client)
+    ///     .await?
+    ///     .output;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_locked_batch(
+        &self,
+        builders: Vec<NotarizationBuilder<Locked>>,
+    ) -> TransactionBuilder<CreateNotarizationBatch<Locked>> {
+        self.create_batch(builders)
+    }
+
+    /// Creates several dynamic notarizations in a single transaction.
+    ///
+    /// The builders are validated when the transaction is built; one invalid builder
+    /// fails the whole batch. The output contains the created notarizations in the order
+    /// of `builders`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use notarization::core::builder::NotarizationBuilder;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let builders = vec![
+    ///     NotarizationBuilder::dynamic().with_string_state("Sensor A: OK".to_string(), None),
+    ///     NotarizationBuilder::dynamic().with_string_state("Sensor B: OK".to_string(), None),
+    /// ];
+    ///
+    /// let notarizations = client
+    ///     .create_dynamic_batch(builders)
+    ///     .build_and_execute(&client)
+    ///     .await?
+    ///     .output;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_dynamic_batch(
+        &self,
+        builders: Vec<NotarizationBuilder<Dynamic>>,
+    ) -> TransactionBuilder<CreateNotarizationBatch<Dynamic>> {
+        self.create_batch(builders)
+    }
+
+    /// Returns a batch creation of `builders` starting with the gas budget of batch creations.
+    fn create_batch<M>(&self, builders: Vec<NotarizationBuilder<M>>) -> TransactionBuilder<CreateNotarizationBatch<M>>
+    where
+        M: Clone,
+    {
+        let gas_budget = self.batch_create_gas_budget(&builders);
+        with_default_gas_budget(
+            TransactionBuilder::new(CreateNotarizationBatch::new(builders)),
+            gas_budget,
+        )
+    }
+
+    /// Returns the gas budget a batch creation of `builders` starts with, if any.
+    fn batch_create_gas_budget<M>(&self, builders: &[NotarizationBuilder<M>]) -> Option<u64> {
+        match self.gas_budget_policy.create() {
+            Some(gas_budget) => Some(gas_budget.saturating_mul(builders.len() as u64)),
+            None => self
+                .gas_budget_policy
+                .scales_with_size()
+                .then(|| suggested_gas_budget(builders.iter().map(NotarizationBuilder::stored_bytes).sum())),
+        }
     }
 }
 
@@ -245,7 +480,177 @@ where
     ///
     /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
     pub fn update_state(&self, state: State, object_id: ObjectID) -> TransactionBuilder<UpdateState> {
-        TransactionBuilder::new(UpdateState::new(state, object_id))
+        let gas_budget = self.gas_budget_policy.update_for(&state);
+        with_default_gas_budget(
+            TransactionBuilder::new(UpdateState::new(state, object_id).with_clock(self.read_client.clock())),
+            gas_budget,
+        )
+    }
+
+    /// Like [`NotarizationClient::update_state`], but skips updates that would not change the
+    /// notarized data.
+    ///
+    /// The current state is read and compared with [`State::same_data`], so an update that only
+    /// changes the state metadata is skipped as well. A skipped update neither increments the
+    /// state version count nor costs gas.
+    ///
+    /// The current state is read when this method is called; a concurrent update is not
+    /// taken into account.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the current state cannot be read.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use notarization::core::types::State;
+    /// # use iota_interaction::types::base_types::ObjectID;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, object_id: ObjectID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let state = State::from_string("Status: Completed".to_string(), None);
+    /// if let Some(update) = client.update_state_if_changed(state, object_id).await? {
+    ///     update.build_and_execute(&client).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns `None` if the notarization already holds the data of `state`.
+    pub async fn update_state_if_changed(
+        &self,
+        state: State,
+        object_id: ObjectID,
+    ) -> Result<Option<TransactionBuilder<UpdateState>>, Error> {
+        let current = self.state(object_id).await?;
+
+        Ok((!current.same_data(&state)).then(|| self.update_state(state, object_id)))
+    }
+
+    /// Updates the state of a dynamic notarization with text data.
+    ///
+    /// Shorthand for [`NotarizationClient::update_state`] with [`State::from_string`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// client
+    ///     .update_state_string("Status: Completed".to_string(), None, object_id)
+    ///     .build_and_execute(&client)
+    ///     .await?;
+    /// ```
+    pub fn update_state_string(
+        &self,
+        text: String,
+        metadata: Option<String>,
+        object_id: ObjectID,
+    ) -> TransactionBuilder<UpdateState> {
+        self.update_state(State::from_string(text, metadata), object_id)
+    }
+
+    /// Updates the state of a dynamic notarization with binary data.
+    ///
+    /// Shorthand for [`NotarizationClient::update_state`] with [`State::from_bytes`].
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// client
+    ///     .update_state_bytes(pdf_bytes, Some("Contract PDF v2".to_string()), object_id)
+    ///     .build_and_execute(&client)
+    ///     .await?;
+    /// ```
+    pub fn update_state_bytes(
+        &self,
+        data: Vec<u8>,
+        metadata: Option<String>,
+        object_id: ObjectID,
+    ) -> TransactionBuilder<UpdateState> {
+        self.update_state(State::from_bytes(data, metadata), object_id)
+    }
+
+    /// Updates the state of a dynamic notarization, unless it was updated concurrently.
+    ///
+    /// Works like [`NotarizationClient::update_state`], but fails with
+    /// [`Error::VersionConflict`] if the state version count of the notarization is not
+    /// `expected_version` anymore, instead of overwriting the other update.
+    ///
+    /// ## Parameters
+    ///
+    /// - `state`: The new state to set
+    /// - `object_id`: The ID of the notarization to update
+    /// - `expected_version`: The state version count the update is based on
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use notarization::core::types::State;
+    /// # use iota_interaction::types::base_types::ObjectID;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, object_id: ObjectID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let version = client.state_version_count(object_id).await?;
+    /// client
+    ///     .update_state_if_version(State::from_string("Status: Completed", None), object_id, version)
+    ///     .build_and_execute(&client)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    pub fn update_state_if_version(
+        &self,
+        state: State,
+        object_id: ObjectID,
+        expected_version: u64,
+    ) -> TransactionBuilder<UpdateState> {
+        let gas_budget = self.gas_budget_policy.update_for(&state);
+        with_default_gas_budget(
+            TransactionBuilder::new(
+                UpdateState::new(state, object_id)
+                    .with_expected_version(expected_version)
+                    .with_clock(self.read_client.clock()),
+            ),
+            gas_budget,
+        )
+    }
+
+    /// Re-attests a dynamic notarization by setting its current state again.
+    ///
+    /// The data and metadata stay unchanged, but like every state update this **increments the
+    /// state version count** and refreshes the last state change timestamp, e.g. for compliance
+    /// workflows that periodically confirm that a record is still valid.
+    ///
+    /// The current state is read when this method is called and the update is bound to the
+    /// version it was read at (see [`NotarizationClient::update_state_if_version`]), so a
+    /// concurrent update fails the touch with [`Error::VersionConflict`] instead of being
+    /// reverted.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] for locked notarizations, whose state can never be
+    /// updated, and an error if the notarization cannot be read.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use iota_interaction::types::base_types::ObjectID;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, object_id: ObjectID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let touched = client.touch(object_id).await?.build_and_execute(&client).await?.output;
+    /// println!("re-attested as version {}", touched.new_version);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn touch(&self, object_id: ObjectID) -> Result<TransactionBuilder<UpdateState>, Error> {
+        let notarization = self.get_notarization_by_id(object_id).await?;
+        if notarization.method != NotarizationMethod::Dynamic {
+            return Err(Error::InvalidArgument(format!(
+                "cannot touch locked notarization {object_id}: the state of a locked notarization can never be updated"
+            )));
+        }
+
+        Ok(self.update_state_if_version(notarization.state, object_id, notarization.state_version_count))
     }
 
     /// Destroys a notarization permanently.
@@ -272,7 +677,127 @@ where
     ///
     /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
     pub fn destroy(&self, object_id: ObjectID) -> TransactionBuilder<DestroyNotarization> {
-        TransactionBuilder::new(DestroyNotarization::new(object_id))
+        with_default_gas_budget(
+            TransactionBuilder::new(DestroyNotarization::new(object_id).with_clock(self.read_client.clock())),
+            self.gas_budget_policy.destroy(),
+        )
+    }
+
+    /// Destroys a notarization, unless its state changed since it was last read.
+    ///
+    /// Works like [`NotarizationClient::destroy`], but the destruction only goes ahead if the
+    /// [content hash](State::content_hash) of the current state is `expected_hash`, e.g. in cleanup
+    /// automation that must not destroy a record someone updated in the meantime. The
+    /// transaction is bound to the object version that was checked, so an update between the
+    /// check and the execution fails the transaction on-chain.
+    ///
+    /// ## Errors
+    ///
+    /// Building the transaction fails with [`Error::VersionConflict`] if the content hash of the
+    /// current state differs from `expected_hash`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use iota_interaction::types::base_types::ObjectID;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, object_id: ObjectID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let expected_hash = client.get_notarization_by_id(object_id).await?.state.content_hash();
+    /// // ... review the notarization ...
+    /// client
+    ///     .destroy_if_state_hash(object_id, expected_hash)
+    ///     .build_and_execute(&client)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn destroy_if_state_hash(
+        &self,
+        object_id: ObjectID,
+        expected_hash: [u8; 32],
+    ) -> TransactionBuilder<DestroyNotarization> {
+        with_default_gas_budget(
+            TransactionBuilder::new(
+                DestroyNotarization::new(object_id)
+                    .with_expected_state_hash(expected_hash)
+                    .with_clock(self.read_client.clock()),
+            ),
+            self.gas_budget_policy.destroy(),
+        )
+    }
+
+    /// Destroys several notarizations in a single transaction.
+    ///
+    /// The batch is atomic: if any of the notarizations cannot be destroyed (e.g. because of an
+    /// active lock, or because it is not owned by the sender), the whole transaction fails and
+    /// none of them is destroyed. Use [`NotarizationClient::destroy_batch_allowed`] to leave out
+    /// the notarizations that cannot be destroyed yet.
+    ///
+    /// ## Parameters
+    ///
+    /// - `object_ids`: The IDs of the notarizations to destroy, without duplicates
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use iota_interaction::types::base_types::ObjectID;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, object_ids: Vec<ObjectID>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let destroyed = client
+    ///     .destroy_batch(object_ids)
+    ///     .build_and_execute(&client)
+    ///     .await?
+    ///     .output;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns a [`TransactionBuilder`] whose output are the IDs of the destroyed notarizations.
+    pub fn destroy_batch(&self, object_ids: Vec<ObjectID>) -> TransactionBuilder<DestroyNotarizationBatch> {
+        let gas_budget = self
+            .gas_budget_policy
+            .destroy()
+            .map(|gas_budget| gas_budget.saturating_mul(object_ids.len() as u64));
+        with_default_gas_budget(
+            TransactionBuilder::new(DestroyNotarizationBatch::new(object_ids).with_clock(self.read_client.clock())),
+            gas_budget,
+        )
+    }
+
+    /// Like [`NotarizationClient::destroy_batch`], but leaves out the notarizations that cannot
+    /// be destroyed at the moment.
+    ///
+    /// The notarizations and the clock are read once, and every notarization whose locks
+    /// currently prevent its destruction, or that does not exist (anymore), is skipped. Compare
+    /// the output of the executed transaction with `object_ids` to find the skipped ones.
+    ///
+    /// The locks are evaluated when this method is called; a lock that is still active when the
+    /// transaction executes fails the whole batch.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the notarizations or the clock cannot be read.
+    ///
+    /// Returns `None` if none of the notarizations can be destroyed.
+    pub async fn destroy_batch_allowed(
+        &self,
+        object_ids: Vec<ObjectID>,
+    ) -> Result<Option<TransactionBuilder<DestroyNotarizationBatch>>, Error> {
+        let notarizations = self.get_notarizations_by_ids(&object_ids).await?;
+        let now = self.now_ts().await?;
+
+        let destroyable = object_ids
+            .into_iter()
+            .zip(notarizations)
+            .filter(|(_, notarization)| {
+                notarization
+                    .as_ref()
+                    .is_some_and(|notarization| notarization.lock_status_at(now).is_destroy_allowed)
+            })
+            .map(|(object_id, _)| object_id)
+            .collect::<Vec<_>>();
+
+        Ok((!destroyable.is_empty()).then(|| self.destroy_batch(destroyable)))
     }
 
     /// Updates the metadata of a dynamic notarization.
@@ -304,7 +829,10 @@ where
     ///
     /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
     pub fn update_metadata(&self, metadata: Option<String>, object_id: ObjectID) -> TransactionBuilder<UpdateMetadata> {
-        TransactionBuilder::new(UpdateMetadata::new(metadata, object_id))
+        with_default_gas_budget(
+            TransactionBuilder::new(UpdateMetadata::new(metadata, object_id).with_clock(self.read_client.clock())),
+            self.gas_budget_policy.update(),
+        )
     }
 
     /// Transfers ownership of a dynamic notarization.
@@ -332,12 +860,529 @@ where
     /// ```
     ///
     /// Returns a [`TransactionBuilder`]. See [module docs](self) for transaction flow.
+    ///
+    /// Building the transaction fails with [`Error::InvalidArgument`] if `recipient` is the
+    /// zero address or the address of this client.
     pub fn transfer_notarization(
         &self,
         object_id: ObjectID,
         recipient: IotaAddress,
     ) -> TransactionBuilder<TransferNotarization> {
-        TransactionBuilder::new(TransferNotarization::new(recipient, object_id))
+        with_default_gas_budget(
+            TransactionBuilder::new(
+                TransferNotarization::new(recipient, object_id)
+                    .with_sender(IotaAddress::from(&self.public_key))
+                    .with_clock(self.read_client.clock()),
+            ),
+            self.gas_budget_policy.transfer(),
+        )
+    }
+
+    /// Transfers a dynamic notarization to a new owner, recording `reason` in the same
+    /// transaction.
+    ///
+    /// The contract cannot store a transfer reason, so the updatable metadata of the
+    /// notarization is replaced with `reason` atomically with the transfer (see
+    /// [`TransferNotarization::with_reason`]).
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// client
+    ///     .transfer_notarization_with_reason(object_id, recipient, "Ownership moved to the archive team".to_string())
+    ///     .build_and_execute(&client)
+    ///     .await?;
+    /// ```
+    pub fn transfer_notarization_with_reason(
+        &self,
+        object_id: ObjectID,
+        recipient: IotaAddress,
+        reason: String,
+    ) -> TransactionBuilder<TransferNotarization> {
+        with_default_gas_budget(
+            TransactionBuilder::new(
+                TransferNotarization::new(recipient, object_id)
+                    .with_sender(IotaAddress::from(&self.public_key))
+                    .with_reason(reason)
+                    .with_clock(self.read_client.clock()),
+            ),
+            self.gas_budget_policy.transfer(),
+        )
+    }
+
+    /// Transfers a dynamic notarization and confirms that `recipient` owns it afterwards.
+    ///
+    /// The transfer is executed with [`Confirmation::Readable`], then the owner of the
+    /// notarization is read back. Use this in custody workflows where a transfer that did not
+    /// take effect as expected must not go unnoticed.
+    ///
+    /// ## Errors
+    ///
+    /// Fails like [`NotarizationClient::transfer_notarization`], and with
+    /// [`Error::TransactionUnexpectedResponse`] if the transfer was executed but the notarization
+    /// is not owned by `recipient` afterwards.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use iota_interaction::types::base_types::{ObjectID, IotaAddress};
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, object_id: ObjectID, recipient: IotaAddress) -> Result<(), Box<dyn std::error::Error>> {
+    /// client.transfer_notarization_confirmed(object_id, recipient).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn transfer_notarization_confirmed(
+        &self,
+        object_id: ObjectID,
+        recipient: IotaAddress,
+    ) -> Result<TransactionOutput<NotarizationTransferred>, Error> {
+        let output = self
+            .transfer_notarization(object_id, recipient)
+            .with_confirmation(Confirmation::Readable)
+            .build_and_execute(self)
+            .await?;
+
+        let owner = self.owner(object_id).await?;
+        if owner != Owner::AddressOwner(recipient) {
+            return Err(Error::TransactionUnexpectedResponse(format!(
+                "notarization {object_id} was transferred to {recipient}, but is owned by {owner}"
+            )));
+        }
+
+        Ok(output)
+    }
+
+    /// Destroys the notarizations of `owner` whose auto-destroy intent has expired.
+    ///
+    /// The notarizations of `owner` are read page by page, and the intents are read from their
+    /// immutable metadata, where they are recorded with
+    /// [`NotarizationBuilder::with_auto_destroy_after`]. Notarizations that are past their
+    /// intended expiry but cannot be destroyed yet (e.g. because of an active delete lock) are
+    /// skipped and picked up by a later call.
+    ///
+    /// ## Parameters
+    ///
+    /// - `owner`: The owner of the notarizations; must be the sender of this client
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `owner` is not the sender of this client, or if a lookup or a
+    /// destroy transaction fails.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use product_common::core_client::CoreClient;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let destroyed = client.destroy_expired(client.sender_address()).await?;
+    /// println!("reaped {} expired notarizations", destroyed.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Returns the [`ObjectID`]s of the destroyed notarizations.
+    pub async fn destroy_expired(&self, owner: IotaAddress) -> Result<Vec<ObjectID>, Error> {
+        if owner != self.sender_address() {
+            return Err(Error::InvalidArgument(format!(
+                "only notarizations owned by the sender {} can be destroyed, not those of {owner}",
+                self.sender_address()
+            )));
+        }
+
+        let now = self.now_ts().await?;
+        let mut expired = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self.get_notarizations_owned_by_paginated(owner, cursor, None).await?;
+            expired.extend(
+                page.data
+                    .into_iter()
+                    .filter(|notarization| {
+                        notarization
+                            .immutable_metadata
+                            .auto_destroy_after()
+                            .is_some_and(|expires_at| u64::from(expires_at) <= now)
+                            && notarization.is_destroy_allowed_at(now)
+                    })
+                    .map(|notarization| *notarization.id.object_id()),
+            );
+
+            if !page.has_next_page {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        let mut destroyed = Vec::new();
+        for object_id in expired {
+            self.destroy(object_id)
+                .build_and_execute(self)
+                .await
+                .map_err(|e| Error::GenericError(format!("failed to destroy expired notarization {object_id}: {e}")))?;
+            destroyed.push(object_id);
+        }
+
+        Ok(destroyed)
+    }
+
+    /// Creates a notarization unless one with the same dedupe tag already exists.
+    ///
+    /// `tag` is a deterministic, caller-supplied key, e.g. derived from the ingested record.
+    /// It is recorded in the immutable description with [`NotarizationBuilder::with_dedupe_tag`].
+    /// This makes retries of at-least-once pipelines safe without an external store.
+    ///
+    /// ## Matching
+    ///
+    /// The notarizations owned by the sender are searched for one whose
+    /// [`dedupe_tag`](crate::core::types::ImmutableMetadata::dedupe_tag) equals `tag` and whose
+    /// method matches the builder's. The first match is returned as
+    /// [`DedupedNotarization::Existing`]. The state and the remaining metadata are not compared.
+    /// If there is no match, the notarization is created and returned as
+    /// [`DedupedNotarization::Created`].
+    ///
+    /// ## Race window
+    ///
+    /// The lookup and the creation are separate steps, so the check is not atomic:
+    /// - Concurrent calls with the same tag can both miss and both create a notarization.
+    /// - A creation is only found once the node has indexed it. Calling again right after a submission that timed out
+    ///   can create a duplicate. Executing with [`Confirmation::Readable`](super::Confirmation::Readable) narrows this
+    ///   window.
+    /// - Notarizations that were transferred away are no longer found.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if `tag` is empty or spans several lines, and an error
+    /// if the lookup or the creation fails.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>) -> Result<(), Box<dyn std::error::Error>> {
+    /// let builder = client
+    ///     .create_dynamic_notarization()
+    ///     .with_string_state("Invoice 42".to_string(), None);
+    ///
+    /// let notarization = client.create_if_absent(builder, "invoice-42".to_string()).await?;
+    /// if !notarization.is_created() {
+    ///     println!("invoice 42 was already notarized");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_if_absent<M>(
+        &self,
+        builder: NotarizationBuilder<M>,
+        tag: String,
+    ) -> Result<DedupedNotarization, Error>
+    where
+        M: Clone + OptionalSend + OptionalSync,
+    {
+        let existing = self
+            .get_notarizations_owned_by(self.sender_address())
+            .await?
+            .into_iter()
+            .find(|notarization| {
+                notarization.method == builder.method
+                    && notarization.immutable_metadata.dedupe_tag() == Some(tag.as_str())
+            });
+        if let Some(notarization) = existing {
+            return Ok(DedupedNotarization::Existing(notarization));
+        }
+
+        let builder = builder.with_dedupe_tag(tag);
+        let gas_budget = builder.gas_budget();
+        let notarization =
+            with_default_gas_budget(TransactionBuilder::new(CreateNotarization::new(builder)), gas_budget)
+                .build_and_execute(self)
+                .await?
+                .output;
+
+        Ok(DedupedNotarization::Created(notarization))
+    }
+
+    /// Creates a large number of notarizations in chunks, reporting the progress after every
+    /// chunk so that an interrupted import can be resumed.
+    ///
+    /// The items are created in chunks of [`ImportOptions::with_chunk_size`] notarizations, one
+    /// batch transaction per chunk. Chunks are submitted one after another, since transactions
+    /// of the same sender in flight at the same time would compete for its gas coin.
+    ///
+    /// ## Per-item errors
+    ///
+    /// Invalid items (see [`NotarizationBuilder::validate`]) are left out of their chunk. If a
+    /// chunk transaction is not executed, because it cannot be built, does not land or fails
+    /// on-chain, its items are retried one by one to isolate the failing ones. Failed items are
+    /// reported with their index in the checkpoints and in the returned [`ImportReport`]; they do
+    /// not abort the import.
+    ///
+    /// ## Resuming
+    ///
+    /// `checkpoint` is called after each chunk was executed, with the IDs of the created
+    /// notarizations and the index of the first item of the next chunk. Persist
+    /// [`ImportCheckpoint::next_index`] and pass it to [`ImportOptions::with_skip`] when
+    /// restarting the import with the same items.
+    ///
+    /// Progress is at-least-once: if the import is interrupted while a chunk is in flight, the
+    /// chunk may have been created without a checkpoint and is created again when resuming.
+    /// To resume without duplicates, create the items of such a chunk with
+    /// [`create_if_absent`](Self::create_if_absent) and a tag per item instead.
+    ///
+    /// ## Errors
+    ///
+    /// - Returns the error of reading the output of an executed transaction, e.g. because the node lags behind. The
+    ///   notarizations of its items were created nonetheless, without a checkpoint.
+    /// - Returns the error of `checkpoint`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::{ImportOptions, full_client::NotarizationClient};
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, records: Vec<String>, progress: &mut Progress) -> Result<(), Box<dyn std::error::Error>> {
+    /// let builders = records
+    ///     .into_iter()
+    ///     .map(|record| client.create_dynamic_notarization().with_string_state(record, None))
+    ///     .collect();
+    ///
+    /// let options = ImportOptions::new().with_skip(progress.next_index());
+    /// let report = client
+    ///     .import_notarizations(builders, options, |checkpoint| progress.save(checkpoint))
+    ///     .await?;
+    /// println!("{} created, {} failed", report.created.len(), report.failed.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn import_notarizations<M, F>(
+        &self,
+        items: Vec<NotarizationBuilder<M>>,
+        options: ImportOptions,
+        mut checkpoint: F,
+    ) -> Result<ImportReport, Error>
+    where
+        M: Clone + OptionalSend + OptionalSync,
+        F: FnMut(ImportCheckpoint<'_>) -> Result<(), Error>,
+    {
+        let mut report = ImportReport::default();
+        let mut items = items.into_iter().enumerate().skip(options.skip).peekable();
+
+        while items.peek().is_some() {
+            let chunk = items.by_ref().take(options.chunk_size.max(1)).collect::<Vec<_>>();
+            let next_index = chunk.last().map_or(options.skip, |(index, _)| index + 1);
+
+            let mut created = Vec::new();
+            let mut failed = Vec::new();
+            let mut valid = Vec::new();
+            for (index, builder) in chunk {
+                match builder.validate() {
+                    Ok(()) => valid.push((index, builder)),
+                    Err(e) => failed.push((index, e)),
+                }
+            }
+
+            if !valid.is_empty() {
+                let builders = valid.iter().map(|(_, builder)| builder.clone()).collect();
+                match execution::execute(self.create_batch(builders), self).await {
+                    // The chunk was created, so it must not be created again even if its output
+                    // cannot be read.
+                    Execution::Executed(output) => {
+                        let output = output?;
+                        created.extend(
+                            valid
+                                .iter()
+                                .map(|(index, _)| *index)
+                                .zip(output.output.iter().map(|notarization| *notarization.id.object_id())),
+                        )
+                    }
+                    // The failure of a single item is its own.
+                    Execution::Failed(error) if valid.len() == 1 => failed.push((valid[0].0, error)),
+                    // Create the items one by one to find out which ones fail.
+                    Execution::Failed(error) => {
+                        #[cfg(feature = "tracing")]
+                        tracing::debug!(%error, "import chunk was not executed, creating its items one by one");
+                        #[cfg(not(feature = "tracing"))]
+                        let _ = error;
+                        for (index, builder) in valid {
+                            match execution::execute(self.create_batch(vec![builder]), self).await {
+                                Execution::Executed(output) => {
+                                    let output = output?;
+                                    created.extend(
+                                        output
+                                            .output
+                                            .first()
+                                            .map(|notarization| (index, *notarization.id.object_id())),
+                                    )
+                                }
+                                Execution::Failed(e) => failed.push((index, e)),
+                            }
+                        }
+                    }
+                }
+            }
+
+            checkpoint(ImportCheckpoint {
+                next_index,
+                created: &created,
+                failed: &failed,
+            })?;
+            report.created.extend(created);
+            report.failed.extend(failed);
+        }
+
+        Ok(report)
+    }
+
+    /// Estimates the gas cost of a transaction without executing it.
+    ///
+    /// The transaction is inspected on the node with this client's address as sender, so the
+    /// estimate reflects the objects the sender owns. The built transaction is cached, hence
+    /// `tx` can be wrapped into a [`TransactionBuilder`] afterwards to execute it.
+    ///
+    /// ## Parameters
+    ///
+    /// - `tx`: The transaction to estimate, e.g. [`UpdateState`] or [`DestroyNotarization`]
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the transaction cannot be built or would fail on-chain, e.g. because
+    /// of an active time lock.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// # use notarization::client::full_client::NotarizationClient;
+    /// # use notarization::core::transactions::UpdateState;
+    /// # use notarization::core::types::State;
+    /// # use iota_interaction::types::base_types::ObjectID;
+    /// # use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+    /// # async fn example(client: &NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>, object_id: ObjectID) -> Result<(), Box<dyn std::error::Error>> {
+    /// let update = UpdateState::new(State::from_string("Status: Completed".to_string(), None), object_id);
+    /// let estimate = client.estimate_gas(&update).await?;
+    /// println!("estimated cost: {} NANOS", estimate.net_cost());
+    ///
+    /// TransactionBuilder::new(update).build_and_execute(&client).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn estimate_gas<Tx>(&self, tx: &Tx) -> Result<GasEstimate, Error>
+    where
+        Tx: Transaction<Error = Error> + OptionalSync,
+    {
+        let ptb = tx.build_programmable_transaction(self).await?;
+
+        let inspection_result = self
+            .client_adapter()
+            .read_api()
+            .dev_inspect_transaction_block(
+                self.sender_address(),
+                TransactionKind::programmable(ptb),
+                None,
+                None,
+                None,
+            )
+            .await
+            .map_err(|err| Error::UnexpectedApiResponse(format!("Failed to inspect transaction block: {err}")))?;
+
+        if let Some(error) = inspection_result.error {
+            return Err(match Error::from_execution_failure(&error) {
+                locked @ Error::Locked { .. } => locked,
+                _ => Error::TransactionUnexpectedResponse(format!("transaction would fail on-chain: {error}")),
+            });
+        }
+
+        Ok(GasEstimate::from(inspection_result.effects.gas_cost_summary()))
+    }
+}
+
+/// The default number of notarizations created per transaction by
+/// [`NotarizationClient::import_notarizations`].
+pub const DEFAULT_IMPORT_CHUNK_SIZE: usize = 50;
+
+/// The options of [`NotarizationClient::import_notarizations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ImportOptions {
+    chunk_size: usize,
+    skip: usize,
+}
+
+impl Default for ImportOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_IMPORT_CHUNK_SIZE,
+            skip: 0,
+        }
+    }
+}
+
+impl ImportOptions {
+    /// Creates options importing all items in chunks of [`DEFAULT_IMPORT_CHUNK_SIZE`]
+    /// notarizations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of notarizations created per transaction; at least one.
+    pub fn with_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size;
+        self
+    }
+
+    /// Skips the first `skip` items, e.g. the [`ImportCheckpoint::next_index`] persisted by an
+    /// interrupted import.
+    pub fn with_skip(mut self, skip: usize) -> Self {
+        self.skip = skip;
+        self
+    }
+}
+
+/// The progress of [`NotarizationClient::import_notarizations`] after a chunk.
+#[derive(Debug)]
+pub struct ImportCheckpoint<'a> {
+    /// The index of the first item that was not processed yet; the import resumes from here.
+    pub next_index: usize,
+    /// The indices of the items of the chunk that were created, with the created notarizations.
+    pub created: &'a [(usize, ObjectID)],
+    /// The indices of the items of the chunk that failed, with their errors.
+    pub failed: &'a [(usize, Error)],
+}
+
+/// The outcome of [`NotarizationClient::import_notarizations`].
+#[derive(Debug, Default)]
+pub struct ImportReport {
+    /// The indices of the created items, with the created notarizations.
+    pub created: Vec<(usize, ObjectID)>,
+    /// The indices of the failed items, with their errors.
+    pub failed: Vec<(usize, Error)>,
+}
+
+/// The outcome of [`NotarizationClient::create_if_absent`].
+#[derive(Debug, Clone)]
+pub enum DedupedNotarization {
+    /// The notarization was created by this call.
+    Created(OnChainNotarization),
+    /// This client already created a notarization with the same content; nothing was created.
+    Existing(OnChainNotarization),
+}
+
+impl DedupedNotarization {
+    /// Returns `true` if the notarization was created by this call.
+    pub fn is_created(&self) -> bool {
+        matches!(self, Self::Created(_))
+    }
+
+    /// Returns the created or existing notarization.
+    pub fn notarization(&self) -> &OnChainNotarization {
+        match self {
+            Self::Created(notarization) | Self::Existing(notarization) => notarization,
+        }
+    }
+
+    /// Consumes the outcome, returning the created or existing notarization.
+    pub fn into_notarization(self) -> OnChainNotarization {
+        match self {
+            Self::Created(notarization) | Self::Existing(notarization) => notarization,
+        }
     }
 }
 
@@ -367,7 +1412,7 @@ where
     }
 
     fn signer(&self) -> &S {
-        &self.signer
+        self.signer.as_ref()
     }
 
     fn sender_public_key(&self) -> &PublicKey {
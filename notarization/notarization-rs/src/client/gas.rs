@@ -0,0 +1,250 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Gas Budget Defaults
+//!
+//! Per-operation default gas budgets of [`NotarizationClient`](super::NotarizationClient), and the
+//! selection of gas coins.
+//!
+//! ## Overview
+//!
+//! Without a policy, the gas budget of every transaction is estimated by a dry run when it is
+//! built. A [`GasBudgetPolicy`] replaces the estimation with fixed budgets per kind of operation,
+//! or, [opting in](GasBudgetPolicy::with_size_scaled_budgets), with budgets of creations and state
+//! updates scaled with the size of the notarized data (see
+//! [`suggested_gas_budget`](crate::core::types::suggested_gas_budget)). A budget set with
+//! `with_gas_budget` on a transaction builder still takes precedence.
+//!
+//! ```rust,ignore
+//! # use notarization::client::GasBudgetPolicy;
+//! # fn example(client: notarization::NotarizationClient<impl secret_storage::Signer<iota_interaction::IotaKeySignature>>) {
+//! let client = client.with_gas_budget_policy(
+//!     GasBudgetPolicy::new()
+//!         .with_create(50_000_000)
+//!         .with_transfer(5_000_000),
+//! );
+//! # }
+//! ```
+
+use iota_interaction::types::base_types::{IotaAddress, ObjectRef};
+use iota_interaction::{IotaClientTrait, OptionalSync};
+use product_common::core_client::CoreClientReadOnly;
+
+use crate::core::types::State;
+use crate::error::Error;
+
+/// The maximum number of coins of a gas payment.
+const MAX_GAS_PAYMENT_COINS: usize = 256;
+
+/// Default gas budgets per kind of operation.
+///
+/// Operations without a budget keep the default behavior of the client.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GasBudgetPolicy {
+    create: Option<u64>,
+    update: Option<u64>,
+    transfer: Option<u64>,
+    destroy: Option<u64>,
+    scale_with_size: bool,
+}
+
+impl GasBudgetPolicy {
+    /// Creates a policy without any budget, keeping the default behavior for all operations.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the budget of creations, per created notarization.
+    ///
+    /// Batch creations start with this budget multiplied by the number of notarizations.
+    pub fn with_create(mut self, budget: u64) -> Self {
+        self.create = Some(budget);
+        self
+    }
+
+    /// Sets the budget of state and metadata updates.
+    pub fn with_update(mut self, budget: u64) -> Self {
+        self.update = Some(budget);
+        self
+    }
+
+    /// Sets the budget of transfers.
+    pub fn with_transfer(mut self, budget: u64) -> Self {
+        self.transfer = Some(budget);
+        self
+    }
+
+    /// Sets the budget of destructions, per destroyed notarization.
+    ///
+    /// Batch destructions start with this budget multiplied by the number of notarizations.
+    pub fn with_destroy(mut self, budget: u64) -> Self {
+        self.destroy = Some(budget);
+        self
+    }
+
+    /// Starts creations and state updates without a fixed budget with a budget scaled with the
+    /// size of the notarized data, instead of estimating it by a dry run.
+    ///
+    /// The scaled budget errs on the generous side, so that large states do not run out of gas;
+    /// only the gas actually used is charged.
+    pub fn with_size_scaled_budgets(mut self) -> Self {
+        self.scale_with_size = true;
+        self
+    }
+
+    /// Returns the budget of creations, per created notarization.
+    pub fn create(&self) -> Option<u64> {
+        self.create
+    }
+
+    /// Returns the budget of state and metadata updates.
+    pub fn update(&self) -> Option<u64> {
+        self.update
+    }
+
+    /// Returns the budget of transfers.
+    pub fn transfer(&self) -> Option<u64> {
+        self.transfer
+    }
+
+    /// Returns the budget of destructions, per destroyed notarization.
+    pub fn destroy(&self) -> Option<u64> {
+        self.destroy
+    }
+
+    /// Indicates if creations and state updates without a fixed budget start with a budget
+    /// scaled with the size of the notarized data.
+    pub fn scales_with_size(&self) -> bool {
+        self.scale_with_size
+    }
+
+    /// Returns the budget of a state update storing `state`: the fixed update budget if set,
+    /// otherwise the scaled one if [opted in](Self::with_size_scaled_budgets).
+    pub(crate) fn update_for(&self, state: &State) -> Option<u64> {
+        self.update
+            .or_else(|| self.scale_with_size.then(|| state.suggested_gas_budget()))
+    }
+}
+
+/// Returns the IOTA coins of `owner` with their balances.
+///
+/// The coins are read page by page, until the coins read so far can pay `budget` NANOS; without
+/// a budget, all coins are read.
+pub(crate) async fn gas_coins_of<C>(
+    client: &C,
+    owner: IotaAddress,
+    budget: Option<u64>,
+) -> Result<Vec<(ObjectRef, u64)>, Error>
+where
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let mut coins = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = client
+            .client_adapter()
+            .coin_read_api()
+            .get_coins(owner, None, cursor, None)
+            .await
+            .map_err(|e| Error::RpcError(format!("failed to get the coins of {owner}: {e}")))?;
+        coins.extend(page.data.into_iter().map(|coin| (coin.object_ref(), coin.balance)));
+        if !page.has_next_page || budget.is_some_and(|budget| payable_balance(&coins) >= budget) {
+            return Ok(coins);
+        }
+        cursor = page.next_cursor;
+    }
+}
+
+/// Returns the balance that `coins`, given with their balances, can pay in one gas payment,
+/// i.e. the balance of the largest [`MAX_GAS_PAYMENT_COINS`] coins.
+pub(crate) fn payable_balance(coins: &[(ObjectRef, u64)]) -> u64 {
+    let mut balances: Vec<u64> = coins.iter().map(|(_, balance)| *balance).collect();
+    balances.sort_unstable_by(|a, b| b.cmp(a));
+    balances
+        .into_iter()
+        .take(MAX_GAS_PAYMENT_COINS)
+        .fold(0, u64::saturating_add)
+}
+
+/// Selects coins of `owner` covering a gas budget of `budget` NANOS out of `coins`, given with
+/// their balances.
+///
+/// The largest coins are picked first, so that as few coins as possible are used; the network
+/// merges the coins of a gas payment into the first one. At most [`MAX_GAS_PAYMENT_COINS`] coins
+/// are picked.
+pub(crate) fn select_gas_coins(
+    owner: IotaAddress,
+    mut coins: Vec<(ObjectRef, u64)>,
+    budget: u64,
+) -> Result<Vec<ObjectRef>, Error> {
+    coins.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+    let mut selected = Vec::new();
+    let mut available = 0u64;
+    for (coin, balance) in coins.into_iter().take(MAX_GAS_PAYMENT_COINS) {
+        if available >= budget && !selected.is_empty() {
+            break;
+        }
+        selected.push(coin);
+        available = available.saturating_add(balance);
+    }
+
+    if available < budget || selected.is_empty() {
+        return Err(Error::InsufficientGas {
+            owner,
+            required: Some(budget),
+            available,
+        });
+    }
+
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::base_types::{ObjectDigest, ObjectID, SequenceNumber};
+
+    use super::*;
+
+    fn coin(byte: u8, balance: u64) -> (ObjectRef, u64) {
+        let coin = (
+            ObjectID::from_single_byte(byte),
+            SequenceNumber::from_u64(1),
+            ObjectDigest::new([byte; 32]),
+        );
+        (coin, balance)
+    }
+
+    #[test]
+    fn test_largest_coins_are_selected_first() {
+        let coins = vec![coin(1, 10), coin(2, 50), coin(3, 30)];
+        let selected = select_gas_coins(IotaAddress::ZERO, coins.clone(), 60).unwrap();
+
+        assert_eq!(selected, vec![coins[1].0, coins[2].0]);
+    }
+
+    #[test]
+    fn test_insufficient_balance_is_reported() {
+        let result = select_gas_coins(IotaAddress::ZERO, vec![coin(1, 10), coin(2, 20)], 100);
+        assert!(matches!(
+            result,
+            Err(Error::InsufficientGas {
+                required: Some(100),
+                available: 30,
+                ..
+            })
+        ));
+
+        let result = select_gas_coins(IotaAddress::ZERO, vec![], 0);
+        assert!(matches!(result, Err(Error::InsufficientGas { available: 0, .. })));
+    }
+
+    #[test]
+    fn test_payable_balance_is_capped_by_the_payment_size() {
+        let mut coins = vec![coin(1, 1_000)];
+        coins.extend((0..MAX_GAS_PAYMENT_COINS).map(|_| coin(2, 1)));
+
+        assert_eq!(payable_balance(&coins), 1_000 + MAX_GAS_PAYMENT_COINS as u64 - 1);
+        assert_eq!(payable_balance(&[]), 0);
+    }
+}
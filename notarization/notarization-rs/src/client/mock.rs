@@ -0,0 +1,142 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Mock Client
+//!
+//! An in-memory [`NotarizationReader`] for unit tests, available with the `test-utils` feature.
+//!
+//! ## Overview
+//!
+//! [`MockNotarizationClient`] serves pre-seeded [`OnChainNotarization`] fixtures, keyed by their
+//! [`ObjectID`], and evaluates their locks against a configurable clock. It needs neither a node
+//! nor a network connection, so code written against [`NotarizationReader`] can be tested
+//! deterministically and offline.
+//!
+//! ```rust,ignore
+//! # use notarization::client::{MockNotarizationClient, NotarizationReader};
+//! # async fn example(fixture: notarization::core::types::OnChainNotarization) -> Result<(), notarization::error::Error> {
+//! let id = *fixture.id.object_id();
+//! let client = MockNotarizationClient::new()
+//!     .with_notarization(fixture)
+//!     .with_clock_timestamp_ms(1_700_000_000_000);
+//!
+//! assert!(!client.is_update_locked(id).await?);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use iota_interaction::types::base_types::ObjectID;
+
+use super::reader::NotarizationReader;
+use crate::core::types::OnChainNotarization;
+use crate::error::Error;
+
+/// An in-memory [`NotarizationReader`] serving pre-seeded notarizations.
+#[derive(Debug, Clone, Default)]
+pub struct MockNotarizationClient {
+    notarizations: HashMap<ObjectID, OnChainNotarization>,
+    clock_timestamp_ms: u64,
+}
+
+impl MockNotarizationClient {
+    /// Creates a mock client without notarizations, whose clock is at the Unix epoch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `notarization`, keyed by its ID, replacing any notarization with the same ID.
+    pub fn with_notarization(mut self, notarization: OnChainNotarization) -> Self {
+        self.insert(notarization);
+        self
+    }
+
+    /// Sets the time of the mock network, in milliseconds since the Unix epoch.
+    pub fn with_clock_timestamp_ms(mut self, clock_timestamp_ms: u64) -> Self {
+        self.clock_timestamp_ms = clock_timestamp_ms;
+        self
+    }
+
+    /// Adds `notarization`, keyed by its ID, and returns the notarization it replaced, if any.
+    pub fn insert(&mut self, notarization: OnChainNotarization) -> Option<OnChainNotarization> {
+        self.notarizations.insert(*notarization.id.object_id(), notarization)
+    }
+
+    /// Removes the notarization `notarized_object_id`, as if it was destroyed.
+    pub fn remove(&mut self, notarized_object_id: ObjectID) -> Option<OnChainNotarization> {
+        self.notarizations.remove(&notarized_object_id)
+    }
+
+    /// Moves the clock of the mock network to `clock_timestamp_ms`.
+    pub fn set_clock_timestamp_ms(&mut self, clock_timestamp_ms: u64) {
+        self.clock_timestamp_ms = clock_timestamp_ms;
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl NotarizationReader for MockNotarizationClient {
+    /// Returns the seeded notarization, or fails with [`Error::ObjectLookup`] if there is none.
+    async fn get_notarization_by_id(&self, notarized_object_id: ObjectID) -> Result<OnChainNotarization, Error> {
+        self.notarizations
+            .get(&notarized_object_id)
+            .cloned()
+            .ok_or_else(|| Error::ObjectLookup(format!("notarization {notarized_object_id} does not exist")))
+    }
+
+    async fn clock_timestamp_ms(&self) -> Result<u64, Error> {
+        Ok(self.clock_timestamp_ms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{LockMetadata, NotarizationMethod, State, TimeLock, fixtures};
+
+    fn notarization(transfer_lock: TimeLock) -> OnChainNotarization {
+        let mut notarization = fixtures::notarization(
+            NotarizationMethod::Dynamic,
+            Some(LockMetadata {
+                update_lock: TimeLock::None,
+                delete_lock: TimeLock::None,
+                transfer_lock,
+            }),
+        );
+        notarization.state = State::from_string("fixture".to_string(), None);
+        notarization.immutable_metadata.description = Some("Fixture".to_string());
+        notarization.state_version_count = 3;
+        notarization
+    }
+
+    #[tokio::test]
+    async fn test_serves_seeded_notarizations() {
+        let id = ObjectID::from_single_byte(1);
+        let client = MockNotarizationClient::new().with_notarization(notarization(TimeLock::UnlockAt(100)));
+
+        assert_eq!(client.state_version_count(id).await.unwrap(), 3);
+        assert_eq!(client.description(id).await.unwrap(), Some("Fixture".to_string()));
+        assert_eq!(client.state(id).await.unwrap().data.as_text().unwrap(), "fixture");
+        assert!(matches!(
+            client.get_notarization_by_id(ObjectID::from_single_byte(2)).await,
+            Err(Error::ObjectLookup(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_locks_follow_the_clock() {
+        let id = ObjectID::from_single_byte(1);
+        let mut client = MockNotarizationClient::new()
+            .with_notarization(notarization(TimeLock::UnlockAt(100)))
+            .with_clock_timestamp_ms(50_000);
+
+        assert!(client.is_transfer_locked(id).await.unwrap());
+        assert!(!client.is_destroy_allowed(id).await.unwrap());
+
+        client.set_clock_timestamp_ms(100_000);
+        assert!(!client.is_transfer_locked(id).await.unwrap());
+        assert!(client.available_actions(id).await.unwrap().can_destroy);
+    }
+}
@@ -6,18 +6,39 @@
 //! This module provides two client types:
 //! - [`read_only`]: Read-only access to notarization data
 //! - [`full_client`]: Full read-write access with transaction capabilities
+//!
+//! Execution strategies for the returned transactions live in [`execution`], the default gas
+//! budgets per operation in [`gas`], the retry policy of read calls in [`retry`] and the
+//! well-known networks in [`network`]. Code that only reads notarizations can be written against
+//! [`NotarizationReader`], which an in-memory mock implements with the `test-utils` feature.
 
 use iota_interaction::IotaClientTrait;
+#[cfg(not(target_arch = "wasm32"))]
+use iota_sdk::{IotaClient, IotaClientBuilder};
 use product_common::network_name::NetworkName;
 
 use crate::error::Error;
 use crate::iota_interaction_adapter::IotaClientAdapter;
 
+pub mod execution;
 pub mod full_client;
+pub mod gas;
+#[cfg(feature = "test-utils")]
+pub mod mock;
+pub mod network;
 pub mod read_only;
+pub mod reader;
+pub mod retry;
 
+pub use execution::*;
 pub use full_client::*;
+pub use gas::GasBudgetPolicy;
+#[cfg(feature = "test-utils")]
+pub use mock::MockNotarizationClient;
+pub use network::Network;
 pub use read_only::*;
+pub use reader::NotarizationReader;
+pub use retry::RetryPolicy;
 
 /// Returns the network-id also known as chain-identifier provided by the specified iota_client
 async fn network_id(iota_client: &IotaClientAdapter) -> Result<NetworkName, Error> {
@@ -28,3 +49,19 @@ async fn network_id(iota_client: &IotaClientAdapter) -> Result<NetworkName, Erro
         .map_err(|e| Error::RpcError(e.to_string()))?;
     Ok(network_id.try_into().expect("chain ID is a valid network name"))
 }
+
+/// Connects to the public endpoint of the network named `network`.
+///
+/// Well-known names (see [`Network::from_name`]) are mapped to their public RPC endpoints.
+/// Any other name is treated as the chain identifier of a local network.
+#[cfg(not(target_arch = "wasm32"))]
+async fn connect(network: &NetworkName) -> Result<IotaClient, Error> {
+    let endpoint = Network::from_name(network.as_ref())
+        .unwrap_or(Network::Localnet)
+        .endpoint();
+
+    IotaClientBuilder::default()
+        .build(endpoint)
+        .await
+        .map_err(|e| Error::RpcError(format!("failed to connect to network {network} at {endpoint}: {e}")))
+}
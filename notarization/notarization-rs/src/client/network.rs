@@ -0,0 +1,139 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Networks
+//!
+//! The well-known IOTA networks, with their friendly names and public endpoints.
+//!
+//! ## Overview
+//!
+//! A [`Network`] spares callers from building an `IotaClient` and from knowing the chain ID
+//! of a network: [`NotarizationClientReadOnly::connect`](super::NotarizationClientReadOnly::connect)
+//! connects to the public endpoint of the network and resolves the Notarization package from
+//! the package registry.
+//!
+//! ```rust,ignore
+//! # use notarization::NotarizationClientReadOnly;
+//! # use notarization::client::Network;
+//! # async fn example() -> Result<(), notarization::error::Error> {
+//! let client = NotarizationClientReadOnly::connect(Network::Testnet).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use product_common::network_name::NetworkName;
+use product_common::package_registry::MAINNET_CHAIN_ID;
+
+use crate::error::Error;
+
+/// A well-known IOTA network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Network {
+    /// The IOTA Mainnet.
+    Mainnet,
+    /// The public IOTA Testnet.
+    Testnet,
+    /// The public IOTA Devnet.
+    Devnet,
+    /// A local network, e.g. started with `iota start`.
+    Localnet,
+}
+
+impl Network {
+    /// Returns the friendly name of the network, as used by the package registry.
+    ///
+    /// The name of the Mainnet is `iota`.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Mainnet => "iota",
+            Self::Testnet => "testnet",
+            Self::Devnet => "devnet",
+            Self::Localnet => "localnet",
+        }
+    }
+
+    /// Returns the public RPC endpoint of the network.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub const fn endpoint(&self) -> &'static str {
+        match self {
+            Self::Mainnet => iota_sdk::IOTA_MAINNET_URL,
+            Self::Testnet => iota_sdk::IOTA_TESTNET_URL,
+            Self::Devnet => iota_sdk::IOTA_DEVNET_URL,
+            Self::Localnet => iota_sdk::IOTA_LOCAL_NETWORK_URL,
+        }
+    }
+
+    /// Returns the network with the friendly name `name`, accepting `mainnet` as an alias of `iota`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "iota" | "mainnet" => Some(Self::Mainnet),
+            "testnet" => Some(Self::Testnet),
+            "devnet" => Some(Self::Devnet),
+            "localnet" => Some(Self::Localnet),
+            _ => None,
+        }
+    }
+
+    /// Returns the network with the chain identifier `chain_id`.
+    ///
+    /// Only the chain ID of the Mainnet is fixed; the chain IDs of the other networks change
+    /// when they are reset and are resolved through the aliases of the package registry.
+    pub async fn from_chain_id(chain_id: &str) -> Option<Self> {
+        if chain_id == MAINNET_CHAIN_ID {
+            return Some(Self::Mainnet);
+        }
+
+        crate::package::notarization_package_registry()
+            .await
+            .chain_alias(chain_id)
+            .and_then(|alias| Self::from_name(alias.as_ref()))
+    }
+
+    /// Returns the [`NetworkName`] of the network.
+    pub fn network_name(&self) -> NetworkName {
+        NetworkName::try_from(self.name()).expect("valid network name")
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for Network {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or_else(|| {
+            Error::InvalidArgument(format!(
+                "unknown network `{s}`, expected one of `iota`, `mainnet`, `testnet`, `devnet` or `localnet`"
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_round_trip() {
+        for network in [Network::Mainnet, Network::Testnet, Network::Devnet, Network::Localnet] {
+            assert_eq!(Network::from_str(&network.to_string()).unwrap(), network);
+            assert_eq!(network.network_name().as_ref(), network.name());
+        }
+
+        assert_eq!(Network::from_str("mainnet").unwrap(), Network::Mainnet);
+        assert!(matches!(Network::from_str("moonnet"), Err(Error::InvalidArgument(_))));
+    }
+
+    #[tokio::test]
+    async fn test_mainnet_chain_id_is_known() {
+        assert_eq!(Network::from_chain_id(MAINNET_CHAIN_ID).await, Some(Network::Mainnet));
+        assert_eq!(Network::from_chain_id("a1b2c3d4").await, None);
+    }
+}
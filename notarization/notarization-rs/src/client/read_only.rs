@@ -7,12 +7,27 @@
 //! on the IOTA network without requiring signing capabilities.
 
 use std::ops::Deref;
+#[cfg(not(target_arch = "wasm32"))]
+use std::str::FromStr;
+use std::sync::{Arc, PoisonError, RwLock};
+use std::time::Duration;
 
+#[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+use futures::{Stream, StreamExt, future, stream};
 #[cfg(not(target_arch = "wasm32"))]
 use iota_interaction::IotaClient;
-use iota_interaction::IotaClientTrait;
-use iota_interaction::types::base_types::{IotaAddress, ObjectID};
-use iota_interaction::types::transaction::{ProgrammableTransaction, TransactionKind};
+#[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+use iota_interaction::rpc_types::EventFilter;
+use iota_interaction::rpc_types::{
+    IotaObjectDataFilter, IotaObjectDataOptions, IotaObjectResponse, IotaObjectResponseQuery, IotaParsedData,
+    IotaPastObjectResponse, IotaTransactionBlockEffectsAPI, IotaTransactionBlockResponseOptions,
+};
+use iota_interaction::types::base_types::{IotaAddress, ObjectID, ObjectType};
+use iota_interaction::types::error::IotaObjectResponseError;
+use iota_interaction::types::object::Owner;
+use iota_interaction::types::transaction::{Command, ProgrammableTransaction, TransactionKind};
+use iota_interaction::types::{StructTag, TypeTag};
+use iota_interaction::{IotaClientTrait, ident_str};
 #[cfg(target_arch = "wasm32")]
 use iota_interaction_ts::bindings::WasmIotaClient;
 use product_common::core_client::CoreClientReadOnly;
@@ -20,14 +35,47 @@ use product_common::network_name::NetworkName;
 use product_common::package_registry::{Env, Metadata};
 use serde::de::DeserializeOwned;
 
+#[cfg(not(target_arch = "wasm32"))]
+use super::connect;
+#[cfg(not(target_arch = "wasm32"))]
+use super::network::Network;
 use super::network_id;
-use crate::core::move_utils;
+use super::retry::{RetryPolicy, is_transient_message};
+use crate::core::move_utils::{self, ClockObject};
 use crate::core::operations::{NotarizationImpl, NotarizationOperations};
-use crate::core::transactions::get_object_ref_by_id_with_bcs;
-use crate::core::types::{Data, LockMetadata, NotarizationMethod, OnChainNotarization, State};
+use crate::core::transactions::get_notarization_by_id_with_bcs;
+use crate::core::type_cache::TypeTagCache;
+use crate::core::types::{
+    AvailableActions, Data, ImmutableMetadata, LockMetadata, LockStatus, NotarizationExpectations, NotarizationMethod,
+    NotarizationPage, NotarizationReference, OnChainNotarization, ProtectionStatus, SchemaCheck, State, StateVersion,
+    TimeLock, VerificationReport,
+};
+#[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+use crate::core::types::{EVENT_MODULES, NotarizationEvent};
 use crate::error::Error;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::error::LockedOperation;
 use crate::iota_interaction_adapter::IotaClientAdapter;
-use crate::package;
+use crate::package::{self, NetworkResolution, PackageInfo, PackageSource};
+
+/// The maximum number of objects a node returns for a single multi-get request.
+pub const MULTI_GET_OBJECTS_LIMIT: usize = 50;
+
+/// The outcome of [`NotarizationClientReadOnly::health_check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HealthReport {
+    /// The name of the network the client is connected to.
+    pub network: NetworkName,
+    /// The chain identifier reported by the node.
+    pub chain_id: String,
+    /// The ID of the Notarization package used by the client.
+    pub package_id: ObjectID,
+    /// The epoch of the latest update of the on-chain clock.
+    pub epoch: u64,
+    /// The checkpoint of the latest update of the on-chain clock, or `None` if the update was
+    /// executed but not yet included in a checkpoint.
+    pub latest_checkpoint: Option<u64>,
+}
 
 /// A read-only client for interacting with IOTA Notarization module objects on a specific network.
 ///
@@ -39,12 +87,31 @@ use crate::package;
 pub struct NotarizationClientReadOnly {
     /// The underlying IOTA client adapter used for communication.
     iota_client: IotaClientAdapter,
+    /// The native client, used for event subscriptions over WebSocket.
+    ///
+    /// Not available for clients created from an existing [`IotaClientAdapter`].
+    #[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+    event_client: Option<IotaClient>,
     /// The [`ObjectID`] of the deployed Notarization package (smart contract).
     /// All interactions go through this package ID.
     notarization_pkg_id: ObjectID,
+    /// The IDs of all versions of the Notarization package, from the original to the current one.
+    package_history: Vec<ObjectID>,
     /// The name of the network this client is connected to (e.g., "mainnet", "testnet").
     network: NetworkName,
     chain_id: String,
+    /// How the package ID was chosen.
+    package_source: PackageSource,
+    /// How the network name was derived from the chain ID.
+    network_resolution: NetworkResolution,
+    /// The retry policy of read calls.
+    retry_policy: RetryPolicy,
+    /// The sender of the dev-inspected transactions of read calls.
+    inspection_sender: IotaAddress,
+    /// The type tags resolved by this client, shared by its clones.
+    type_cache: Arc<RwLock<TypeTagCache>>,
+    /// The clock object transactions and queries of this client read the time from.
+    clock: ClockObject,
 }
 
 impl Deref for NotarizationClientReadOnly {
@@ -73,6 +140,142 @@ impl NotarizationClientReadOnly {
         &self.chain_id
     }
 
+    /// Returns the IDs of all versions of the Notarization package on the connected network,
+    /// ordered from the original package to the one used by this client.
+    ///
+    /// Types and events are bound to the package version that first defined them, so objects
+    /// created before a package upgrade are resolved through the earlier entries of the history.
+    pub fn package_history(&self) -> Vec<ObjectID> {
+        self.package_history.clone()
+    }
+
+    /// Returns how the package and the network of this client were resolved when it was
+    /// created.
+    ///
+    /// Use this to debug which deployment the client is connected to, e.g. whether the package
+    /// came from the registry or from an override, and which alias the chain ID resolved to.
+    pub fn package_info(&self) -> PackageInfo {
+        PackageInfo {
+            package_id: self.notarization_pkg_id,
+            package_history: self.package_history.clone(),
+            package_source: self.package_source,
+            chain_id: self.chain_id.clone(),
+            network: self.network.clone(),
+            network_resolution: self.network_resolution,
+        }
+    }
+
+    /// Returns the ID of the original Notarization package, which defines the types of all
+    /// notarization objects and events on the connected network.
+    ///
+    /// Package upgrades keep the identity of existing types: objects created under any version
+    /// of the package have types of the original package. Move calls of this client target
+    /// [`package_id`](Self::package_id), the latest version known to the client, which accepts
+    /// objects of all earlier versions, so objects created before an upgrade are read like any
+    /// other. Use this ID to filter objects and events by type.
+    pub fn original_package_id(&self) -> ObjectID {
+        package::original_package_id(&self.package_history, self.notarization_pkg_id)
+    }
+
+    /// Uses the clock object `clock_id` instead of the system clock (`0x6`) for all transactions
+    /// and queries of this client.
+    ///
+    /// The initial shared version of the clock is resolved from the network. This is only needed
+    /// for forked or custom networks whose clock is shared at a different version than on the
+    /// public networks. A [`NotarizationClient`](crate::client::NotarizationClient) created from
+    /// this client builds its transactions against the same clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock_id`: The [`ObjectID`] of the shared clock object of the network.
+    ///
+    /// # Returns
+    /// A `Result` containing the client, or an [`Error`] if the object is not a shared object.
+    pub async fn with_clock_object_id(mut self, clock_id: ObjectID) -> Result<Self, Error> {
+        let initial_shared_version = move_utils::get_clock_shared_version(&self, clock_id).await?;
+        self.clock = ClockObject {
+            id: clock_id,
+            initial_shared_version,
+        };
+
+        Ok(self)
+    }
+
+    /// Returns the clock object transactions and queries of this client read the time from.
+    pub(crate) fn clock(&self) -> ClockObject {
+        self.clock
+    }
+
+    /// Sets the retry policy of read calls (default: [`RetryPolicy::default`]).
+    ///
+    /// Read calls that fail with a transient RPC error (e.g. a timeout or a connection reset)
+    /// are retried according to `retry_policy`; see the [retry module docs](super::retry).
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Returns the retry policy of read calls.
+    pub fn retry_policy(&self) -> RetryPolicy {
+        self.retry_policy
+    }
+
+    /// Sets the sender of the transactions that read calls dev-inspect (default: [`IotaAddress::ZERO`]).
+    ///
+    /// Read calls execute Move functions without submitting a transaction. Set a sender if a
+    /// node rejects inspections from the zero address, or for Move functions whose result
+    /// depends on the sender. The sender does not need to own any gas.
+    pub fn with_inspection_sender(mut self, inspection_sender: IotaAddress) -> Self {
+        self.inspection_sender = inspection_sender;
+        self
+    }
+
+    /// Returns the sender of the transactions that read calls dev-inspect.
+    pub fn inspection_sender(&self) -> IotaAddress {
+        self.inspection_sender
+    }
+
+    /// Sets how long the resolved type tags of notarization objects are cached (default: 10 minutes).
+    ///
+    /// Most queries need the type tag of the queried object, which costs an extra RPC call.
+    /// The cache belongs to the client and is shared by its clones; setting the TTL starts with
+    /// an empty cache. A zero `ttl` disables caching.
+    pub fn with_type_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.type_cache = Arc::new(RwLock::new(TypeTagCache::new(ttl)));
+        self
+    }
+
+    /// Removes all type tags of notarization objects cached by this client and its clones.
+    ///
+    /// The type of an object never changes, so this is rarely needed; it frees the memory
+    /// held by the cache.
+    pub fn clear_type_cache(&self) {
+        self.type_cache.write().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+
+    /// Returns the type tag of the state data of `object_id`, from the cache if possible.
+    async fn type_tag(&self, object_id: ObjectID) -> Result<TypeTag, Error> {
+        let cached = self
+            .type_cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&object_id);
+        if let Some(tag) = cached {
+            return Ok(tag);
+        }
+
+        let tag = self
+            .retry_policy
+            .retry(|| move_utils::get_type_tag(self, &object_id))
+            .await?;
+        self.type_cache
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(object_id, tag.clone());
+
+        Ok(tag)
+    }
+
     /// Attempts to create a new [`NotarizationClientReadOnly`] from a given IOTA client.
     ///
     /// # Failures
@@ -93,48 +296,223 @@ impl NotarizationClientReadOnly {
         #[cfg(target_arch = "wasm32")] iota_client: WasmIotaClient,
         #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
     ) -> Result<Self, Error> {
-        let client = IotaClientAdapter::new(iota_client);
-        let network = network_id(&client).await?;
-        Self::new_internal(client, network).await
+        Self::new_internal(iota_client, None, None, None).await
+    }
+
+    /// Connects to the public endpoint of `network` and creates a new [`NotarizationClientReadOnly`].
+    ///
+    /// The Notarization package is resolved from the package registry, like in
+    /// [`NotarizationClientReadOnly::new`]. Packages of local networks must be registered up
+    /// front, see [`register_package`](crate::package::register_package).
+    ///
+    /// # Arguments
+    ///
+    /// * `network`: The [`Network`] to connect to.
+    ///
+    /// # Returns
+    /// A `Result` containing the initialized [`NotarizationClientReadOnly`] or an [`Error`] if
+    /// the endpoint is unreachable or no package is known for the network.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn connect(network: Network) -> Result<Self, Error> {
+        Self::new(connect(&network.network_name()).await?).await
+    }
+
+    /// Attempts to create a new [`NotarizationClientReadOnly`], falling back to `package_id` if
+    /// the internal package registry has no information about the connected network.
+    ///
+    /// Unlike [`NotarizationClientReadOnly::new_with_pkg_id`], the given package ID never overrides
+    /// a known package ID. This is useful for applications that also run against freshly deployed
+    /// networks that are not (yet) part of the registry.
+    ///
+    /// # Arguments
+    ///
+    /// * `iota_client`: The IOTA client instance.
+    /// * `package_id`: The [`ObjectID`] of the Notarization package to use if the network is unknown.
+    ///
+    /// # Returns
+    /// A `Result` containing the initialized [`NotarizationClientReadOnly`] or an [`Error`].
+    pub async fn with_fallback_package_id(
+        #[cfg(target_arch = "wasm32")] iota_client: WasmIotaClient,
+        #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
+        package_id: ObjectID,
+    ) -> Result<Self, Error> {
+        Self::new_internal(iota_client, None, None, Some(package_id)).await
+    }
+
+    /// Attempts to create a new [`NotarizationClientReadOnly`] from an existing IOTA client adapter.
+    ///
+    /// The adapter is used as is, so one adapter (and its connection) can be shared with other
+    /// IOTA product clients. The notarization package ID is looked up like in
+    /// [`NotarizationClientReadOnly::new`].
+    ///
+    /// Clients created from an adapter cannot subscribe to events (`subscribe_events`), which
+    /// requires the native `IotaClient`.
+    ///
+    /// # Arguments
+    ///
+    /// * `iota_client`: The IOTA client adapter to use for communication.
+    ///
+    /// # Returns
+    /// A `Result` containing the initialized [`NotarizationClientReadOnly`] or an [`Error`] if
+    /// the network is unrecognized or communication fails.
+    pub async fn from_adapter(iota_client: IotaClientAdapter) -> Result<Self, Error> {
+        Self::from_adapter_internal(iota_client, None, None, None).await
+    }
+
+    /// Creates a new [`NotarizationClientReadOnly`] for the network with the chain identifier
+    /// `chain_id` and the Notarization package `package_id`, without contacting the node.
+    ///
+    /// Use this to build transactions in environments without outbound RPC access, e.g. to hand
+    /// the BCS bytes of a programmable transaction (see
+    /// [`build_programmable_transaction_bcs`](crate::core::transactions::build_programmable_transaction_bcs))
+    /// off for signing and submission elsewhere. The package ID is kept on the returned client
+    /// only; unlike [`NotarizationClientReadOnly::new_with_pkg_id`], the global package registry
+    /// is neither read nor written. The package history therefore only holds `package_id`, and the
+    /// network is named after the chain identifier (or `iota` for mainnet) without looking up an
+    /// alias.
+    ///
+    /// Nothing is verified up front: a wrong chain identifier or package ID only surfaces when
+    /// the built transaction is executed, or with [`Self::health_check`]. Read methods still
+    /// require connectivity, and so does building any transaction that reads on-chain data:
+    /// only creations whose locks do not depend on the time can be built fully offline.
+    /// Creations with relative locks or `TimeLock::UnlockAt` locks read the on-chain clock to
+    /// resolve or check them, and transactions on existing notarizations look up the object
+    /// and its type on the node.
+    ///
+    /// # Arguments
+    ///
+    /// * `iota_client`: The IOTA client instance; it is not contacted by this constructor.
+    /// * `chain_id`: The chain identifier of the network, e.g. as printed by `iota client chain-identifier`.
+    /// * `package_id`: The [`ObjectID`] of the Notarization package to use.
+    pub fn new_offline(
+        #[cfg(target_arch = "wasm32")] iota_client: WasmIotaClient,
+        #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
+        chain_id: NetworkName,
+        package_id: ObjectID,
+    ) -> Self {
+        let (network, network_resolution) = match chain_id.as_ref() {
+            product_common::package_registry::MAINNET_CHAIN_ID => (
+                NetworkName::try_from("iota").expect("valid network name"),
+                NetworkResolution::Mainnet,
+            ),
+            _ => (chain_id.clone(), NetworkResolution::ChainId),
+        };
+
+        NotarizationClientReadOnly {
+            #[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+            event_client: Some(iota_client.clone()),
+            iota_client: IotaClientAdapter::new(iota_client),
+            notarization_pkg_id: package_id,
+            package_history: vec![package_id],
+            network,
+            chain_id: chain_id.as_ref().to_string(),
+            package_source: PackageSource::Override,
+            network_resolution,
+            retry_policy: RetryPolicy::default(),
+            inspection_sender: IotaAddress::ZERO,
+            type_cache: Arc::default(),
+            clock: ClockObject::default(),
+        }
+    }
+
+    /// Internal helper function to create a new [`NotarizationClientReadOnly`] from a raw IOTA client.
+    ///
+    /// # Arguments
+    ///
+    /// * `iota_client`: The IOTA client instance.
+    /// * `chain_id`: The chain identifier of the network; fetched from the node if `None`.
+    /// * `package_id`: The package ID to register for the network, overriding any known package ID.
+    /// * `fallback_package_id`: The package ID to use if the registry has no information about the network.
+    async fn new_internal(
+        #[cfg(target_arch = "wasm32")] iota_client: WasmIotaClient,
+        #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
+        chain_id: Option<NetworkName>,
+        package_id: Option<ObjectID>,
+        fallback_package_id: Option<ObjectID>,
+    ) -> Result<Self, Error> {
+        #[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+        let event_client = iota_client.clone();
+        let client = Self::from_adapter_internal(
+            IotaClientAdapter::new(iota_client),
+            chain_id,
+            package_id,
+            fallback_package_id,
+        )
+        .await?;
+        #[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+        let client = Self {
+            event_client: Some(event_client),
+            ..client
+        };
+
+        Ok(client)
     }
 
     /// Internal helper function to create a new [`NotarizationClientReadOnly`].
     ///
-    /// This function looks up the notarization package ID based on the provided network name
+    /// This function looks up the notarization package ID based on the network of the client
     /// using the internal package registry.
     ///
     /// # Arguments
     ///
     /// * `iota_client`: The IOTA client adapter.
-    /// * `network`: The name of the network.
-    async fn new_internal(iota_client: IotaClientAdapter, network: NetworkName) -> Result<Self, Error> {
+    /// * `chain_id`: The chain identifier of the network; fetched from the node if `None`.
+    /// * `package_id`: The package ID to register for the network, overriding any known package ID.
+    /// * `fallback_package_id`: The package ID to use if the registry has no information about the network.
+    async fn from_adapter_internal(
+        iota_client: IotaClientAdapter,
+        chain_id: Option<NetworkName>,
+        package_id: Option<ObjectID>,
+        fallback_package_id: Option<ObjectID>,
+    ) -> Result<Self, Error> {
+        let network = match chain_id {
+            Some(chain_id) => chain_id,
+            None => network_id(&iota_client).await?,
+        };
         let chain_id = network.as_ref().to_string();
-        let (network, notarization_pkg_id) = {
-            let package_registry = package::notarization_package_registry().await;
-            let package_id = package_registry
-        .package_id(&network)
-        .ok_or_else(|| {
-        Error::InvalidConfig(format!(
-            "no information for a published `notarization` package on network {network}; try to use `NotarizationClientReadOnly::new_with_package_id`"
-            ))
-        })?;
-            let network = match chain_id.as_str() {
-                product_common::package_registry::MAINNET_CHAIN_ID => {
-                    NetworkName::try_from("iota").expect("valid network name")
-                }
-                _ => package_registry
+        let (network, network_resolution, notarization_pkg_id, package_history, package_source) = {
+            let mut package_registry = package::notarization_package_registry_mut().await;
+            // Use the passed pkg_id to add a new env or override the information of an existing one.
+            let package_source = if let Some(package_id) = package_id {
+                package_registry.insert_env(Env::new(network.as_ref()), Metadata::from_package_id(package_id));
+                PackageSource::Override
+            } else if package_registry.package_id(network.as_ref()).is_some() {
+                PackageSource::Registry
+            } else {
+                PackageSource::Fallback
+            };
+            let package_id = package::resolve_package_id(&mut package_registry, network.as_ref(), fallback_package_id)?;
+            let package_history = package::package_history(&package_registry, network.as_ref(), package_id);
+            let (network, network_resolution) = match chain_id.as_str() {
+                product_common::package_registry::MAINNET_CHAIN_ID => (
+                    NetworkName::try_from("iota").expect("valid network name"),
+                    NetworkResolution::Mainnet,
+                ),
+                _ => match package_registry
                     .chain_alias(&chain_id)
                     .and_then(|alias| NetworkName::try_from(alias).ok())
-                    .unwrap_or(network),
+                {
+                    Some(alias) => (alias, NetworkResolution::ChainAlias),
+                    None => (network, NetworkResolution::ChainId),
+                },
             };
 
-            (network, package_id)
+            (network, network_resolution, package_id, package_history, package_source)
         };
         Ok(NotarizationClientReadOnly {
             iota_client,
+            #[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+            event_client: None,
             notarization_pkg_id,
+            package_history,
             network,
             chain_id,
+            package_source,
+            network_resolution,
+            retry_policy: RetryPolicy::default(),
+            inspection_sender: IotaAddress::ZERO,
+            type_cache: Arc::default(),
+            clock: ClockObject::default(),
         })
     }
 
@@ -144,6 +522,10 @@ impl NotarizationClientReadOnly {
     /// for connecting to networks where the package ID is known but not yet registered, or
     /// for testing with custom deployments.
     ///
+    /// The package ID is registered for the connected network in the global package registry.
+    /// To configure several networks up front, use [`crate::package::register_package`] and
+    /// [`NotarizationClientReadOnly::new`] instead.
+    ///
     /// # Arguments
     ///
     /// * `iota_client`: The IOTA client instance.
@@ -156,16 +538,229 @@ impl NotarizationClientReadOnly {
         #[cfg(not(target_arch = "wasm32"))] iota_client: IotaClient,
         package_id: ObjectID,
     ) -> Result<Self, Error> {
-        let client = IotaClientAdapter::new(iota_client);
-        let network = network_id(&client).await?;
+        Self::new_internal(iota_client, None, Some(package_id), None).await
+    }
 
-        // Use the passed pkg_id to add a new env or override the information of an existing one.
-        {
-            let mut registry = package::notarization_package_registry_mut().await;
-            registry.insert_env(Env::new(network.as_ref()), Metadata::from_package_id(package_id));
+    /// Verifies a notarization from its serialized [`NotarizationReference`].
+    ///
+    /// This is a one-call "scan and verify" primitive: the reference is parsed, a connection
+    /// to the referenced network is established, the notarized object is fetched and the
+    /// [content hash](State::content_hash) of its state is compared against `expected_hash`.
+    ///
+    /// Well-known network names are resolved to their public endpoints; any other network
+    /// name is expected to be the chain identifier of a local network.
+    ///
+    /// # Arguments
+    ///
+    /// * `reference`: The serialized reference, e.g. `notarization:testnet:0x1234...`.
+    /// * `expected_hash`: The SHA-256 hash of the document that should be notarized.
+    ///
+    /// # Returns
+    /// A `Result` containing `true` if the notarized state matches `expected_hash`, `false` if it
+    /// doesn't, or an [`Error`] if the reference is malformed, the network is unreachable or the
+    /// object cannot be fetched.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn verify_reference(reference: &str, expected_hash: [u8; 32]) -> Result<bool, Error> {
+        let reference = NotarizationReference::from_str(reference)?;
+        let client = Self::new(connect(reference.network()).await?).await?;
+
+        if client.network() != reference.network() && client.chain_id() != reference.network().as_ref() {
+            return Err(Error::InvalidConfig(format!(
+                "connected to network {} but the reference points to network {}",
+                client.network(),
+                reference.network()
+            )));
         }
 
-        Self::new_internal(client, network).await
+        let notarization = client.get_notarization_by_id(reference.object_id()).await?;
+
+        Ok(notarization.state.content_hash() == expected_hash)
+    }
+
+    /// Returns a compact [`NotarizationReference`] to `notarized_object_id` on the network
+    /// this client is connected to.
+    ///
+    /// The reference can be serialized with `to_string()` and later be verified with
+    /// [`Self::verify_reference`].
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    pub fn reference(&self, notarized_object_id: ObjectID) -> NotarizationReference {
+        NotarizationReference::new(self.network.clone(), notarized_object_id)
+    }
+
+    /// Computes the anchor fingerprint of a notarized object.
+    ///
+    /// See [`OnChainNotarization::anchor_fingerprint`] for the hashed fields. The network name
+    /// and package ID of this client are used.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the 32 byte fingerprint or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn anchor_fingerprint(&self, notarized_object_id: ObjectID) -> Result<[u8; 32], Error> {
+        let notarization = self.get_notarization_by_id(notarized_object_id).await?;
+
+        Ok(notarization.anchor_fingerprint(&self.network, self.notarization_pkg_id))
+    }
+
+    /// Retrieves all notarizations owned by `owner`.
+    ///
+    /// Follows the pages of [`Self::get_notarizations_owned_by_paginated`] until all
+    /// notarizations have been fetched. Objects that are not notarizations of this client's
+    /// package are ignored.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner`: The [`IotaAddress`] owning the notarizations.
+    ///
+    /// # Returns
+    /// A `Result` containing the owned [`OnChainNotarization`]s or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn get_notarizations_owned_by(&self, owner: IotaAddress) -> Result<Vec<OnChainNotarization>, Error> {
+        let mut notarizations = Vec::new();
+        let mut cursor = None;
+        loop {
+            let page = self.get_notarizations_owned_by_paginated(owner, cursor, None).await?;
+            notarizations.extend(page.data);
+
+            if !page.has_next_page {
+                break;
+            }
+            cursor = page.next_cursor;
+        }
+
+        Ok(notarizations)
+    }
+
+    /// Retrieves one page of the notarizations owned by `owner`.
+    ///
+    /// Only objects of type `notarization::Notarization` of this client's package are returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner`: The [`IotaAddress`] owning the notarizations.
+    /// * `cursor`: The `next_cursor` of the previous page, or `None` to start from the first page.
+    /// * `limit`: The maximum number of notarizations in the page; the node's default is used if `None`.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`NotarizationPage`] or an [`Error`].
+    pub async fn get_notarizations_owned_by_paginated(
+        &self,
+        owner: IotaAddress,
+        cursor: Option<ObjectID>,
+        limit: Option<usize>,
+    ) -> Result<NotarizationPage, Error> {
+        // Object types are bound to the package version that first defined them.
+        let original_package_id = self.original_package_id();
+        let filter = IotaObjectDataFilter::StructType(StructTag {
+            address: original_package_id.into(),
+            module: ident_str!("notarization").into(),
+            name: ident_str!("Notarization").into(),
+            type_params: vec![],
+        });
+        let query = IotaObjectResponseQuery::new(Some(filter), Some(IotaObjectDataOptions::bcs_lossless()));
+
+        let page = self
+            .retry_policy
+            .retry_if(
+                || {
+                    self.iota_client
+                        .read_api()
+                        .get_owned_objects(owner, Some(query.clone()), cursor, limit)
+                },
+                |e| is_transient_message(&e.to_string()),
+            )
+            .await
+            .map_err(|e| Error::RpcError(format!("failed to get objects owned by {owner}: {e}")))?;
+
+        let data = page
+            .data
+            .into_iter()
+            .map(|response| {
+                let object = response
+                    .data
+                    .ok_or_else(|| Error::ObjectLookup("missing data in response".to_string()))?
+                    .bcs
+                    .ok_or_else(|| Error::ObjectLookup("missing object content in data".to_string()))?
+                    .try_into_move()
+                    .ok_or_else(|| Error::ObjectLookup("failed to convert data to move object".to_string()))?;
+
+                OnChainNotarization::from_move_object(&object)
+            })
+            .collect::<Result<Vec<OnChainNotarization>, Error>>()?;
+
+        Ok(NotarizationPage {
+            data,
+            next_cursor: page.next_cursor,
+            has_next_page: page.has_next_page,
+        })
+    }
+
+    /// Checks that the BCS decoded notarization matches the node's JSON rendering of the object.
+    ///
+    /// Key fields (`id`, `method` and `state_version_count`) are compared between both
+    /// representations; any difference indicates that the client-side layout of
+    /// [`OnChainNotarization`] drifted from the on-chain struct, e.g. after a package upgrade.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`SchemaCheck`] report or an [`Error`] if the object cannot be
+    /// fetched or decoded at all.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn self_check(&self, notarized_object_id: ObjectID) -> Result<SchemaCheck, Error> {
+        let decoded = self.get_notarization_by_id(notarized_object_id).await?;
+
+        let content = self
+            .iota_client
+            .read_api()
+            .get_object_with_options(notarized_object_id, IotaObjectDataOptions::new().with_content())
+            .await
+            .map_err(|err| Error::ObjectLookup(err.to_string()))?
+            .data
+            .ok_or_else(|| Error::ObjectLookup("missing data in response".to_string()))?
+            .content
+            .ok_or_else(|| Error::ObjectLookup("missing object content in data".to_string()))?;
+        let IotaParsedData::MoveObject(object) = content else {
+            return Err(Error::ObjectLookup(format!(
+                "object {notarized_object_id} is not a Move object"
+            )));
+        };
+
+        Ok(SchemaCheck::compare(&object.fields.to_json_value(), &decoded))
+    }
+
+    /// Verifies the notarized object against the `expectations` of a verifier.
+    ///
+    /// The notarization and the on-chain clock are fetched once; the content hash, method,
+    /// description and lock state are then compared locally. Expectations that are not set are
+    /// not checked.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    /// * `expectations`: The [`NotarizationExpectations`] to check.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`VerificationReport`] listing every check and its outcome, or
+    /// an [`Error`] if the object cannot be fetched. A notarization that does not meet the
+    /// expectations is reported as such, not as an error.
+    pub async fn verify(
+        &self,
+        notarized_object_id: ObjectID,
+        expectations: NotarizationExpectations,
+    ) -> Result<VerificationReport, Error> {
+        let notarization = self.get_notarization_by_id(notarized_object_id).await?;
+        let now = self.now_ts().await?;
+
+        Ok(VerificationReport::evaluate(&notarization, &expectations, now))
     }
 
     /// Retrieves the [`OnChainNotarization`] of a notarized object.
@@ -178,12 +773,249 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing the [`OnChainNotarization`] or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn get_notarization_by_id(&self, notarized_object_id: ObjectID) -> Result<OnChainNotarization, Error> {
-        let notarization_object = get_object_ref_by_id_with_bcs(self, &notarized_object_id).await?;
+        let notarization_object = self
+            .retry_policy
+            .retry(|| get_notarization_by_id_with_bcs(self, &notarized_object_id))
+            .await?;
 
         Ok(notarization_object)
     }
 
+    /// Retrieves the [`OnChainNotarization`]s of several notarized objects at once.
+    ///
+    /// The objects are fetched with as few RPC calls as possible (one per
+    /// [`MULTI_GET_OBJECTS_LIMIT`] objects) instead of one call per object.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_ids`: The [`ObjectID`]s of the notarized objects.
+    ///
+    /// # Returns
+    /// A `Result` containing one entry per requested ID, in the same order: the
+    /// [`OnChainNotarization`], or `None` if the object does not exist (anymore). Returns an
+    /// [`Error`] if the request fails or an object is not a notarization.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(count = notarized_object_ids.len()), err))]
+    pub async fn get_notarizations_by_ids(
+        &self,
+        notarized_object_ids: &[ObjectID],
+    ) -> Result<Vec<Option<OnChainNotarization>>, Error> {
+        let mut notarizations = Vec::with_capacity(notarized_object_ids.len());
+        for chunk in notarized_object_ids.chunks(MULTI_GET_OBJECTS_LIMIT) {
+            let responses = self
+                .retry_policy
+                .retry_if(
+                    || {
+                        self.iota_client
+                            .read_api()
+                            .multi_get_object_with_options(chunk.to_vec(), IotaObjectDataOptions::bcs_lossless())
+                    },
+                    |e| is_transient_message(&e.to_string()),
+                )
+                .await
+                .map_err(|e| Error::RpcError(format!("failed to get objects: {e}")))?;
+
+            if responses.len() != chunk.len() {
+                return Err(Error::UnexpectedApiResponse(format!(
+                    "requested {} objects, got {}",
+                    chunk.len(),
+                    responses.len()
+                )));
+            }
+
+            for (object_id, response) in chunk.iter().zip(responses) {
+                // Missing and deleted objects come without data.
+                let Some(data) = response.data else {
+                    notarizations.push(None);
+                    continue;
+                };
+
+                let object = data
+                    .bcs
+                    .ok_or_else(|| Error::ObjectLookup(format!("missing content of object {object_id}")))?
+                    .try_into_move()
+                    .ok_or_else(|| Error::ObjectLookup(format!("object {object_id} is not a move object")))?;
+                let notarization = OnChainNotarization::from_move_object(&object)
+                    .map_err(|err| Error::ObjectLookup(format!("object {object_id} is not a notarization: {err}")))?;
+                notarizations.push(Some(notarization));
+            }
+        }
+
+        Ok(notarizations)
+    }
+
+    /// Indicates if an object with the given `object_id` currently exists.
+    ///
+    /// Only the existence of the object is checked; its content is neither fetched nor decoded,
+    /// so this does not verify that the object is a notarization.
+    ///
+    /// # Arguments
+    ///
+    /// * `object_id`: The [`ObjectID`] of the object.
+    ///
+    /// # Returns
+    /// A `Result` containing `true` if the object exists, or an [`Error`] if the request fails.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn exists(&self, object_id: ObjectID) -> Result<bool, Error> {
+        Ok(self.object_status(object_id).await?.data.is_some())
+    }
+
+    /// Indicates if the object with the given `object_id` was deleted, e.g. because the
+    /// notarization was destroyed.
+    ///
+    /// Unlike [`NotarizationClientReadOnly::exists`], this distinguishes deleted objects from
+    /// objects that never existed, based on the object status reported by the node. Nodes that
+    /// pruned the deletion report the object as non-existent, in which case `false` is returned.
+    ///
+    /// # Arguments
+    ///
+    /// * `object_id`: The [`ObjectID`] of the object.
+    ///
+    /// # Returns
+    /// A `Result` containing `true` if the object was deleted, or an [`Error`] if the request fails.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn is_destroyed(&self, object_id: ObjectID) -> Result<bool, Error> {
+        let response = self.object_status(object_id).await?;
+
+        Ok(matches!(response.error, Some(IotaObjectResponseError::Deleted { .. })))
+    }
+
+    /// Retrieves the current [`Owner`] of a notarized object.
+    ///
+    /// Notarizations are usually owned by an address ([`Owner::AddressOwner`]); use this e.g.
+    /// to confirm the new owner after a transfer. Only the owner is fetched, not the content.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`Owner`], or an [`Error`] if the object does not exist.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn owner(&self, notarized_object_id: ObjectID) -> Result<Owner, Error> {
+        self.object_with_options(notarized_object_id, IotaObjectDataOptions::new().with_owner())
+            .await?
+            .data
+            .and_then(|data| data.owner)
+            .ok_or_else(|| Error::ObjectLookup(format!("object {notarized_object_id} does not exist")))
+    }
+
+    /// Retrieves the full Move type of a notarized object, e.g.
+    /// `0x…::notarization::Notarization<0x1::string::String>`.
+    ///
+    /// Unlike the type tag used internally, the type includes the package and the struct, so it
+    /// tells apart notarizations of other packages and reveals custom state types, which
+    /// [`Self::state`] rejects as "Unsupported state type". Only the type is fetched, not the
+    /// content.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the type string, or an [`Error`] if the object does not exist.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn object_type(&self, notarized_object_id: ObjectID) -> Result<String, Error> {
+        self.object_with_options(notarized_object_id, IotaObjectDataOptions::new().with_type())
+            .await?
+            .data
+            .and_then(|data| data.type_)
+            .map(|object_type| object_type.to_string())
+            .ok_or_else(|| Error::ObjectLookup(format!("object {notarized_object_id} does not exist")))
+    }
+
+    /// Checks that the client can be used with the connected node, e.g. before running a batch job.
+    ///
+    /// The check fails with [`Error::InvalidConfig`] if the node now reports a different chain
+    /// identifier than when the client was created (e.g. a reset local network), or if the
+    /// Notarization package does not exist on the network. The epoch and the latest checkpoint
+    /// are taken from the latest update of the on-chain clock, which happens with every
+    /// checkpoint.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`HealthReport`], or an [`Error`] if the node cannot be
+    /// reached or a check fails.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn health_check(&self) -> Result<HealthReport, Error> {
+        let chain_id = network_id(&self.iota_client).await?.as_ref().to_string();
+        if chain_id != self.chain_id {
+            return Err(Error::InvalidConfig(format!(
+                "the node is on chain {chain_id}, but the client was created for chain {}",
+                self.chain_id
+            )));
+        }
+
+        let package = self
+            .object_with_options(self.notarization_pkg_id, IotaObjectDataOptions::new().with_type())
+            .await?;
+        if !matches!(package.data.and_then(|data| data.type_), Some(ObjectType::Package)) {
+            return Err(Error::InvalidConfig(format!(
+                "notarization package {} does not exist on network {}",
+                self.notarization_pkg_id, self.network
+            )));
+        }
+
+        let clock_update = self
+            .object_with_options(self.clock.id, IotaObjectDataOptions::new().with_previous_transaction())
+            .await?
+            .data
+            .and_then(|data| data.previous_transaction)
+            .ok_or_else(|| Error::UnexpectedApiResponse("the clock object has no previous transaction".to_string()))?;
+        let response = self
+            .retry_policy
+            .retry_if(
+                || {
+                    self.iota_client.read_api().get_transaction_with_options(
+                        clock_update,
+                        IotaTransactionBlockResponseOptions::new().with_effects(),
+                    )
+                },
+                |e| is_transient_message(&e.to_string()),
+            )
+            .await
+            .map_err(|e| Error::RpcError(format!("failed to get transaction {clock_update}: {e}")))?
+            .clone_native_response();
+        let epoch = response
+            .effects
+            .as_ref()
+            .map(|effects| effects.executed_epoch())
+            .ok_or_else(|| Error::UnexpectedApiResponse(format!("transaction {clock_update} has no effects")))?;
+
+        Ok(HealthReport {
+            network: self.network.clone(),
+            chain_id,
+            package_id: self.notarization_pkg_id,
+            epoch,
+            latest_checkpoint: response.checkpoint,
+        })
+    }
+
+    /// Reads the status of an object, without its content.
+    async fn object_status(&self, object_id: ObjectID) -> Result<IotaObjectResponse, Error> {
+        self.object_with_options(object_id, IotaObjectDataOptions::new()).await
+    }
+
+    /// Reads an object with the given `options`, retrying transient failures.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self, options), err))]
+    async fn object_with_options(
+        &self,
+        object_id: ObjectID,
+        options: IotaObjectDataOptions,
+    ) -> Result<IotaObjectResponse, Error> {
+        self.retry_policy
+            .retry_if(
+                || {
+                    self.iota_client
+                        .read_api()
+                        .get_object_with_options(object_id, options.clone())
+                },
+                |e| is_transient_message(&e.to_string()),
+            )
+            .await
+            .map_err(|e| Error::RpcError(format!("failed to get object {object_id}: {e}")))
+    }
+
     /// Retrieves the `last_state_change_at` timestamp of a notarized object.
     ///
     /// This timestamp indicates the time of the most recent state change for the object.
@@ -194,8 +1026,13 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing the timestamp as a `u64` or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn last_state_change_ts(&self, notarized_object_id: ObjectID) -> Result<u64, Error> {
-        let tx = NotarizationImpl::last_change_ts(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::last_change_ts(notarized_object_id, &tag, self))
+            .await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -210,8 +1047,13 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing the timestamp as a `u64` or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn created_at_ts(&self, notarized_object_id: ObjectID) -> Result<u64, Error> {
-        let tx = NotarizationImpl::created_at(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::created_at(notarized_object_id, &tag, self))
+            .await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -226,12 +1068,126 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing the version count as a `u64` or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn state_version_count(&self, notarized_object_id: ObjectID) -> Result<u64, Error> {
-        let tx = NotarizationImpl::version_count(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::version_count(notarized_object_id, &tag, self))
+            .await?;
 
         self.execute_read_only_transaction(tx).await
     }
 
+    /// Reconstructs the past states of a notarization from its on-chain update events.
+    ///
+    /// Every state update emits a `NotarizationUpdated` event carrying the complete new state,
+    /// so the data, metadata and [content hash](State::content_hash) of each version set by an
+    /// update can be recovered, along with the transaction that set it. The initial state
+    /// (version `0`) is not emitted in any event and is therefore not part of the history; it
+    /// is only available as the current state of a notarization that was never updated.
+    ///
+    /// Only the transactions that modified the notarization are read: starting with the latest
+    /// one, each transaction leads to the previous version of the object and the transaction
+    /// that produced it. The walk stops as soon as all versions of the state are found, so the
+    /// cost grows with the number of transactions on the notarization, not with the number of
+    /// notarizations on the network. The history is only complete if the node still serves all
+    /// past versions of the notarization; nodes that prune old objects return the retained
+    /// versions only.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`StateVersion`]s ordered by version, oldest first, or an
+    /// [`Error`]. The history of a notarization that was never updated is empty.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn state_history(&self, notarized_object_id: ObjectID) -> Result<Vec<StateVersion>, Error> {
+        let version_count = self.state_version_count(notarized_object_id).await?;
+        let data_tag = self.type_tag(notarized_object_id).await?;
+
+        let mut history = Vec::new();
+        let mut previous_transaction = self
+            .object_with_options(
+                notarized_object_id,
+                IotaObjectDataOptions::new().with_previous_transaction(),
+            )
+            .await?
+            .data
+            .and_then(|data| data.previous_transaction);
+        while let Some(digest) = previous_transaction.take() {
+            if history.len() as u64 >= version_count {
+                break;
+            }
+
+            let response = self
+                .retry_policy
+                .retry_if(
+                    || {
+                        self.iota_client.read_api().get_transaction_with_options(
+                            digest,
+                            IotaTransactionBlockResponseOptions::new().with_effects().with_events(),
+                        )
+                    },
+                    |e| is_transient_message(&e.to_string()),
+                )
+                .await
+                .map_err(|e| Error::RpcError(format!("failed to get transaction {digest}: {e}")))?
+                .clone_native_response();
+
+            for event in response.events.iter().flat_map(|events| &events.data) {
+                if event.type_.module.as_str() != "notarization" || event.type_.name.as_str() != "NotarizationUpdated" {
+                    continue;
+                }
+                let (notarization_id, version) = StateVersion::from_updated_event(
+                    &event.parsed_json,
+                    &data_tag,
+                    event.timestamp_ms,
+                    event.id.tx_digest,
+                )?;
+                if notarization_id == notarized_object_id {
+                    history.push(version);
+                }
+            }
+
+            // The version the transaction modified; none for the transaction that created the object.
+            let Some(previous_version) = response.effects.as_ref().and_then(|effects| {
+                effects
+                    .modified_at_versions()
+                    .into_iter()
+                    .find_map(|(object_id, version)| (object_id == notarized_object_id).then_some(version))
+            }) else {
+                break;
+            };
+            let past_object = self
+                .retry_policy
+                .retry_if(
+                    || {
+                        self.iota_client.read_api().try_get_parsed_past_object(
+                            notarized_object_id,
+                            previous_version,
+                            IotaObjectDataOptions::new().with_previous_transaction(),
+                        )
+                    },
+                    |e| is_transient_message(&e.to_string()),
+                )
+                .await
+                .map_err(|e| {
+                    Error::RpcError(format!(
+                        "failed to get version {previous_version} of object {notarized_object_id}: {e}"
+                    ))
+                })?;
+            // Pruned versions end the walk.
+            if let IotaPastObjectResponse::VersionFound(data) = past_object {
+                previous_transaction = data.previous_transaction;
+            }
+        }
+        history.sort_by_key(|version| version.version);
+
+        Ok(history)
+    }
+
     /// Retrieves the `description` of a notarization object by its `object_id`.
     ///
     /// The description is an optional string associated with the object.
@@ -242,12 +1198,36 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing an `Option<String>` or an [`Error`]. `None` if no description is set.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn description(&self, notarized_object_id: ObjectID) -> Result<Option<String>, Error> {
-        let tx = NotarizationImpl::description(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::description(notarized_object_id, &tag, self))
+            .await?;
 
         self.execute_read_only_transaction(tx).await
     }
 
+    /// Retrieves the [`ImmutableMetadata`] of a notarization object by its `object_id`.
+    ///
+    /// The metadata is decoded from the BCS content of the object, so `created_at`,
+    /// `description` and `locking` are returned with a single object read instead of a
+    /// dev-inspect call per field.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`ImmutableMetadata`] or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn immutable_metadata(&self, notarized_object_id: ObjectID) -> Result<ImmutableMetadata, Error> {
+        let notarization = self.get_notarization_by_id(notarized_object_id).await?;
+
+        Ok(notarization.immutable_metadata)
+    }
+
     /// Retrieves the `updatable_metadata` of a notarization object by its `object_id`.
     ///
     /// This metadata is an optional string that can be updated after creation.
@@ -258,8 +1238,13 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing an `Option<String>` or an [`Error`]. `None` if no updatable metadata is set.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn updatable_metadata(&self, notarized_object_id: ObjectID) -> Result<Option<String>, Error> {
-        let tx = NotarizationImpl::updatable_metadata(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::updatable_metadata(notarized_object_id, &tag, self))
+            .await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -274,8 +1259,13 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing the [`NotarizationMethod`] or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn notarization_method(&self, notarized_object_id: ObjectID) -> Result<NotarizationMethod, Error> {
-        let tx = NotarizationImpl::notarization_method(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::notarization_method(notarized_object_id, &tag, self))
+            .await?;
         self.execute_read_only_transaction(tx).await
     }
 
@@ -289,8 +1279,13 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing an `Option<LockMetadata>` or an [`Error`]. `None` if no locks are set.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn lock_metadata(&self, notarized_object_id: ObjectID) -> Result<Option<LockMetadata>, Error> {
-        let tx = NotarizationImpl::lock_metadata(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::lock_metadata(notarized_object_id, &tag, self))
+            .await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -309,28 +1304,53 @@ impl NotarizationClientReadOnly {
     /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
     ///
     /// # Returns
-    /// A `Result` containing the [`State<Data>`] or an [`Error`].
+    /// A `Result` containing the [`State<Data>`] or an [`Error`]. Fails with
+    /// [`Error::InvalidArgument`] if the state is a `string::String` whose bytes are not valid
+    /// UTF-8, which only a corrupt object can contain.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn state(&self, notarized_object_id: ObjectID) -> Result<State, Error> {
-        let type_tag = move_utils::get_type_tag(self, &notarized_object_id).await?;
-        let type_str = type_tag.to_string();
+        let type_tag = self.type_tag(notarized_object_id).await?;
+        if !Data::is_supported_type_tag(&type_tag) {
+            return Err(Error::InvalidArgument(format!("Unsupported state type: {type_tag}")));
+        }
 
-        let tx = NotarizationImpl::state(notarized_object_id, self).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::state(notarized_object_id, &type_tag, self))
+            .await?;
 
-        if type_str == "vector<u8>" {
-            let state: State<Vec<u8>> = self.execute_read_only_transaction(tx).await?;
-            Ok(State {
-                data: Data::Bytes(state.data),
-                metadata: state.metadata,
-            })
-        } else if type_str.contains("::string::String") {
-            let state: State<String> = self.execute_read_only_transaction(tx).await?;
-            Ok(State {
-                data: Data::Text(state.data),
-                metadata: state.metadata,
-            })
-        } else {
-            return Err(Error::InvalidArgument(format!("Unsupported state type: {type_str}")));
-        }
+        // `vector<u8>` and `string::String` share the same BCS encoding; the kind of data is
+        // taken from the type tag only, never from the content.
+        let state: State<Vec<u8>> = self.execute_read_only_transaction(tx).await?;
+        let data = Data::Bytes(state.data).try_with_type_tag(&type_tag).map_err(|e| {
+            Error::InvalidArgument(format!(
+                "state of notarization {notarized_object_id} claims String type but contains invalid UTF-8: {e}"
+            ))
+        })?;
+        Ok(State {
+            data,
+            metadata: state.metadata,
+        })
+    }
+
+    /// Retrieves the metadata of the `state` of a notarization object by its `object_id`.
+    ///
+    /// This reads the whole notarization object, including the state data, and is no cheaper
+    /// than [`Self::get_notarization_by_id`]: the contract has no getter for the state metadata
+    /// alone, and an object read always returns the full object content. Like [`Self::state`],
+    /// this handles the default state types only.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing an `Option<String>` or an [`Error`]. `None` if the state has no metadata.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn state_metadata(&self, notarized_object_id: ObjectID) -> Result<Option<String>, Error> {
+        let notarization = self.get_notarization_by_id(notarized_object_id).await?;
+
+        Ok(notarization.state.metadata)
     }
 
     /// Retrieves the `state` of a notarization object by its `object_id` and deserializes it into a custom type `T`.
@@ -342,12 +1362,129 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing the [`State<T>`] or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn state_as<T: DeserializeOwned>(&self, notarized_object_id: ObjectID) -> Result<State<T>, Error> {
-        let tx = NotarizationImpl::state(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::state(notarized_object_id, &tag, self))
+            .await?;
 
         self.execute_read_only_transaction(tx).await
     }
 
+    /// Retrieves the `state` of a notarization object as the raw BCS bytes of the Move
+    /// `State<D>` returned by the contract.
+    ///
+    /// Use this to decode states of application specific types, e.g. to dispatch between several
+    /// schemas stored in the same kind of notarization. See also
+    /// [`NotarizationClientReadOnly::state_with`].
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the BCS encoded state or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn state_raw(&self, notarized_object_id: ObjectID) -> Result<Vec<u8>, Error> {
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::state(notarized_object_id, &tag, self))
+            .await?;
+
+        self.execute_read_only_transaction_raw(tx).await
+    }
+
+    /// Retrieves the Move type `D` of the state data of a `Notarization<D>` object, e.g.
+    /// `vector<u8>` or `0x1::string::String`.
+    ///
+    /// Use it together with [`NotarizationClientReadOnly::state_raw`] to decode states of
+    /// custom types.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`TypeTag`] of the state data or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn state_type(&self, notarized_object_id: ObjectID) -> Result<TypeTag, Error> {
+        self.type_tag(notarized_object_id).await
+    }
+
+    /// Retrieves the `state` of a notarization object and decodes it with `decode`.
+    ///
+    /// `decode` receives the raw BCS bytes of the Move `State<D>` (see
+    /// [`NotarizationClientReadOnly::state_raw`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    /// * `decode`: Decodes the raw state into `T`.
+    ///
+    /// # Returns
+    /// A `Result` containing the decoded state or an [`Error`] returned by the request or by `decode`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let state = client
+    ///     .state_with(object_id, |bytes| {
+    ///         let state: State<String> = bcs::from_bytes(bytes)?;
+    ///         AppSchema::from_tagged_json(&state.data)
+    ///     })
+    ///     .await?;
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self, decode), err))]
+    pub async fn state_with<T, F>(&self, notarized_object_id: ObjectID, decode: F) -> Result<T, Error>
+    where
+        F: FnOnce(&[u8]) -> Result<T, Error>,
+    {
+        let bytes = self.state_raw(notarized_object_id).await?;
+
+        decode(&bytes)
+    }
+
+    /// Computes the [content hash](State::content_hash) of the state of a notarized object.
+    ///
+    /// Compare the result with the SHA-256 hash of a local file to check that the file
+    /// matches the notarization.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the SHA-256 hash of the notarized data or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn state_hash(&self, notarized_object_id: ObjectID) -> Result<[u8; 32], Error> {
+        self.state(notarized_object_id).await.map(|state| state.content_hash())
+    }
+
+    /// Retrieves the state of a notarized object whose data was stored as JSON, e.g. with
+    /// [`State::from_json`], and parses it into `T`.
+    ///
+    /// Unlike [`NotarizationClientReadOnly::state_as`], which decodes the BCS representation,
+    /// this method parses the JSON text of the notarized data.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`State<T>`] or an [`Error`] if the data is not valid JSON for `T`.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn state_as_json<T: DeserializeOwned>(&self, notarized_object_id: ObjectID) -> Result<State<T>, Error> {
+        let State { data, metadata } = self.state(notarized_object_id).await?;
+
+        Ok(State {
+            data: data.as_json()?,
+            metadata,
+        })
+    }
+
     /// Checks if the notarized object is currently locked against state updates.
     ///
     /// # Arguments
@@ -356,8 +1493,13 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing `true` if the object is update-locked, `false` otherwise, or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn is_update_locked(&self, notarized_object_id: ObjectID) -> Result<bool, Error> {
-        let tx = NotarizationImpl::is_update_locked(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::is_update_locked(notarized_object_id, &tag, self.clock, self))
+            .await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -370,8 +1512,13 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing `true` if the object is destroy-allowed, `false` otherwise, or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn is_destroy_allowed(&self, notarized_object_id: ObjectID) -> Result<bool, Error> {
-        let tx = NotarizationImpl::is_destroy_allowed(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::is_destroy_allowed(notarized_object_id, &tag, self.clock, self))
+            .await?;
 
         self.execute_read_only_transaction(tx).await
     }
@@ -384,14 +1531,372 @@ impl NotarizationClientReadOnly {
     ///
     /// # Returns
     /// A `Result` containing `true` if the object is transfer-locked, `false` otherwise, or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
     pub async fn is_transfer_locked(&self, notarized_object_id: ObjectID) -> Result<bool, Error> {
-        let tx = NotarizationImpl::is_transfer_locked(notarized_object_id, self).await?;
+        let tag = self.type_tag(notarized_object_id).await?;
+        let tx = self
+            .retry_policy
+            .retry(|| NotarizationImpl::is_transfer_locked(notarized_object_id, &tag, self.clock, self))
+            .await?;
 
         self.execute_read_only_transaction(tx).await
     }
+
+    /// Returns the number of seconds until the update lock of the notarized object is released.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing `Some(seconds)` for an [`TimeLock::UnlockAt`] lock (`Some(0)` once it
+    /// expired), `None` if the object has no such lock (`TimeLock::None`, `TimeLock::UntilDestroyed`
+    /// or no lock metadata at all), or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn remaining_update_lock(&self, notarized_object_id: ObjectID) -> Result<Option<u64>, Error> {
+        self.remaining_lock(notarized_object_id, |locking| locking.update_lock)
+            .await
+    }
+
+    /// Returns the number of seconds until the delete lock of the notarized object is released.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing `Some(seconds)` for an [`TimeLock::UnlockAt`] lock (`Some(0)` once it
+    /// expired), `None` if the object has no such lock (`TimeLock::None`, `TimeLock::UntilDestroyed`
+    /// or no lock metadata at all), or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn remaining_delete_lock(&self, notarized_object_id: ObjectID) -> Result<Option<u64>, Error> {
+        self.remaining_lock(notarized_object_id, |locking| locking.delete_lock)
+            .await
+    }
+
+    /// Returns the number of seconds until the transfer lock of the notarized object is released.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing `Some(seconds)` for an [`TimeLock::UnlockAt`] lock (`Some(0)` once it
+    /// expired), `None` if the object has no such lock (`TimeLock::None`, `TimeLock::UntilDestroyed`
+    /// or no lock metadata at all), or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn remaining_transfer_lock(&self, notarized_object_id: ObjectID) -> Result<Option<u64>, Error> {
+        self.remaining_lock(notarized_object_id, |locking| locking.transfer_lock)
+            .await
+    }
+
+    /// Returns the current time of the network, in seconds since the Unix epoch.
+    ///
+    /// The time is read from the on-chain clock (see
+    /// [`with_clock_object_id`](Self::with_clock_object_id)), which is what the contract
+    /// evaluates locks against. Use it instead of the local system time to compute lock statuses,
+    /// e.g. with [`LockMetadata::evaluate`](crate::core::types::LockMetadata::evaluate), so that
+    /// they are not off by the drift of the local clock.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn now_ts(&self) -> Result<u64, Error> {
+        Ok(move_utils::get_clock_timestamp_ms(self, self.clock).await? / 1000)
+    }
+
+    /// Resolves which operations would currently succeed on the notarized object.
+    ///
+    /// The notarization and the on-chain clock are fetched once and the locks are
+    /// evaluated locally, following the same rules as the Move contract.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`AvailableActions`] of the object, or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn available_actions(&self, notarized_object_id: ObjectID) -> Result<AvailableActions, Error> {
+        let notarization = self.get_notarization_by_id(notarized_object_id).await?;
+        let now = self.now_ts().await?;
+
+        Ok(AvailableActions::from(&notarization.lock_status_at(now)))
+    }
+
+    /// Retrieves the lock metadata of the notarized object together with its resolved lock flags.
+    ///
+    /// Unlike calling [`lock_metadata`](Self::lock_metadata), [`is_update_locked`](Self::is_update_locked),
+    /// [`is_transfer_locked`](Self::is_transfer_locked) and [`is_destroy_allowed`](Self::is_destroy_allowed)
+    /// one by one, the notarization and the on-chain clock are fetched once and the locks are
+    /// evaluated locally, so the returned values are consistent with each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`LockStatus`] of the object, or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn lock_status(&self, notarized_object_id: ObjectID) -> Result<LockStatus, Error> {
+        let notarization = self.get_notarization_by_id(notarized_object_id).await?;
+        let now = self.now_ts().await?;
+
+        Ok(notarization.lock_status_at(now))
+    }
+
+    /// Waits until the notarized object can be destroyed.
+    ///
+    /// The lock status is evaluated against the on-chain clock every `poll_interval`, so the
+    /// result does not depend on the local clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    /// * `poll_interval`: The time between two checks of the lock status.
+    /// * `max_wait`: The maximum total time to wait.
+    ///
+    /// # Returns
+    /// `Ok(())` as soon as destroying the object is allowed. Returns [`Error::Locked`] if it is
+    /// still not allowed after `max_wait`, or any other [`Error`] of the status queries.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn wait_until_destroy_allowed(
+        &self,
+        notarized_object_id: ObjectID,
+        poll_interval: Duration,
+        max_wait: Duration,
+    ) -> Result<(), Error> {
+        self.wait_until_unlocked(notarized_object_id, LockedOperation::Delete, poll_interval, max_wait)
+            .await
+    }
+
+    /// Waits until the notarized object can be transferred.
+    ///
+    /// The lock status is evaluated against the on-chain clock every `poll_interval`, so the
+    /// result does not depend on the local clock.
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    /// * `poll_interval`: The time between two checks of the lock status.
+    /// * `max_wait`: The maximum total time to wait.
+    ///
+    /// # Returns
+    /// `Ok(())` as soon as transferring the object is allowed. Returns [`Error::Locked`] if it
+    /// is still not allowed after `max_wait`, or right away if the transfer lock is never
+    /// released (`UntilDestroyed`). Returns any other [`Error`] of the status queries.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub async fn wait_until_transfer_unlocked(
+        &self,
+        notarized_object_id: ObjectID,
+        poll_interval: Duration,
+        max_wait: Duration,
+    ) -> Result<(), Error> {
+        self.wait_until_unlocked(notarized_object_id, LockedOperation::Transfer, poll_interval, max_wait)
+            .await
+    }
+
+    /// Classifies how well the notarized object is protected against changes.
+    ///
+    /// The classification is derived from the notarization method and its locks, evaluated
+    /// against the on-chain clock, and is meant to be shown to users (e.g. as a badge).
+    ///
+    /// # Arguments
+    ///
+    /// * `notarized_object_id`: The [`ObjectID`] of the notarized object.
+    ///
+    /// # Returns
+    /// A `Result` containing the [`ProtectionStatus`] of the object, or an [`Error`].
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(self), err))]
+    pub async fn protection_status(&self, notarized_object_id: ObjectID) -> Result<ProtectionStatus, Error> {
+        let notarization = self.get_notarization_by_id(notarized_object_id).await?;
+        let now = self.now_ts().await?;
+
+        Ok(notarization.protection_status_at(now))
+    }
+
+    /// Subscribes to the lifecycle events of all notarizations on the connected network.
+    ///
+    /// The subscription is scoped to the Notarization package of this client; events of its
+    /// `notarization`, `dynamic_notarization` and `locked_notarization` modules are yielded
+    /// as [`NotarizationEvent`]s in the order they are emitted. Dropping the stream ends the
+    /// subscription.
+    ///
+    /// Subscriptions require a WebSocket connection, i.e. the `IotaClient` the client was
+    /// created with must have been built with a WebSocket URL.
+    ///
+    /// # Returns
+    /// A `Result` containing the stream of events, or an [`Error`] if the subscription could not
+    /// be established. Errors of the subscription and events that cannot be parsed are yielded
+    /// as stream items.
+    #[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+    pub async fn subscribe_events(&self) -> Result<impl Stream<Item = Result<NotarizationEvent, Error>>, Error> {
+        let event_client = self.event_client.as_ref().ok_or_else(|| {
+            Error::InvalidConfig("event subscriptions require a client created from an `IotaClient`".to_string())
+        })?;
+        let events = event_client
+            .event_api()
+            .subscribe_event(EventFilter::Package(self.notarization_pkg_id))
+            .await
+            .map_err(|e| Error::RpcError(format!("failed to subscribe to notarization events: {e}")))?;
+
+        Ok(events.filter_map(|event| {
+            let event = match event {
+                Ok(event) if EVENT_MODULES.contains(&event.type_.module.as_str()) => {
+                    NotarizationEvent::from_move_event(event.type_.name.as_str(), &event.parsed_json).transpose()
+                }
+                Ok(_) => None,
+                Err(e) => Some(Err(Error::RpcError(format!(
+                    "notarization event subscription failed: {e}"
+                )))),
+            };
+            future::ready(event)
+        }))
+    }
+
+    /// Crawls all notarizations ever created under the Notarization package of this client.
+    ///
+    /// The creation events (`DynamicNotarizationCreated`, then `LockedNotarizationCreated`) are
+    /// paged through oldest first, and each page is resolved to the current on-chain objects
+    /// with [`NotarizationClientReadOnly::get_notarizations_by_ids`]. Notarizations that were
+    /// destroyed in the meantime are skipped. Pages are only requested as the stream is polled.
+    ///
+    /// Only notarizations whose creation events the node still serves are found; nodes that
+    /// prune old events yield the retained ones only.
+    ///
+    /// # Returns
+    /// A stream of the current [`OnChainNotarization`]s. A failing request is yielded as an
+    /// [`Error`] and ends the stream.
+    #[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+    pub fn all_notarizations(&self) -> impl Stream<Item = Result<OnChainNotarization, Error>> + '_ {
+        // Event types are bound to the package version that first defined them.
+        let original_package_id = self.original_package_id();
+        let filters = [
+            (
+                ident_str!("dynamic_notarization"),
+                ident_str!("DynamicNotarizationCreated"),
+            ),
+            (
+                ident_str!("locked_notarization"),
+                ident_str!("LockedNotarizationCreated"),
+            ),
+        ]
+        .map(|(module, name)| {
+            EventFilter::MoveEventType(StructTag {
+                address: original_package_id.into(),
+                module: module.into(),
+                name: name.into(),
+                type_params: vec![],
+            })
+        });
+
+        stream::iter(filters).flat_map(move |filter| self.created_notarizations(filter))
+    }
 }
 
 impl NotarizationClientReadOnly {
+    /// Polls the lock status until `operation` is allowed, see
+    /// [`NotarizationClientReadOnly::wait_until_destroy_allowed`].
+    #[cfg(not(target_arch = "wasm32"))]
+    async fn wait_until_unlocked(
+        &self,
+        notarized_object_id: ObjectID,
+        operation: LockedOperation,
+        poll_interval: Duration,
+        max_wait: Duration,
+    ) -> Result<(), Error> {
+        let started = std::time::Instant::now();
+        loop {
+            let status = self.lock_status(notarized_object_id).await?;
+            let allowed = match operation {
+                LockedOperation::Transfer => !status.is_transfer_locked,
+                _ => status.is_destroy_allowed,
+            };
+            if allowed {
+                return Ok(());
+            }
+
+            let unlock_at = status.lock_metadata.as_ref().and_then(|locking| match operation {
+                LockedOperation::Transfer => unlock_time([&locking.transfer_lock]),
+                _ => unlock_time([&locking.update_lock, &locking.delete_lock, &locking.transfer_lock]),
+            });
+            let elapsed = started.elapsed();
+            // A transfer lock without an unlock time is only released by destroying the object.
+            let is_permanent = operation == LockedOperation::Transfer && unlock_at.is_none();
+            if is_permanent || elapsed >= max_wait {
+                return Err(Error::Locked { operation, unlock_at });
+            }
+            tokio::time::sleep(poll_interval.min(max_wait - elapsed)).await;
+        }
+    }
+
+    /// Pages through the creation events matching `filter` and resolves them to the notarizations
+    /// that still exist, see [`NotarizationClientReadOnly::all_notarizations`].
+    #[cfg(all(feature = "streaming", not(target_arch = "wasm32")))]
+    fn created_notarizations(
+        &self,
+        filter: EventFilter,
+    ) -> impl Stream<Item = Result<OnChainNotarization, Error>> + '_ {
+        // The state is the cursor of the next page, or `None` once the last page was read.
+        stream::unfold(Some(None), move |cursor| {
+            let filter = filter.clone();
+            async move {
+                let cursor = cursor?;
+                let page = match self
+                    .retry_policy
+                    .retry_if(
+                        || {
+                            self.iota_client
+                                .event_api()
+                                .query_events(filter.clone(), cursor, None, false)
+                        },
+                        |e| is_transient_message(&e.to_string()),
+                    )
+                    .await
+                {
+                    Ok(page) => page,
+                    Err(e) => {
+                        let error = Error::RpcError(format!("failed to query creation events: {e}"));
+                        return Some((vec![Err(error)], None));
+                    }
+                };
+                let next_cursor = page.next_cursor.filter(|_| page.has_next_page).map(Some);
+
+                let notarization_ids = page
+                    .data
+                    .iter()
+                    .filter_map(|event| {
+                        NotarizationEvent::from_move_event(event.type_.name.as_str(), &event.parsed_json)
+                            .map(|event| event.and_then(|event| event.notarization_id()))
+                            .transpose()
+                    })
+                    .collect::<Result<Vec<_>, _>>();
+                let notarizations = match notarization_ids {
+                    Ok(notarization_ids) => self.get_notarizations_by_ids(&notarization_ids).await,
+                    Err(e) => Err(e),
+                };
+
+                match notarizations {
+                    // Destroyed notarizations are resolved to `None`.
+                    Ok(notarizations) => Some((notarizations.into_iter().flatten().map(Ok).collect(), next_cursor)),
+                    Err(e) => Some((vec![Err(e)], None)),
+                }
+            }
+        })
+        .flat_map(stream::iter)
+    }
+
+    /// Combines the lock selected by `select` with the on-chain clock into the remaining lock time.
+    async fn remaining_lock(
+        &self,
+        notarized_object_id: ObjectID,
+        select: impl FnOnce(LockMetadata) -> TimeLock,
+    ) -> Result<Option<u64>, Error> {
+        let Some(locking) = self.lock_metadata(notarized_object_id).await? else {
+            return Ok(None);
+        };
+        let now = self.now_ts().await?;
+
+        Ok(select(locking).remaining_secs(now))
+    }
+
     /// A helper function to execute a read-only transaction and deserialize
     /// the result into the specified type `T`.
     ///
@@ -400,6 +1905,9 @@ impl NotarizationClientReadOnly {
     /// to the network. The result of the first return value of the first execution result
     /// is deserialized using BCS.
     ///
+    /// Move functions without return values are accepted for types without content, like `()`,
+    /// which are deserialized from no bytes at all.
+    ///
     /// # Arguments
     ///
     /// * `tx`: The [`ProgrammableTransaction`] to execute.
@@ -410,10 +1918,43 @@ impl NotarizationClientReadOnly {
         &self,
         tx: ProgrammableTransaction,
     ) -> Result<T, Error> {
+        let function = called_function(&tx);
+        match self.inspect_first_return_value(tx).await? {
+            Some(return_value_bytes) => Ok(bcs::from_bytes::<T>(&return_value_bytes)?),
+            None => bcs::from_bytes::<T>(&[]).map_err(|_| missing_return_value(&function)),
+        }
+    }
+
+    /// Like [`NotarizationClientReadOnly::execute_read_only_transaction`], but returns the BCS
+    /// bytes of the first return value without deserializing them.
+    async fn execute_read_only_transaction_raw(&self, tx: ProgrammableTransaction) -> Result<Vec<u8>, Error> {
+        let function = called_function(&tx);
+        self.inspect_first_return_value(tx)
+            .await?
+            .ok_or_else(|| missing_return_value(&function))
+    }
+
+    /// Dev-inspects `tx` and returns the BCS bytes of the first return value of the first
+    /// execution result, or `None` if the first command returned nothing.
+    #[cfg_attr(
+        feature = "tracing",
+        tracing::instrument(level = "debug", name = "dev_inspect", skip_all, err)
+    )]
+    async fn inspect_first_return_value(&self, tx: ProgrammableTransaction) -> Result<Option<Vec<u8>>, Error> {
         let inspection_result = self
-            .iota_client
-            .read_api()
-            .dev_inspect_transaction_block(IotaAddress::ZERO, TransactionKind::programmable(tx), None, None, None)
+            .retry_policy
+            .retry_if(
+                || {
+                    self.iota_client.read_api().dev_inspect_transaction_block(
+                        self.inspection_sender,
+                        TransactionKind::programmable(tx.clone()),
+                        None,
+                        None,
+                        None,
+                    )
+                },
+                |e| is_transient_message(&e.to_string()),
+            )
             .await
             .map_err(|err| Error::UnexpectedApiResponse(format!("Failed to inspect transaction block: {err}")))?;
 
@@ -421,16 +1962,16 @@ impl NotarizationClientReadOnly {
             .results
             .ok_or_else(|| Error::UnexpectedApiResponse("DevInspectResults missing 'results' field".to_string()))?;
 
-        let (return_value_bytes, _) = execution_results
-            .first()
+        let return_value = execution_results
+            .into_iter()
+            .next()
             .ok_or_else(|| Error::UnexpectedApiResponse("Execution results list is empty".to_string()))?
             .return_values
-            .first()
-            .ok_or_else(|| Error::InvalidArgument("should have at least one return value".to_string()))?;
-
-        let deserialized_output = bcs::from_bytes::<T>(return_value_bytes)?;
+            .into_iter()
+            .next()
+            .map(|(return_value_bytes, _)| return_value_bytes);
 
-        Ok(deserialized_output)
+        Ok(return_value)
     }
 }
 
@@ -455,3 +1996,28 @@ impl CoreClientReadOnly for NotarizationClientReadOnly {
         &self.iota_client
     }
 }
+
+/// Returns the `module::function` called by the first command of `tx`, for error messages.
+fn called_function(tx: &ProgrammableTransaction) -> String {
+    match tx.commands.first() {
+        Some(Command::MoveCall(call)) => format!("{}::{}", call.module, call.function),
+        _ => "the first command".to_string(),
+    }
+}
+
+/// The error for a read call whose Move function returned no value although one was expected.
+fn missing_return_value(function: &str) -> Error {
+    Error::InvalidArgument(format!("`{function}` should have at least one return value"))
+}
+
+/// Returns the latest unlock time (seconds since the Unix epoch) of the `UnlockAt` locks.
+#[cfg(not(target_arch = "wasm32"))]
+fn unlock_time<'a>(locks: impl IntoIterator<Item = &'a TimeLock>) -> Option<u64> {
+    locks
+        .into_iter()
+        .filter_map(|lock| match lock {
+            TimeLock::UnlockAt(unlock_time) => Some(u64::from(*unlock_time)),
+            _ => None,
+        })
+        .max()
+}
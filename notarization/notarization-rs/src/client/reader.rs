@@ -0,0 +1,206 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Notarization Reader
+//!
+//! An abstraction over the read methods of [`NotarizationClientReadOnly`].
+//!
+//! ## Overview
+//!
+//! Application code that only reads notarizations can be written against [`NotarizationReader`]
+//! instead of a concrete client. In production the reader is a [`NotarizationClientReadOnly`]
+//! (or a [`NotarizationClient`](super::NotarizationClient), which dereferences to it); in unit
+//! tests it can be replaced by the in-memory `MockNotarizationClient` of the `test-utils`
+//! feature, which serves pre-seeded notarizations without a node.
+//!
+//! ```rust,ignore
+//! # use iota_interaction::types::base_types::ObjectID;
+//! # use notarization::client::NotarizationReader;
+//! async fn is_current(reader: &impl NotarizationReader, id: ObjectID) -> Result<bool, notarization::error::Error> {
+//!     Ok(reader.state_version_count(id).await? > 0 && !reader.is_update_locked(id).await?)
+//! }
+//! ```
+//!
+//! Except for [`get_notarization_by_id`](NotarizationReader::get_notarization_by_id) and
+//! [`clock_timestamp_ms`](NotarizationReader::clock_timestamp_ms), all methods have default
+//! implementations that evaluate the fetched notarization locally, following the same rules as
+//! the Move contract.
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::types::base_types::ObjectID;
+
+use super::read_only::NotarizationClientReadOnly;
+use crate::core::move_utils;
+use crate::core::types::{
+    AvailableActions, ImmutableMetadata, LockMetadata, LockStatus, NotarizationMethod, OnChainNotarization, State,
+};
+use crate::error::Error;
+
+/// Read access to notarizations.
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+pub trait NotarizationReader: OptionalSync {
+    /// Retrieves the [`OnChainNotarization`] with the ID `notarized_object_id`.
+    async fn get_notarization_by_id(&self, notarized_object_id: ObjectID) -> Result<OnChainNotarization, Error>;
+
+    /// Returns the current time of the network, in milliseconds since the Unix epoch.
+    async fn clock_timestamp_ms(&self) -> Result<u64, Error>;
+
+    /// Retrieves the state of the notarized object.
+    async fn state(&self, notarized_object_id: ObjectID) -> Result<State, Error> {
+        Ok(self.get_notarization_by_id(notarized_object_id).await?.state)
+    }
+
+    /// Retrieves the immutable description of the notarized object.
+    async fn description(&self, notarized_object_id: ObjectID) -> Result<Option<String>, Error> {
+        Ok(self.immutable_metadata(notarized_object_id).await?.description)
+    }
+
+    /// Retrieves the immutable metadata of the notarized object.
+    async fn immutable_metadata(&self, notarized_object_id: ObjectID) -> Result<ImmutableMetadata, Error> {
+        Ok(self
+            .get_notarization_by_id(notarized_object_id)
+            .await?
+            .immutable_metadata)
+    }
+
+    /// Retrieves the updatable metadata of the notarized object.
+    async fn updatable_metadata(&self, notarized_object_id: ObjectID) -> Result<Option<String>, Error> {
+        Ok(self
+            .get_notarization_by_id(notarized_object_id)
+            .await?
+            .updatable_metadata)
+    }
+
+    /// Retrieves the lock metadata of the notarized object.
+    async fn lock_metadata(&self, notarized_object_id: ObjectID) -> Result<Option<LockMetadata>, Error> {
+        Ok(self.immutable_metadata(notarized_object_id).await?.locking)
+    }
+
+    /// Retrieves the [`NotarizationMethod`] of the notarized object.
+    async fn notarization_method(&self, notarized_object_id: ObjectID) -> Result<NotarizationMethod, Error> {
+        Ok(self.get_notarization_by_id(notarized_object_id).await?.method)
+    }
+
+    /// Retrieves the number of state updates of the notarized object.
+    async fn state_version_count(&self, notarized_object_id: ObjectID) -> Result<u64, Error> {
+        Ok(self
+            .get_notarization_by_id(notarized_object_id)
+            .await?
+            .state_version_count)
+    }
+
+    /// Retrieves the timestamp (in milliseconds) of the last state change of the notarized object.
+    async fn last_state_change_ts(&self, notarized_object_id: ObjectID) -> Result<u64, Error> {
+        Ok(self
+            .get_notarization_by_id(notarized_object_id)
+            .await?
+            .last_state_change_at)
+    }
+
+    /// Retrieves the creation timestamp (in milliseconds) of the notarized object.
+    async fn created_at_ts(&self, notarized_object_id: ObjectID) -> Result<u64, Error> {
+        Ok(self.immutable_metadata(notarized_object_id).await?.created_at)
+    }
+
+    /// Checks if the notarized object is currently locked for updates.
+    async fn is_update_locked(&self, notarized_object_id: ObjectID) -> Result<bool, Error> {
+        Ok(self.lock_status(notarized_object_id).await?.is_update_locked)
+    }
+
+    /// Checks if the notarized object is currently locked against transfer.
+    async fn is_transfer_locked(&self, notarized_object_id: ObjectID) -> Result<bool, Error> {
+        Ok(self.lock_status(notarized_object_id).await?.is_transfer_locked)
+    }
+
+    /// Checks if the notarized object is currently allowed to be destroyed.
+    async fn is_destroy_allowed(&self, notarized_object_id: ObjectID) -> Result<bool, Error> {
+        Ok(self.lock_status(notarized_object_id).await?.is_destroy_allowed)
+    }
+
+    /// Resolves which operations would currently succeed on the notarized object.
+    async fn available_actions(&self, notarized_object_id: ObjectID) -> Result<AvailableActions, Error> {
+        let notarization = self.get_notarization_by_id(notarized_object_id).await?;
+        let now = self.clock_timestamp_ms().await? / 1000;
+
+        Ok(AvailableActions::from(&notarization.lock_status_at(now)))
+    }
+
+    /// Retrieves the lock metadata of the notarized object together with its resolved lock flags.
+    async fn lock_status(&self, notarized_object_id: ObjectID) -> Result<LockStatus, Error> {
+        let notarization = self.get_notarization_by_id(notarized_object_id).await?;
+        let now = self.clock_timestamp_ms().await? / 1000;
+
+        Ok(notarization.lock_status_at(now))
+    }
+}
+
+/// Delegates to the inherent methods, which query the node (e.g. with read-only Move calls).
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl NotarizationReader for NotarizationClientReadOnly {
+    async fn get_notarization_by_id(&self, notarized_object_id: ObjectID) -> Result<OnChainNotarization, Error> {
+        NotarizationClientReadOnly::get_notarization_by_id(self, notarized_object_id).await
+    }
+
+    async fn clock_timestamp_ms(&self) -> Result<u64, Error> {
+        move_utils::get_clock_timestamp_ms(self, self.clock()).await
+    }
+
+    async fn state(&self, notarized_object_id: ObjectID) -> Result<State, Error> {
+        NotarizationClientReadOnly::state(self, notarized_object_id).await
+    }
+
+    async fn description(&self, notarized_object_id: ObjectID) -> Result<Option<String>, Error> {
+        NotarizationClientReadOnly::description(self, notarized_object_id).await
+    }
+
+    async fn immutable_metadata(&self, notarized_object_id: ObjectID) -> Result<ImmutableMetadata, Error> {
+        NotarizationClientReadOnly::immutable_metadata(self, notarized_object_id).await
+    }
+
+    async fn updatable_metadata(&self, notarized_object_id: ObjectID) -> Result<Option<String>, Error> {
+        NotarizationClientReadOnly::updatable_metadata(self, notarized_object_id).await
+    }
+
+    async fn lock_metadata(&self, notarized_object_id: ObjectID) -> Result<Option<LockMetadata>, Error> {
+        NotarizationClientReadOnly::lock_metadata(self, notarized_object_id).await
+    }
+
+    async fn notarization_method(&self, notarized_object_id: ObjectID) -> Result<NotarizationMethod, Error> {
+        NotarizationClientReadOnly::notarization_method(self, notarized_object_id).await
+    }
+
+    async fn state_version_count(&self, notarized_object_id: ObjectID) -> Result<u64, Error> {
+        NotarizationClientReadOnly::state_version_count(self, notarized_object_id).await
+    }
+
+    async fn last_state_change_ts(&self, notarized_object_id: ObjectID) -> Result<u64, Error> {
+        NotarizationClientReadOnly::last_state_change_ts(self, notarized_object_id).await
+    }
+
+    async fn created_at_ts(&self, notarized_object_id: ObjectID) -> Result<u64, Error> {
+        NotarizationClientReadOnly::created_at_ts(self, notarized_object_id).await
+    }
+
+    async fn is_update_locked(&self, notarized_object_id: ObjectID) -> Result<bool, Error> {
+        NotarizationClientReadOnly::is_update_locked(self, notarized_object_id).await
+    }
+
+    async fn is_transfer_locked(&self, notarized_object_id: ObjectID) -> Result<bool, Error> {
+        NotarizationClientReadOnly::is_transfer_locked(self, notarized_object_id).await
+    }
+
+    async fn is_destroy_allowed(&self, notarized_object_id: ObjectID) -> Result<bool, Error> {
+        NotarizationClientReadOnly::is_destroy_allowed(self, notarized_object_id).await
+    }
+
+    async fn available_actions(&self, notarized_object_id: ObjectID) -> Result<AvailableActions, Error> {
+        NotarizationClientReadOnly::available_actions(self, notarized_object_id).await
+    }
+
+    async fn lock_status(&self, notarized_object_id: ObjectID) -> Result<LockStatus, Error> {
+        NotarizationClientReadOnly::lock_status(self, notarized_object_id).await
+    }
+}
@@ -0,0 +1,295 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Read Retries
+//!
+//! Retry policy for the read calls of [`NotarizationClientReadOnly`](super::NotarizationClientReadOnly).
+//!
+//! ## Overview
+//!
+//! Busy nodes intermittently fail requests with transient errors (timeouts, `5xx` responses,
+//! connection resets). Read calls that fail with such an error are retried with exponential
+//! backoff and jitter; any other error (e.g. an invalid argument or a deserialization error)
+//! is returned immediately.
+//!
+//! ```rust,ignore
+//! # use std::time::Duration;
+//! # use notarization::NotarizationClientReadOnly;
+//! # use notarization::client::RetryPolicy;
+//! # async fn example(iota_client: iota_sdk::IotaClient) -> Result<(), Box<dyn std::error::Error>> {
+//! let client = NotarizationClientReadOnly::new(iota_client)
+//!     .await?
+//!     .with_retry_policy(RetryPolicy::new(5).with_base_delay(Duration::from_millis(500)));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! On `wasm32`, the delays are waited out with a JavaScript timer and are not jittered.
+
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::Error;
+
+/// The default number of attempts of a read call.
+const DEFAULT_MAX_ATTEMPTS: usize = 3;
+/// The default delay before the first retry.
+const DEFAULT_BASE_DELAY: Duration = Duration::from_millis(200);
+/// The default upper bound of the delay between two attempts.
+const DEFAULT_MAX_DELAY: Duration = Duration::from_secs(5);
+
+/// Error message fragments of transient RPC failures.
+const TRANSIENT_ERROR_PATTERNS: [&str; 12] = [
+    "timeout",
+    "timed out",
+    "connection reset",
+    "connection refused",
+    "connection closed",
+    "broken pipe",
+    "temporarily unavailable",
+    "too many requests",
+    "internal server error",
+    "bad gateway",
+    "service unavailable",
+    "gateway timeout",
+];
+
+/// The retry policy of read calls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_attempts: usize,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// 3 attempts, starting with a delay of 200ms, capped at 5s.
+    fn default() -> Self {
+        Self {
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            base_delay: DEFAULT_BASE_DELAY,
+            max_delay: DEFAULT_MAX_DELAY,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a policy that tries each call up to `max_attempts` times, with the default delays.
+    ///
+    /// Values lower than one are treated as one.
+    pub fn new(max_attempts: usize) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a policy that never retries.
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Sets the delay before the first retry; every further retry doubles it.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the upper bound of the delay between two attempts.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Returns the maximum number of attempts of a call.
+    pub fn max_attempts(&self) -> usize {
+        self.max_attempts
+    }
+
+    /// Returns the delay before the first retry.
+    pub fn base_delay(&self) -> Duration {
+        self.base_delay
+    }
+
+    /// Returns the upper bound of the delay between two attempts.
+    pub fn max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// Runs `operation` until it succeeds, fails with an error that `is_transient` rejects,
+    /// or the maximum number of attempts is reached.
+    pub(crate) async fn retry_if<T, E, F, Fut>(
+        &self,
+        mut operation: F,
+        is_transient: impl Fn(&E) -> bool,
+    ) -> Result<T, E>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match operation().await {
+                Err(error) if attempt < self.max_attempts && is_transient(&error) => {
+                    sleep(self.delay(attempt, jitter())).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Runs `operation` with retries on transient [`Error`]s.
+    pub(crate) async fn retry<T, F, Fut>(&self, operation: F) -> Result<T, Error>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, Error>>,
+    {
+        self.retry_if(operation, is_transient_error).await
+    }
+
+    /// Returns the delay after the failed `attempt` (starting at one).
+    ///
+    /// The delay grows exponentially and is capped at the maximum delay. `jitter` (in `[0, 1)`)
+    /// spreads the delay over its upper half, so that clients failing at the same time do not
+    /// retry in lockstep.
+    fn delay(&self, attempt: usize, jitter: f64) -> Duration {
+        let exponent = u32::try_from(attempt - 1).unwrap_or(u32::MAX).min(16);
+        let delay = self.base_delay.saturating_mul(1 << exponent).min(self.max_delay);
+
+        delay / 2 + delay.mul_f64(jitter.clamp(0.0, 1.0) / 2.0)
+    }
+}
+
+/// Indicates if `error` is a transient RPC failure that is worth retrying.
+///
+/// Errors that are caused by the request itself, or by the content of the response, are
+/// never transient.
+pub(crate) fn is_transient_error(error: &Error) -> bool {
+    match error {
+        Error::InvalidKey(_)
+        | Error::InvalidConfig(_)
+        | Error::InvalidArgument(_)
+        | Error::TimeLock(_)
+        | Error::DeserializationError(_)
+        | Error::VersionConflict { .. }
+        | Error::InsufficientGas { .. }
+        | Error::Locked { .. }
+        | Error::Timeout(_)
+        | Error::MoveAbort { .. } => false,
+        error => is_transient_message(&error.to_string()),
+    }
+}
+
+/// Indicates if the error `message` describes a transient RPC failure.
+pub(crate) fn is_transient_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    TRANSIENT_ERROR_PATTERNS.iter().any(|pattern| message.contains(pattern))
+}
+
+/// Returns a pseudo-random number in `[0, 1)`.
+fn jitter() -> f64 {
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|now| now.subsec_nanos())
+            .unwrap_or_default();
+        f64::from(nanos) / 1_000_000_000.0
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        0.0
+    }
+}
+
+/// Waits for `duration`, on `wasm32` with a JavaScript timer.
+async fn sleep(duration: Duration) {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::time::sleep(duration).await;
+    #[cfg(target_arch = "wasm32")]
+    gloo_timers::future::sleep(duration).await;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new(3).with_base_delay(Duration::ZERO)
+    }
+
+    #[test]
+    fn test_delay_grows_exponentially_and_is_capped() {
+        let policy = RetryPolicy::new(10)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(500));
+
+        assert_eq!(policy.delay(1, 0.0), Duration::from_millis(50));
+        assert_eq!(policy.delay(2, 0.0), Duration::from_millis(100));
+        assert_eq!(policy.delay(2, 0.5), Duration::from_millis(150));
+        assert_eq!(policy.delay(8, 0.0), Duration::from_millis(250));
+        assert!(policy.delay(8, 0.99) < Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_transient_errors() {
+        assert!(is_transient_error(&Error::RpcError("request timed out".to_string())));
+        assert!(is_transient_error(&Error::UnexpectedApiResponse(
+            "server returned 503 Service Unavailable".to_string()
+        )));
+        assert!(!is_transient_error(&Error::RpcError("method not found".to_string())));
+        assert!(!is_transient_error(&Error::InvalidArgument("timeout".to_string())));
+        assert!(!is_transient_error(&Error::Timeout(Duration::from_secs(1))));
+    }
+
+    #[tokio::test]
+    async fn test_retries_transient_errors() {
+        let attempts = AtomicUsize::new(0);
+
+        let result = policy()
+            .retry(|| async {
+                if attempts.fetch_add(1, Ordering::SeqCst) == 0 {
+                    Err(Error::RpcError("connection reset by peer".to_string()))
+                } else {
+                    Ok("object")
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), "object");
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_does_not_retry_permanent_errors() {
+        let attempts = AtomicUsize::new(0);
+
+        let result = policy()
+            .retry(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(Error::InvalidArgument("no data found".to_string()))
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let attempts = AtomicUsize::new(0);
+
+        let result = policy()
+            .retry(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(Error::RpcError("504 Gateway Timeout".to_string()))
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::RpcError(_))));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+    }
+}
@@ -13,6 +13,9 @@
 //! - **Locked**: Immutable records that cannot be modified after creation
 //! - **Dynamic**: Updatable records that can evolve over time
 //!
+//! The notarized state is either bytes or text; custom types are serialized into one of them,
+//! see [`NotarizationBuilder::with_state`].
+//!
 //! ## Examples
 //!
 //! ### Creating a Locked Notarization
@@ -42,13 +45,34 @@
 //! ```
 
 use std::marker::PhantomData;
+use std::time::Duration;
 
+use iota_interaction::{OptionalSend, OptionalSync};
 use product_common::transaction::transaction_builder::TransactionBuilder;
+use serde::Serialize;
 
-use super::transactions::CreateNotarization;
-use super::types::{NotarizationMethod, State, TimeLock};
+use super::move_utils::ClockObject;
+use super::transactions::{CreateNotarization, CreateNotarizationIdOnly, with_default_gas_budget};
+use super::types::metadata::{record_auto_destroy_intent, record_dedupe_tag};
+use super::types::timelock::unlock_after;
+use super::types::{
+    DEFAULT_MAX_METADATA_BYTES, DEFAULT_MAX_STATE_BYTES, NotarizationMethod, State, TimeLock, ensure_metadata_max_size,
+    suggested_gas_budget,
+};
+use crate::client::GasBudgetPolicy;
 use crate::error::Error;
 
+/// The lock combinations the contract accepts, quoted in the errors for rejected combinations.
+///
+/// The contract has no delete lock for dynamic notarizations and rejects `TimeLock::UntilDestroyed`
+/// as delete lock, so a record that can never be destroyed but stays transferable cannot be
+/// expressed.
+const LEGAL_LOCK_COMBINATIONS: &str = "dynamic notarizations take only a transfer lock (`TimeLock::None`, \
+                                       `TimeLock::UnlockAt` or `TimeLock::UntilDestroyed`) and cannot be destroyed \
+                                       while an `UnlockAt` transfer lock is active; locked notarizations take only a \
+                                       delete lock (`TimeLock::None` or `TimeLock::UnlockAt`), use \
+                                       `NotarizationBuilder::permanent_record` for a record that is never destroyed";
+
 /// Marker type for locked notarizations.
 #[derive(Clone)]
 pub struct Locked;
@@ -74,8 +98,27 @@ pub struct NotarizationBuilder<M> {
     pub delete_lock: Option<TimeLock>,
     /// Time restriction for transfers (Dynamic only)
     pub transfer_lock: Option<TimeLock>,
+    /// Duration after which deletion is allowed, resolved into `delete_lock` (Locked only)
+    pub delete_lock_after: Option<Duration>,
+    /// Duration after which transfers are allowed, resolved into `transfer_lock` (Dynamic only)
+    pub transfer_lock_after: Option<Duration>,
+    /// Intended expiry recorded in the description (seconds since the Unix epoch)
+    pub auto_destroy_after: Option<u32>,
+    /// Caller-supplied tag recorded in the description to deduplicate creations
+    pub dedupe_tag: Option<String>,
     /// The notarization method
     pub method: NotarizationMethod,
+    /// The maximum size of the state data in bytes
+    pub max_state_bytes: usize,
+    /// The maximum size of the immutable description and of the updatable metadata in bytes
+    pub max_metadata_bytes: usize,
+    /// The gas budget the finished transaction starts with
+    pub(crate) default_gas_budget: Option<u64>,
+    /// Whether the finished transaction starts with a budget scaled with the stored bytes, if it
+    /// has no default gas budget
+    pub(crate) scale_gas_budget: bool,
+    /// The clock object the transaction reads the time from
+    pub(crate) clock: ClockObject,
     _marker: PhantomData<M>,
 }
 
@@ -100,16 +143,62 @@ impl NotarizationBuilder<Locked> {
             updatable_metadata: None,
             delete_lock: None,
             transfer_lock: None,
+            delete_lock_after: None,
+            transfer_lock_after: None,
+            auto_destroy_after: None,
+            dedupe_tag: None,
             method: NotarizationMethod::Locked,
+            max_state_bytes: DEFAULT_MAX_STATE_BYTES,
+            max_metadata_bytes: DEFAULT_MAX_METADATA_BYTES,
+            default_gas_budget: None,
+            scale_gas_budget: false,
+            clock: ClockObject::default(),
             _marker: PhantomData,
         }
     }
 
+    /// Creates a builder for a locked notarization that can never be destroyed in practice.
+    ///
+    /// The contract rejects `TimeLock::UntilDestroyed` as delete lock, so the delete lock is set
+    /// to the latest representable unlock time, `TimeLock::UnlockAt(u32::MAX)` (February 2106).
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    ///
+    /// let builder = NotarizationBuilder::permanent_record().with_string_state("Deed #42".to_string(), None);
+    /// ```
+    pub fn permanent_record() -> Self {
+        Self::locked().with_delete_lock(TimeLock::UnlockAt(u32::MAX))
+    }
+
+    /// Creates a builder for a locked notarization that can be destroyed from `unlock_at`
+    /// (seconds since the Unix epoch) on.
+    ///
+    /// Shorthand for [`locked`](Self::locked) with a `TimeLock::UnlockAt(unlock_at)` delete lock.
+    /// Use [`with_delete_at_checked`](Self::with_delete_at_checked) on [`locked`](Self::locked)
+    /// to reject unlock times in the past.
+    pub fn destroyable_at(unlock_at: u32) -> Self {
+        Self::locked().with_delete_lock(TimeLock::UnlockAt(unlock_at))
+    }
+
+    /// Creates a builder for a locked notarization that can be destroyed anytime.
+    ///
+    /// Shorthand for [`locked`](Self::locked) with a `TimeLock::None` delete lock; the state
+    /// can still never be updated or transferred.
+    pub fn destroyable_anytime() -> Self {
+        Self::locked().with_delete_lock(TimeLock::None)
+    }
+
     /// Sets when the notarization can be destroyed.
     ///
     /// This is required for locked notarizations. Common patterns:
+    /// - `TimeLock::None`: Can be destroyed anytime
     /// - `TimeLock::UnlockAt(timestamp)`: Can be destroyed after specific time
-    /// - `TimeLock::UntilDestroyed`: Can never be destroyed (permanent record)
+    ///
+    /// Use [`with_delete_lock_after`](Self::with_delete_lock_after) for a lock relative to the
+    /// on-chain clock.
     ///
     /// ## Example
     ///
@@ -122,14 +211,61 @@ impl NotarizationBuilder<Locked> {
     /// ```
     pub fn with_delete_lock(mut self, lock: TimeLock) -> Self {
         self.delete_lock = Some(lock);
+        self.delete_lock_after = None;
+        self
+    }
+
+    /// Sets the notarization to be destroyable once `duration` has passed.
+    ///
+    /// The duration is counted from the on-chain clock time at which the transaction is built and
+    /// stored as a `TimeLock::UnlockAt` delete lock, so the result does not depend on the local
+    /// system time. Only whole seconds are taken into account; a duration shorter than one second
+    /// is rejected when the transaction is built. Replaces a lock set with
+    /// [`with_delete_lock`](Self::with_delete_lock).
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use std::time::Duration;
+    ///
+    /// use notarization::core::builder::NotarizationBuilder;
+    ///
+    /// // Can be destroyed one hour after creation
+    /// let builder = NotarizationBuilder::locked().with_delete_lock_after(Duration::from_secs(3600));
+    /// ```
+    pub fn with_delete_lock_after(mut self, duration: Duration) -> Self {
+        self.delete_lock = None;
+        self.delete_lock_after = Some(duration);
         self
     }
 
+    /// Sets the notarization to be destroyable from `unlock_at` (seconds since the Unix epoch) on.
+    ///
+    /// Unlike passing `TimeLock::UnlockAt(unlock_at)` to [`with_delete_lock`](Self::with_delete_lock),
+    /// the unlock time is checked against the system time with [`TimeLock::new_with_ts`], so a
+    /// time in the past is rejected before any transaction is built instead of aborting on-chain.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if `unlock_at` is not in the future.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    ///
+    /// let builder = NotarizationBuilder::locked().with_delete_at_checked(2_000_000_000)?;
+    /// ```
+    pub fn with_delete_at_checked(self, unlock_at: u32) -> Result<Self, Error> {
+        Ok(self.with_delete_lock(TimeLock::new_with_ts(unlock_at)?))
+    }
+
     /// Finalizes the builder and creates a transaction builder.
     ///
     /// ## Errors
     ///
-    /// Returns an error if `delete_lock` is not set, as it's required for locked notarizations.
+    /// Returns an error if the builder does not [validate](NotarizationBuilder::validate), e.g.
+    /// if the state is missing or exceeds the [maximum state size](Self::with_max_state_bytes).
     ///
     /// ## Example
     ///
@@ -143,7 +279,13 @@ impl NotarizationBuilder<Locked> {
     /// # Ok::<(), notarization::Error>(())
     /// ```
     pub fn finish(self) -> Result<TransactionBuilder<CreateNotarization<Locked>>, Error> {
-        Ok(TransactionBuilder::new(CreateNotarization::new(self)))
+        self.validate()?;
+
+        let gas_budget = self.gas_budget();
+        Ok(with_default_gas_budget(
+            TransactionBuilder::new(CreateNotarization::new(self)),
+            gas_budget,
+        ))
     }
 }
 
@@ -168,11 +310,38 @@ impl NotarizationBuilder<Dynamic> {
             updatable_metadata: None,
             delete_lock: None,
             transfer_lock: None,
+            delete_lock_after: None,
+            transfer_lock_after: None,
+            auto_destroy_after: None,
+            dedupe_tag: None,
             method: NotarizationMethod::Dynamic,
+            max_state_bytes: DEFAULT_MAX_STATE_BYTES,
+            max_metadata_bytes: DEFAULT_MAX_METADATA_BYTES,
+            default_gas_budget: None,
+            scale_gas_budget: false,
+            clock: ClockObject::default(),
             _marker: PhantomData,
         }
     }
 
+    /// Creates a builder for a dynamic notarization that can never be transferred.
+    ///
+    /// Shorthand for [`dynamic`](Self::dynamic) with a `TimeLock::UntilDestroyed` transfer lock.
+    /// The notarization can still be updated and destroyed.
+    pub fn non_transferable() -> Self {
+        Self::dynamic().with_transfer_lock(TimeLock::UntilDestroyed)
+    }
+
+    /// Creates a builder for a dynamic notarization that can be transferred from `unlock_at`
+    /// (seconds since the Unix epoch) on.
+    ///
+    /// Shorthand for [`dynamic`](Self::dynamic) with a `TimeLock::UnlockAt(unlock_at)` transfer
+    /// lock. Like any time based transfer lock, it also prevents destroying the notarization
+    /// until `unlock_at`.
+    pub fn transferable_at(unlock_at: u32) -> Self {
+        Self::dynamic().with_transfer_lock(TimeLock::UnlockAt(unlock_at))
+    }
+
     /// Sets restrictions on when the notarization can be transferred.
     ///
     /// By default, dynamic notarizations can be transferred freely. Use this
@@ -184,6 +353,13 @@ impl NotarizationBuilder<Dynamic> {
     /// - `TimeLock::UnlockAt(timestamp)`: Can be transferred after specific time
     /// - `TimeLock::UntilDestroyed`: Can never be transferred
     ///
+    /// Use [`with_transfer_lock_after`](Self::with_transfer_lock_after) for a lock relative to
+    /// the on-chain clock.
+    ///
+    /// Dynamic notarizations have no delete lock of their own. A time based transfer lock
+    /// (`UnlockAt`) also prevents destroying the notarization until it expires, whereas
+    /// `TimeLock::UntilDestroyed` does not restrict destruction.
+    ///
     /// ## Example
     ///
     /// ```rust,ignore
@@ -195,9 +371,37 @@ impl NotarizationBuilder<Dynamic> {
     /// ```
     pub fn with_transfer_lock(mut self, lock: TimeLock) -> Self {
         self.transfer_lock = Some(lock);
+        self.transfer_lock_after = None;
+        self
+    }
+
+    /// Locks transfers of the notarization until `duration` has passed.
+    ///
+    /// The duration is counted from the on-chain clock time at which the transaction is built and
+    /// stored as a `TimeLock::UnlockAt` transfer lock, which also prevents destroying the
+    /// notarization until it expires. Only whole seconds are taken into account; a duration
+    /// shorter than one second is rejected when the transaction is built. Replaces a lock set
+    /// with [`with_transfer_lock`](Self::with_transfer_lock).
+    pub fn with_transfer_lock_after(mut self, duration: Duration) -> Self {
+        self.transfer_lock = None;
+        self.transfer_lock_after = Some(duration);
         self
     }
 
+    /// Locks transfers of the notarization until `unlock_at` (seconds since the Unix epoch).
+    ///
+    /// Unlike passing `TimeLock::UnlockAt(unlock_at)` to
+    /// [`with_transfer_lock`](Self::with_transfer_lock), the unlock time is checked against the
+    /// system time with [`TimeLock::new_with_ts`], so a time in the past is rejected before any
+    /// transaction is built instead of aborting on-chain.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if `unlock_at` is not in the future.
+    pub fn with_transfer_lock_at_checked(self, unlock_at: u32) -> Result<Self, Error> {
+        Ok(self.with_transfer_lock(TimeLock::new_with_ts(unlock_at)?))
+    }
+
     /// Finalizes the builder and creates a transaction builder.
     ///
     /// Unlike locked notarizations, dynamic notarizations have no required fields
@@ -214,7 +418,36 @@ impl NotarizationBuilder<Dynamic> {
     ///     .finish();
     /// ```
     pub fn finish(self) -> TransactionBuilder<CreateNotarization<Dynamic>> {
-        TransactionBuilder::new(CreateNotarization::new(self))
+        let gas_budget = self.gas_budget();
+        with_default_gas_budget(TransactionBuilder::new(CreateNotarization::new(self)), gas_budget)
+    }
+}
+
+impl<M: Clone + OptionalSend + OptionalSync> NotarizationBuilder<M> {
+    /// Finalizes the builder into a transaction that only returns the [`ObjectID`] of the
+    /// created notarization.
+    ///
+    /// The created object is not read back after the transaction was executed, which saves
+    /// one RPC call per notarization. Use [`NotarizationClientReadOnly::get_notarization_by_id`]
+    /// to fetch it later on.
+    ///
+    /// [`ObjectID`]: iota_interaction::types::base_types::ObjectID
+    /// [`NotarizationClientReadOnly::get_notarization_by_id`]: crate::NotarizationClientReadOnly::get_notarization_by_id
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let notarization_id = client
+    ///     .create_dynamic_notarization()
+    ///     .with_string_state("Sensor reading".to_string(), None)
+    ///     .finish_id_only()
+    ///     .build_and_execute(&client)
+    ///     .await?
+    ///     .output;
+    /// ```
+    pub fn finish_id_only(self) -> TransactionBuilder<CreateNotarizationIdOnly<M>> {
+        let gas_budget = self.gas_budget();
+        with_default_gas_budget(TransactionBuilder::new(CreateNotarizationIdOnly::new(self)), gas_budget)
     }
 }
 
@@ -223,7 +456,19 @@ impl<M> NotarizationBuilder<M> {
     /// Sets the state (data) to be notarized.
     ///
     /// The state is the actual content being notarized. It can contain any data
-    /// along with optional metadata.
+    /// along with optional metadata. Strings and byte vectors convert into states without
+    /// metadata; strings become text, bytes stay bytes.
+    ///
+    /// ## Supported data
+    ///
+    /// The client creates only `State<vector<u8>>` and `State<String>` notarizations. The contract
+    /// accepts any `store + drop + copy` type as state (`notarization::new_state_from_generic`),
+    /// but the client decodes, compares and updates states as [`Data`](super::types::Data), so
+    /// notarizations of other types could not be read back with
+    /// `NotarizationClientReadOnly::get_notarization_by_id` or updated. Serialize custom types
+    /// instead, e.g. with [`with_json_state`](Self::with_json_state) and read them back with
+    /// `NotarizationClientReadOnly::state_as_json`, or with `bcs` into a
+    /// [byte state](Self::with_bytes_state).
     ///
     /// ## Example
     ///
@@ -233,9 +478,11 @@ impl<M> NotarizationBuilder<M> {
     ///
     /// let builder = NotarizationBuilder::locked()
     ///     .with_state(State::from_string("Document content", Some("v1.0")));
+    ///
+    /// let builder = NotarizationBuilder::dynamic().with_state("Status: Active");
     /// ```
-    pub fn with_state(mut self, state: State) -> Self {
-        self.state = Some(state);
+    pub fn with_state(mut self, state: impl Into<State>) -> Self {
+        self.state = Some(state.into());
         self
     }
 
@@ -284,6 +531,59 @@ impl<M> NotarizationBuilder<M> {
         self.with_state(State::from_string(data, metadata))
     }
 
+    /// Sets the state to the canonical JSON encoding of `value`.
+    ///
+    /// The JSON is encoded with sorted object keys (see [`State::from_json`]), so equal values
+    /// always produce the same state and content hash.
+    ///
+    /// Unlike the other `with_*` methods, this method returns a `Result`, as serializing `value`
+    /// can fail.
+    ///
+    /// ## Parameters
+    ///
+    /// - `value`: The value to notarize
+    /// - `metadata`: Optional metadata about the value
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    /// use serde_json::json;
+    ///
+    /// let builder = NotarizationBuilder::dynamic()
+    ///     .with_json_state(&json!({ "status": "active", "version": "2.0" }), None)?
+    ///     .with_immutable_description("Service configuration".to_string());
+    /// ```
+    pub fn with_json_state<T: Serialize>(self, value: &T, metadata: Option<String>) -> Result<Self, Error> {
+        Ok(self.with_state(State::from_json(value, metadata)?))
+    }
+
+    /// Sets the state to the canonical form of the JSON document `json`.
+    ///
+    /// The document is parsed and encoded like in [`with_json_state`](Self::with_json_state),
+    /// with sorted object keys and without insignificant whitespace, so documents that differ
+    /// only in formatting or key order produce byte-identical states and content hashes. Use
+    /// [`with_string_state`](Self::with_string_state) to store a document verbatim.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if `json` is not a valid JSON document.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    ///
+    /// let builder = NotarizationBuilder::dynamic()
+    ///     .with_normalized_json_state("{ \"version\": \"2.0\",\n  \"status\": \"active\" }\n", None)?;
+    /// ```
+    pub fn with_normalized_json_state(self, json: &str, metadata: Option<String>) -> Result<Self, Error> {
+        let value = serde_json::from_str::<serde_json::Value>(json)
+            .map_err(|e| Error::InvalidArgument(format!("state is not a valid JSON document: {e}")))?;
+
+        self.with_json_state(&value, metadata)
+    }
+
     /// Sets a permanent description for the notarization.
     ///
     /// This description is immutable and cannot be changed after creation.
@@ -319,4 +619,262 @@ impl<M> NotarizationBuilder<M> {
         self.updatable_metadata = Some(metadata);
         self
     }
+
+    /// Sets the maximum size of the state data in bytes (default: [`DEFAULT_MAX_STATE_BYTES`]).
+    ///
+    /// The state data is measured as passed to the contract, i.e. by its BCS encoding including the
+    /// ULEB128 length prefix. A state exceeding the maximum is rejected with
+    /// [`Error::InvalidArgument`] before the transaction is submitted, instead of aborting on-chain.
+    pub fn with_max_state_bytes(mut self, max_state_bytes: usize) -> Self {
+        self.max_state_bytes = max_state_bytes;
+        self
+    }
+
+    /// Sets the maximum size of the immutable description and of the updatable metadata in bytes
+    /// (default: [`DEFAULT_MAX_METADATA_BYTES`]).
+    ///
+    /// Both are measured as passed to the contract, i.e. by the BCS encoding of an optional string
+    /// including the option tag and the length prefix; the description includes the
+    /// [dedupe tag](Self::with_dedupe_tag) and the [auto-destroy intent](Self::with_auto_destroy_after),
+    /// if any. Metadata exceeding the maximum is rejected with [`Error::InvalidArgument`] before the
+    /// transaction is submitted.
+    pub fn with_max_metadata_bytes(mut self, max_metadata_bytes: usize) -> Self {
+        self.max_metadata_bytes = max_metadata_bytes;
+        self
+    }
+
+    /// Records an intended expiry for the notarization.
+    ///
+    /// The intent is appended as the last line of the immutable description and can be read
+    /// back with [`ImmutableMetadata::auto_destroy_after`](super::types::ImmutableMetadata::auto_destroy_after).
+    /// The chain does not destroy notarizations on its own; expired notarizations are reaped with
+    /// `NotarizationClient::destroy_expired`, as far as their locks allow it. For locked
+    /// notarizations, combine this with a matching delete lock.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    ///
+    /// // Intended to be removed after January 1, 2025
+    /// let builder = NotarizationBuilder::dynamic().with_auto_destroy_after(1735689600);
+    /// ```
+    pub fn with_auto_destroy_after(mut self, expires_at: u32) -> Self {
+        self.auto_destroy_after = Some(expires_at);
+        self
+    }
+
+    /// Records a deterministic tag identifying the notarization.
+    ///
+    /// The tag is appended as a line of the immutable description and can be read back with
+    /// [`ImmutableMetadata::dedupe_tag`](super::types::ImmutableMetadata::dedupe_tag). It must be a
+    /// non-empty single line; other tags are rejected with [`Error::InvalidArgument`] when the
+    /// transaction is built. Usually set through `NotarizationClient::create_if_absent`.
+    pub fn with_dedupe_tag(mut self, tag: String) -> Self {
+        self.dedupe_tag = Some(tag);
+        self
+    }
+
+    /// Checks the builder against the rules the contract enforces, without building a transaction.
+    ///
+    /// Reports a missing state, a state or metadata exceeding its maximum size, an invalid
+    /// [dedupe tag](Self::with_dedupe_tag), a lock combination the contract rejects, and
+    /// `TimeLock::UnlockAt` locks that are not in the future according to the system time. All
+    /// problems are reported at once, so that a form can show them together. Building the
+    /// transaction runs the same checks, with unlock times checked against the on-chain clock.
+    ///
+    /// On `wasm32` targets the system time is not read and unlock times are only checked when the
+    /// transaction is built.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] listing every problem, separated by `; `.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::builder::NotarizationBuilder;
+    ///
+    /// let builder = NotarizationBuilder::dynamic().with_string_state(form.content, None);
+    /// if let Err(e) = builder.validate() {
+    ///     form.show_error(e.to_string());
+    /// }
+    /// ```
+    pub fn validate(&self) -> Result<(), Error> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .ok()
+            .map(|now| now.as_secs());
+        #[cfg(target_arch = "wasm32")]
+        let now = None;
+
+        self.validate_at(now)
+    }
+
+    /// Runs the checks of [`validate`](Self::validate), checking unlock times against `now`
+    /// (seconds since the Unix epoch) if given.
+    pub(crate) fn validate_at(&self, now: Option<u64>) -> Result<(), Error> {
+        let mut problems = Vec::new();
+
+        match &self.state {
+            Some(state) => problems.extend(state.ensure_max_size(self.max_state_bytes).err().map(problem)),
+            None => problems.push("State is required".to_string()),
+        }
+        match self.recorded_description() {
+            Ok(description) => problems.extend(
+                ensure_metadata_max_size("immutable description", description.as_deref(), self.max_metadata_bytes)
+                    .err()
+                    .map(problem),
+            ),
+            Err(e) => problems.push(problem(e)),
+        }
+        problems.extend(
+            ensure_metadata_max_size(
+                "updatable metadata",
+                self.updatable_metadata.as_deref(),
+                self.max_metadata_bytes,
+            )
+            .err()
+            .map(problem),
+        );
+
+        let mut lock_problems = Vec::new();
+        match self.method {
+            NotarizationMethod::Dynamic => {
+                if let Some(delete_lock) = &self.delete_lock {
+                    lock_problems.push(format!(
+                        "Delete lock {delete_lock:?} cannot be set for dynamic notarizations"
+                    ));
+                }
+                if self.delete_lock_after.is_some() {
+                    lock_problems.push("Delete lock cannot be set for dynamic notarizations".to_string());
+                }
+            }
+            NotarizationMethod::Locked => {
+                if self.transfer_lock.is_some() || self.transfer_lock_after.is_some() {
+                    lock_problems.push("Transfer lock cannot be set for locked notarizations".to_string());
+                }
+                if self.delete_lock == Some(TimeLock::UntilDestroyed) {
+                    lock_problems.push("Delete lock cannot be `TimeLock::UntilDestroyed`".to_string());
+                }
+            }
+        }
+        if !lock_problems.is_empty() {
+            problems.push(format!("{}: {LEGAL_LOCK_COMBINATIONS}", lock_problems.join(", ")));
+        }
+
+        for (kind, duration) in [
+            ("delete", self.delete_lock_after),
+            ("transfer", self.transfer_lock_after),
+        ] {
+            if duration.is_some_and(|duration| duration.as_secs() == 0) {
+                problems.push(format!("the {kind} lock duration must be at least one second"));
+            }
+        }
+
+        if let Some(now) = now {
+            for (kind, lock) in [("delete", &self.delete_lock), ("transfer", &self.transfer_lock)] {
+                if let Some(TimeLock::UnlockAt(unlock_at)) = lock {
+                    if u64::from(*unlock_at) <= now {
+                        problems.push(format!(
+                            "the {kind} lock unlocks at {unlock_at}, which is not in the future (now: {now})"
+                        ));
+                    }
+                }
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::InvalidArgument(problems.join("; ")))
+        }
+    }
+
+    /// Returns the immutable description as stored on-chain, with the dedupe tag and the
+    /// auto-destroy intent appended.
+    pub(crate) fn recorded_description(&self) -> Result<Option<String>, Error> {
+        // The dedupe tag goes first, so that the auto-destroy intent stays the last line.
+        let description = match &self.dedupe_tag {
+            Some(tag) => Some(record_dedupe_tag(self.immutable_description.clone(), tag)?),
+            None => self.immutable_description.clone(),
+        };
+
+        Ok(match self.auto_destroy_after {
+            Some(expires_at) => Some(record_auto_destroy_intent(description, expires_at)),
+            None => description,
+        })
+    }
+
+    /// Returns the number of bytes the notarization stores: its state, its description and its
+    /// updatable metadata.
+    pub(crate) fn stored_bytes(&self) -> usize {
+        self.state.as_ref().map_or(0, State::stored_bytes)
+            + self.immutable_description.as_ref().map_or(0, String::len)
+            + self.updatable_metadata.as_ref().map_or(0, String::len)
+    }
+
+    /// Returns the gas budget the finished transaction starts with, following the client's
+    /// [`GasBudgetPolicy`](crate::client::GasBudgetPolicy): its creation budget if set, otherwise
+    /// a budget scaled with the [stored bytes](Self::stored_bytes) if opted in.
+    ///
+    /// Without either, the budget is estimated when the transaction is built. A budget set with
+    /// `with_gas_budget` on the returned transaction builder takes precedence.
+    pub(crate) fn gas_budget(&self) -> Option<u64> {
+        self.default_gas_budget
+            .or_else(|| self.scale_gas_budget.then(|| suggested_gas_budget(self.stored_bytes())))
+    }
+
+    /// Sets the gas budget the finished transaction starts with, following the creation budget
+    /// of `policy`.
+    pub(crate) fn with_gas_budget_policy(mut self, policy: &GasBudgetPolicy) -> Self {
+        self.default_gas_budget = policy.create();
+        self.scale_gas_budget = policy.scales_with_size();
+        self
+    }
+
+    /// Sets the clock object the transaction reads the time from (default: the system clock).
+    pub(crate) fn with_clock(mut self, clock: ClockObject) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Indicates if any of the locks must be checked or resolved against the on-chain clock.
+    pub(crate) fn needs_clock(&self) -> bool {
+        self.delete_lock_after.is_some()
+            || self.transfer_lock_after.is_some()
+            || [&self.delete_lock, &self.transfer_lock]
+                .into_iter()
+                .any(|lock| matches!(lock, Some(TimeLock::UnlockAt(_))))
+    }
+
+    /// Returns the delete and the transfer lock with relative locks resolved into
+    /// `TimeLock::UnlockAt` locks, counting from `now` (seconds since the Unix epoch).
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::TimeLock`] if a relative lock is set but `now` is not given.
+    pub(crate) fn resolved_locks(&self, now: Option<u64>) -> Result<(Option<TimeLock>, Option<TimeLock>), Error> {
+        let resolve = |lock: &Option<TimeLock>, after: Option<Duration>| match (after, now) {
+            (Some(duration), Some(now)) => unlock_after(now, duration).map(Some),
+            (Some(_), None) => Err(Error::TimeLock(
+                "relative time locks must be resolved against the on-chain clock".to_string(),
+            )),
+            (None, _) => Ok(lock.clone()),
+        };
+
+        Ok((
+            resolve(&self.delete_lock, self.delete_lock_after)?,
+            resolve(&self.transfer_lock, self.transfer_lock_after)?,
+        ))
+    }
+}
+
+/// Turns an error of a single check into a problem reported by [`NotarizationBuilder::validate`].
+fn problem(error: Error) -> String {
+    match error {
+        Error::InvalidArgument(message) => message,
+        error => error.to_string(),
+    }
 }
@@ -10,4 +10,5 @@ pub mod builder;
 pub(crate) mod move_utils;
 pub(crate) mod operations;
 pub mod transactions;
+pub(crate) mod type_cache;
 pub mod types;
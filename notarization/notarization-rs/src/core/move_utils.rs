@@ -3,27 +3,96 @@
 
 use std::str::FromStr;
 
-use iota_interaction::rpc_types::IotaObjectDataOptions;
-use iota_interaction::types::base_types::{ObjectID, ObjectRef};
+use iota_interaction::rpc_types::{IotaObjectData, IotaObjectDataOptions};
+use iota_interaction::types::base_types::{ObjectID, ObjectRef, SequenceNumber};
+use iota_interaction::types::id::UID;
+use iota_interaction::types::object::Owner;
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder as Ptb;
 use iota_interaction::types::transaction::{Argument, ObjectArg};
 use iota_interaction::types::{IOTA_CLOCK_OBJECT_ID, IOTA_CLOCK_OBJECT_SHARED_VERSION, TypeTag};
 use iota_interaction::{IotaClientTrait, OptionalSync};
 use product_common::core_client::CoreClientReadOnly;
-use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
+use crate::client::MULTI_GET_OBJECTS_LIMIT;
+use crate::core::transactions::get_object_ref_by_id_with_bcs;
 use crate::error::Error;
 
-/// Adds a reference to the on-chain clock to `ptb`'s arguments.
-pub(crate) fn get_clock_ref(ptb: &mut Ptb) -> Argument {
+/// The BCS layout of the on-chain `0x2::clock::Clock` object.
+#[derive(Deserialize)]
+struct Clock {
+    #[allow(dead_code)]
+    id: UID,
+    timestamp_ms: u64,
+}
+
+/// A shared clock object, identified by its ID and the version it was shared at.
+///
+/// Defaults to the system clock (`0x6`). Forked or custom networks may have their clock at a
+/// different shared version than the system clock of the public networks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ClockObject {
+    pub(crate) id: ObjectID,
+    pub(crate) initial_shared_version: SequenceNumber,
+}
+
+impl Default for ClockObject {
+    fn default() -> Self {
+        Self {
+            id: IOTA_CLOCK_OBJECT_ID,
+            initial_shared_version: IOTA_CLOCK_OBJECT_SHARED_VERSION,
+        }
+    }
+}
+
+/// Adds a reference to the on-chain `clock` to `ptb`'s arguments.
+pub(crate) fn get_clock_ref(ptb: &mut Ptb, clock: ClockObject) -> Argument {
     ptb.obj(ObjectArg::SharedObject {
-        id: IOTA_CLOCK_OBJECT_ID,
-        initial_shared_version: IOTA_CLOCK_OBJECT_SHARED_VERSION,
+        id: clock.id,
+        initial_shared_version: clock.initial_shared_version,
         mutable: false,
     })
     .expect("network has a singleton clock instantiated")
 }
 
+/// Resolves the initial shared version of the clock object `clock_id`.
+pub(crate) async fn get_clock_shared_version(
+    client: &impl CoreClientReadOnly,
+    clock_id: ObjectID,
+) -> Result<SequenceNumber, Error> {
+    let owner = client
+        .client_adapter()
+        .read_api()
+        .get_object_with_options(clock_id, IotaObjectDataOptions::new().with_owner())
+        .await
+        .map_err(|err| Error::ObjectLookup(format!("failed to get clock object {clock_id}: {err}")))?
+        .data
+        .and_then(|data| data.owner)
+        .ok_or_else(|| Error::ObjectLookup(format!("clock object {clock_id} not found")))?;
+
+    match owner {
+        Owner::Shared { initial_shared_version } => Ok(initial_shared_version),
+        _ => Err(Error::InvalidArgument(format!(
+            "object {clock_id} is not a shared clock object"
+        ))),
+    }
+}
+
+/// Reads the current timestamp of the on-chain `clock` in milliseconds.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, err))]
+pub(crate) async fn get_clock_timestamp_ms(client: &impl CoreClientReadOnly, clock: ClockObject) -> Result<u64, Error> {
+    let clock: Clock = get_object_ref_by_id_with_bcs(client, &clock.id).await?;
+
+    Ok(clock.timestamp_ms)
+}
+
+/// Returns the size of `value` as a pure transaction argument, i.e. of its BCS encoding, which
+/// includes the ULEB128 length prefix of strings and vectors and the tag of options.
+pub(crate) fn pure_size<T: Serialize + ?Sized>(value: &T) -> usize {
+    bcs::serialized_size(value).expect("strings and byte vectors are always serializable")
+}
+
 pub(crate) fn ptb_pure<T>(ptb: &mut Ptb, name: &str, value: T) -> Result<Argument, Error>
 where
     T: Serialize + core::fmt::Debug,
@@ -36,6 +105,7 @@ where
 }
 
 /// Get the type tag of an object
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(client), err))]
 pub(crate) async fn get_type_tag<C>(client: &C, object_id: &ObjectID) -> Result<TypeTag, Error>
 where
     C: CoreClientReadOnly + OptionalSync,
@@ -51,6 +121,11 @@ where
         .data
         .ok_or_else(|| Error::FailedToParseTag(format!("Object {object_id} not found")))?;
 
+    type_tag_of(&object_data)
+}
+
+/// Parses the type tag of the state data out of the type of `object_data`.
+fn type_tag_of(object_data: &IotaObjectData) -> Result<TypeTag, Error> {
     let full_type_str = object_data
         .object_type()
         .map_err(|e| Error::FailedToParseTag(format!("Failed to get object type: {e}")))?
@@ -58,14 +133,19 @@ where
 
     let type_param_str = parse_type(&full_type_str)?;
 
-    let tag = TypeTag::from_str(&type_param_str)
-        .map_err(|e| Error::FailedToParseTag(format!("Failed to parse tag '{type_param_str}': {e}")))?;
-
-    Ok(tag)
+    TypeTag::from_str(&type_param_str)
+        .map_err(|e| Error::FailedToParseTag(format!("Failed to parse tag '{type_param_str}': {e}")))
 }
 
+/// The part of a `Notarization<D>` type string that precedes the type argument `D`.
+const NOTARIZATION_TYPE_MARKER: &str = "::notarization::Notarization<";
+
 /// Parses the type string to get the generic argument
 ///
+/// If the type contains a `Notarization<D>` (e.g. because the notarization is wrapped in a
+/// dynamic field), `D` is returned. Otherwise, the generic arguments of the outermost type are
+/// returned. Surrounding whitespace is trimmed.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -74,15 +154,39 @@ where
 /// assert_eq!(type_param_str, "vector<u8>");
 /// ```
 pub(crate) fn parse_type(full_type: &str) -> Result<String, Error> {
-    if let (Some(start), Some(end)) = (full_type.find('<'), full_type.rfind('>')) {
-        Ok(full_type[start + 1..end].to_string())
-    } else {
-        Err(Error::FailedToParseTag(format!(
+    let start = match full_type.find(NOTARIZATION_TYPE_MARKER) {
+        Some(position) => Some(position + NOTARIZATION_TYPE_MARKER.len() - 1),
+        None => full_type.find('<'),
+    };
+
+    match start.and_then(|start| closing_angle_bracket(full_type, start).map(|end| (start, end))) {
+        Some((start, end)) => Ok(full_type[start + 1..end].trim().to_string()),
+        None => Err(Error::FailedToParseTag(format!(
             "Could not parse type parameter from {full_type}"
-        )))
+        ))),
+    }
+}
+
+/// Returns the position of the `>` closing the `<` at `open`.
+fn closing_angle_bracket(full_type: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (position, c) in full_type.char_indices().skip_while(|(position, _)| *position < open) {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(position);
+                }
+            }
+            _ => {}
+        }
     }
+
+    None
 }
 
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(iota_client), err))]
 pub(crate) async fn get_object_ref_by_id(
     iota_client: &impl CoreClientReadOnly,
     obj: &ObjectID,
@@ -101,6 +205,70 @@ pub(crate) async fn get_object_ref_by_id(
     Ok(data.object_ref())
 }
 
+/// Gets the current [`ObjectRef`]s and the type tags of the state data of several notarizations,
+/// in the order of `object_ids`.
+///
+/// The objects are fetched with one request per [`MULTI_GET_OBJECTS_LIMIT`] objects.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip_all, fields(count = object_ids.len()), err))]
+pub(crate) async fn get_object_refs_and_type_tags(
+    iota_client: &impl CoreClientReadOnly,
+    object_ids: &[ObjectID],
+) -> Result<Vec<(ObjectRef, TypeTag)>, Error> {
+    let mut objects = Vec::with_capacity(object_ids.len());
+    for chunk in object_ids.chunks(MULTI_GET_OBJECTS_LIMIT) {
+        let responses = iota_client
+            .client_adapter()
+            .read_api()
+            .multi_get_object_with_options(chunk.to_vec(), IotaObjectDataOptions::new().with_type())
+            .await
+            .map_err(|err| Error::RpcError(format!("failed to get objects: {err}")))?;
+
+        if responses.len() != chunk.len() {
+            return Err(Error::UnexpectedApiResponse(format!(
+                "requested {} objects, got {}",
+                chunk.len(),
+                responses.len()
+            )));
+        }
+
+        for (object_id, response) in chunk.iter().zip(responses) {
+            let data = response
+                .data
+                .ok_or_else(|| Error::ObjectLookup(format!("object {object_id} not found")))?;
+            objects.push((data.object_ref(), type_tag_of(&data)?));
+        }
+    }
+
+    Ok(objects)
+}
+
+/// Reads an object and its current [`ObjectRef`] in a single request.
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(iota_client), err))]
+pub(crate) async fn get_object_with_ref<T: DeserializeOwned>(
+    iota_client: &impl CoreClientReadOnly,
+    obj: &ObjectID,
+) -> Result<(ObjectRef, T), Error> {
+    let data = iota_client
+        .client_adapter()
+        .read_api()
+        .get_object_with_options(*obj, IotaObjectDataOptions::bcs_lossless())
+        .await
+        .map_err(|err| Error::ObjectLookup(err.to_string()))?
+        .data
+        .ok_or_else(|| Error::ObjectLookup("missing data in response".to_string()))?;
+    let object_ref = data.object_ref();
+
+    let object = data
+        .bcs
+        .ok_or_else(|| Error::ObjectLookup("missing object content in data".to_string()))?
+        .try_into_move()
+        .ok_or_else(|| Error::ObjectLookup("failed to convert data to move object".to_string()))?
+        .deserialize()
+        .map_err(|err| Error::ObjectLookup(err.to_string()))?;
+
+    Ok((object_ref, object))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,4 +307,31 @@ mod tests {
         let type_param_str = parse_type(full_type).unwrap();
         assert_eq!(type_param_str, "");
     }
+
+    #[test]
+    fn test_parse_type_trims_whitespace() {
+        let full_type = "0x123::notarization::Notarization< 0x1::string::String >";
+        let type_param_str = parse_type(full_type).unwrap();
+        assert_eq!(type_param_str, "0x1::string::String");
+    }
+
+    #[test]
+    fn test_parse_type_field_embedded_notarization() {
+        let full_type = "0x2::dynamic_field::Field<address, 0x123::notarization::Notarization<vector<u8>>>";
+        let type_param_str = parse_type(full_type).unwrap();
+        assert_eq!(type_param_str, "vector<u8>");
+    }
+
+    #[test]
+    fn test_parse_type_wrapped_notarization() {
+        let full_type = "0x456::registry::Entry<0x123::notarization::Notarization<0x1::string::String>, u64>";
+        let type_param_str = parse_type(full_type).unwrap();
+        assert_eq!(type_param_str, "0x1::string::String");
+    }
+
+    #[test]
+    fn test_parse_type_unbalanced_generics() {
+        let full_type = "0x123::notarization::Notarization<vector<u8>";
+        assert!(parse_type(full_type).is_err());
+    }
 }
@@ -12,14 +12,14 @@
 use std::str::FromStr;
 
 use async_trait::async_trait;
-use iota_interaction::types::Identifier;
-use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::base_types::{IotaAddress, ObjectID, ObjectRef};
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use iota_interaction::types::transaction::{Argument, ObjectArg, ProgrammableTransaction};
+use iota_interaction::types::{Identifier, TypeTag};
 use iota_interaction::{OptionalSync, ident_str};
 use product_common::core_client::CoreClientReadOnly;
 
-use super::move_utils;
+use super::move_utils::{self, ClockObject};
 use super::types::{State, TimeLock};
 use crate::error::Error;
 
@@ -35,6 +35,7 @@ impl NotarizationImpl {
     /// * `iota_client` - The IOTA client adapter
     /// * `package_id` - The package ID for the transaction
     /// * `object_id` - Optional object ID for the notarization
+    /// * `tag` - The type tag of the notarization's state data
     /// * `method` - The method name to call
     /// * `additional_args` - Closure providing additional arguments for the transaction
     ///
@@ -43,13 +44,13 @@ impl NotarizationImpl {
     ///
     /// # Errors
     /// Returns `Error` if:
-    /// * Tag retrieval fails
     /// * Object reference retrieval fails
     /// * Transaction building fails
     /// * Method name is invalid
     async fn build_transaction<C, F>(
         client: &C,
         object_id: ObjectID,
+        tag: &TypeTag,
         method: impl AsRef<str>,
         additional_args: F,
     ) -> Result<ProgrammableTransaction, Error>
@@ -57,21 +58,53 @@ impl NotarizationImpl {
         F: FnOnce(&mut ProgrammableTransactionBuilder) -> Result<Vec<Argument>, Error>,
         C: CoreClientReadOnly + OptionalSync,
     {
-        let mut ptb = ProgrammableTransactionBuilder::new();
+        let notarization = move_utils::get_object_ref_by_id(client, &object_id).await?;
 
-        let tag = vec![move_utils::get_type_tag(client, &object_id).await?];
+        Self::build_transaction_at(client, notarization, tag, method, additional_args).await
+    }
 
-        let mut args = {
-            let notarization = move_utils::get_object_ref_by_id(client, &object_id).await?;
+    /// Like [`NotarizationImpl::build_transaction`], but uses the given `notarization` reference
+    /// instead of resolving the latest version of the object.
+    ///
+    /// The transaction fails on-chain if the object was modified after `notarization` was read.
+    async fn build_transaction_at<C, F>(
+        client: &C,
+        notarization: ObjectRef,
+        tag: &TypeTag,
+        method: impl AsRef<str>,
+        additional_args: F,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        F: FnOnce(&mut ProgrammableTransactionBuilder) -> Result<Vec<Argument>, Error>,
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        Self::add_move_call(&mut ptb, client, notarization, tag, method, additional_args)?;
 
-            vec![
-                ptb.obj(ObjectArg::ImmOrOwnedObject(notarization))
-                    .map_err(|e| Error::InvalidArgument(format!("Failed to create object argument: {e}")))?,
-            ]
-        };
+        Ok(ptb.finish())
+    }
+
+    /// Adds the call of `method` on the given `notarization` reference to `ptb`, e.g. to call it
+    /// on several notarizations in one transaction.
+    fn add_move_call<C, F>(
+        ptb: &mut ProgrammableTransactionBuilder,
+        client: &C,
+        notarization: ObjectRef,
+        tag: &TypeTag,
+        method: impl AsRef<str>,
+        additional_args: F,
+    ) -> Result<(), Error>
+    where
+        F: FnOnce(&mut ProgrammableTransactionBuilder) -> Result<Vec<Argument>, Error>,
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let mut args = vec![
+            ptb.obj(ObjectArg::ImmOrOwnedObject(notarization))
+                .map_err(|e| Error::InvalidArgument(format!("Failed to create object argument: {e}")))?,
+        ];
         // Add additional arguments
         args.extend(
-            additional_args(&mut ptb)
+            additional_args(ptb)
                 .map_err(|e| Error::InvalidArgument(format!("Failed to add additional arguments: {e}")))?,
         );
 
@@ -84,11 +117,11 @@ impl NotarizationImpl {
             client.package_id(),
             ident_str!("notarization").into(),
             function,
-            tag,
+            vec![tag.clone()],
             args,
         );
 
-        Ok(ptb.finish())
+        Ok(())
     }
 }
 
@@ -96,53 +129,110 @@ impl NotarizationImpl {
 ///
 /// These operations return a `ProgrammableTransaction` which is
 /// a single transaction, or command, in a programmable transaction block
+///
+/// Operations that only read a notarization take the type tag of its state data, so that
+/// callers can resolve it once and reuse it for several reads.
 #[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
 #[cfg_attr(feature = "send-sync", async_trait)]
 pub(crate) trait NotarizationOperations {
     /// Build a transaction that creates a new locked notarization
     fn new_locked(
         package_id: ObjectID,
-        state: State,
+        clock: ClockObject,
+        state: &State,
         immutable_description: Option<String>,
         updatable_metadata: Option<String>,
         delete_lock: TimeLock,
     ) -> Result<ProgrammableTransaction, Error> {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
+        Self::add_new_locked(
+            &mut ptb,
+            package_id,
+            clock,
+            state,
+            immutable_description,
+            updatable_metadata,
+            delete_lock,
+        )?;
+
+        Ok(ptb.finish())
+    }
+
+    /// Add a move call that creates a new locked notarization to `ptb`
+    fn add_new_locked(
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectID,
+        clock: ClockObject,
+        state: &State,
+        immutable_description: Option<String>,
+        updatable_metadata: Option<String>,
+        delete_lock: TimeLock,
+    ) -> Result<(), Error> {
         let tag = state.data.tag();
-        let clock = move_utils::get_clock_ref(&mut ptb);
-        let state_arg = state.into_ptb(&mut ptb, package_id)?;
-        let immutable_description = move_utils::ptb_pure(&mut ptb, "immutable_description", immutable_description)?;
-        let updatable_metadata = move_utils::ptb_pure(&mut ptb, "updatable_metadata", updatable_metadata)?;
-        let delete_lock = delete_lock.to_ptb(&mut ptb, package_id)?;
+        let clock_arg = move_utils::get_clock_ref(ptb, clock);
+        let state_arg = state.build_into_ptb(ptb, package_id)?;
+        let immutable_description = move_utils::ptb_pure(ptb, "immutable_description", immutable_description)?;
+        let updatable_metadata = move_utils::ptb_pure(ptb, "updatable_metadata", updatable_metadata)?;
+        let delete_lock = delete_lock.to_ptb(ptb, package_id, clock)?;
 
         ptb.programmable_move_call(
             package_id,
             ident_str!("locked_notarization").into(),
             ident_str!("create").into(),
             vec![tag],
-            vec![state_arg, immutable_description, updatable_metadata, delete_lock, clock],
+            vec![
+                state_arg,
+                immutable_description,
+                updatable_metadata,
+                delete_lock,
+                clock_arg,
+            ],
         );
 
-        Ok(ptb.finish())
+        Ok(())
     }
 
     /// Build a transaction that creates a new dynamic notarization
     fn new_dynamic(
         package_id: ObjectID,
-        state: State,
+        clock: ClockObject,
+        state: &State,
         immutable_description: Option<String>,
         updatable_metadata: Option<String>,
         transfer_lock: TimeLock,
     ) -> Result<ProgrammableTransaction, Error> {
         let mut ptb = ProgrammableTransactionBuilder::new();
 
+        Self::add_new_dynamic(
+            &mut ptb,
+            package_id,
+            clock,
+            state,
+            immutable_description,
+            updatable_metadata,
+            transfer_lock,
+        )?;
+
+        Ok(ptb.finish())
+    }
+
+    /// Add a move call that creates a new dynamic notarization to `ptb`
+    fn add_new_dynamic(
+        ptb: &mut ProgrammableTransactionBuilder,
+        package_id: ObjectID,
+        clock: ClockObject,
+        state: &State,
+        immutable_description: Option<String>,
+        updatable_metadata: Option<String>,
+        transfer_lock: TimeLock,
+    ) -> Result<(), Error> {
         let tag = state.data.tag();
-        let clock = move_utils::get_clock_ref(&mut ptb);
-        let state_arg = state.into_ptb(&mut ptb, package_id)?;
-        let immutable_description = move_utils::ptb_pure(&mut ptb, "immutable_description", immutable_description)?;
-        let updatable_metadata = move_utils::ptb_pure(&mut ptb, "updatable_metadata", updatable_metadata)?;
-        let transfer_lock = transfer_lock.to_ptb(&mut ptb, package_id)?;
+        let clock_arg = move_utils::get_clock_ref(ptb, clock);
+        let state_arg = state.build_into_ptb(ptb, package_id)?;
+        let immutable_description = move_utils::ptb_pure(ptb, "immutable_description", immutable_description)?;
+        let updatable_metadata = move_utils::ptb_pure(ptb, "updatable_metadata", updatable_metadata)?;
+        let transfer_lock = transfer_lock.to_ptb(ptb, package_id, clock)?;
 
         ptb.programmable_move_call(
             package_id,
@@ -154,159 +244,245 @@ pub(crate) trait NotarizationOperations {
                 immutable_description,
                 updatable_metadata,
                 transfer_lock,
-                clock,
+                clock_arg,
             ],
         );
 
-        Ok(ptb.finish())
+        Ok(())
     }
 
     /// Build a transaction that updates the state of a notarization
     async fn update_state<C>(
         client: &C,
         object_id: ObjectID,
-        new_state: State,
+        new_state: &State,
+        clock: ClockObject,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "update_state", |ptb| {
+        let tag = move_utils::get_type_tag(client, &object_id).await?;
+        NotarizationImpl::build_transaction(client, object_id, &tag, "update_state", |ptb| {
             Ok(vec![
-                new_state.into_ptb(ptb, client.package_id())?,
-                move_utils::get_clock_ref(ptb),
+                new_state.build_into_ptb(ptb, client.package_id())?,
+                move_utils::get_clock_ref(ptb, clock),
+            ])
+        })
+        .await
+    }
+
+    /// Build a transaction that updates the state of the given version of a notarization
+    async fn update_state_at<C>(
+        client: &C,
+        notarization: ObjectRef,
+        new_state: &State,
+        clock: ClockObject,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let tag = move_utils::get_type_tag(client, &notarization.0).await?;
+        NotarizationImpl::build_transaction_at(client, notarization, &tag, "update_state", |ptb| {
+            Ok(vec![
+                new_state.build_into_ptb(ptb, client.package_id())?,
+                move_utils::get_clock_ref(ptb, clock),
             ])
         })
         .await
     }
 
     /// Build a transaction that destroys a notarization
-    async fn destroy<C>(client: &C, object_id: ObjectID) -> Result<ProgrammableTransaction, Error>
+    async fn destroy<C>(client: &C, object_id: ObjectID, clock: ClockObject) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let tag = move_utils::get_type_tag(client, &object_id).await?;
+        NotarizationImpl::build_transaction(client, object_id, &tag, "destroy", |ptb| {
+            Ok(vec![move_utils::get_clock_ref(ptb, clock)])
+        })
+        .await
+    }
+
+    /// Build a transaction that destroys the given version of a notarization
+    async fn destroy_at<C>(
+        client: &C,
+        notarization: ObjectRef,
+        clock: ClockObject,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "destroy", |ptb| {
-            Ok(vec![move_utils::get_clock_ref(ptb)])
+        let tag = move_utils::get_type_tag(client, &notarization.0).await?;
+        NotarizationImpl::build_transaction_at(client, notarization, &tag, "destroy", |ptb| {
+            Ok(vec![move_utils::get_clock_ref(ptb, clock)])
         })
         .await
     }
 
+    /// Build a transaction that destroys several notarizations at once
+    async fn destroy_batch<C>(
+        client: &C,
+        object_ids: &[ObjectID],
+        clock: ClockObject,
+    ) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let notarizations = move_utils::get_object_refs_and_type_tags(client, object_ids).await?;
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let clock = move_utils::get_clock_ref(&mut ptb, clock);
+        for (notarization, tag) in notarizations {
+            NotarizationImpl::add_move_call(&mut ptb, client, notarization, &tag, "destroy", |_| Ok(vec![clock]))?;
+        }
+
+        Ok(ptb.finish())
+    }
+
     /// Build a transaction that updates the metadata of a notarization
     async fn update_metadata<C>(
         client: &C,
         object_id: ObjectID,
         new_metadata: Option<String>,
+        clock: ClockObject,
     ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "update_metadata", |ptb| {
+        let tag = move_utils::get_type_tag(client, &object_id).await?;
+        NotarizationImpl::build_transaction(client, object_id, &tag, "update_metadata", |ptb| {
             Ok(vec![
                 move_utils::ptb_pure(ptb, "new_metadata", new_metadata)?,
-                move_utils::get_clock_ref(ptb),
+                move_utils::get_clock_ref(ptb, clock),
             ])
         })
         .await
     }
 
     /// Build a transaction that returns the notarization method
-    async fn notarization_method<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn notarization_method<C>(
+        object_id: ObjectID,
+        tag: &TypeTag,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "notarization_method", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, object_id, tag, "notarization_method", |_| Ok(vec![])).await
     }
 
     /// Build a transaction that checks if the notarization is locked for update
-    async fn is_update_locked<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn is_update_locked<C>(
+        object_id: ObjectID,
+        tag: &TypeTag,
+        clock: ClockObject,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "is_update_locked", |ptb| {
-            Ok(vec![move_utils::get_clock_ref(ptb)])
+        NotarizationImpl::build_transaction(client, object_id, tag, "is_update_locked", |ptb| {
+            Ok(vec![move_utils::get_clock_ref(ptb, clock)])
         })
         .await
     }
 
     /// Build a transaction that checks if the notarization is allowed to be destroyed
-    async fn is_destroy_allowed<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn is_destroy_allowed<C>(
+        object_id: ObjectID,
+        tag: &TypeTag,
+        clock: ClockObject,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "is_destroy_allowed", |ptb| {
-            Ok(vec![move_utils::get_clock_ref(ptb)])
+        NotarizationImpl::build_transaction(client, object_id, tag, "is_destroy_allowed", |ptb| {
+            Ok(vec![move_utils::get_clock_ref(ptb, clock)])
         })
         .await
     }
 
     /// Build a transaction that checks if the notarization is locked for transfer
-    async fn is_transfer_locked<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn is_transfer_locked<C>(
+        object_id: ObjectID,
+        tag: &TypeTag,
+        clock: ClockObject,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "is_transfer_locked", |ptb| {
-            Ok(vec![move_utils::get_clock_ref(ptb)])
+        NotarizationImpl::build_transaction(client, object_id, tag, "is_transfer_locked", |ptb| {
+            Ok(vec![move_utils::get_clock_ref(ptb, clock)])
         })
         .await
     }
 
     /// Last change timestamp
-    async fn last_change_ts<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn last_change_ts<C>(object_id: ObjectID, tag: &TypeTag, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "last_change", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, object_id, tag, "last_change", |_| Ok(vec![])).await
     }
 
     /// Version count
-    async fn version_count<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn version_count<C>(object_id: ObjectID, tag: &TypeTag, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "version_count", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, object_id, tag, "version_count", |_| Ok(vec![])).await
     }
 
     /// Created at timestamp
-    async fn created_at<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn created_at<C>(object_id: ObjectID, tag: &TypeTag, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "created_at", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, object_id, tag, "created_at", |_| Ok(vec![])).await
     }
 
     /// Description
-    async fn description<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn description<C>(object_id: ObjectID, tag: &TypeTag, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "description", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, object_id, tag, "description", |_| Ok(vec![])).await
     }
 
     /// Updatable metadata
-    async fn updatable_metadata<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn updatable_metadata<C>(
+        object_id: ObjectID,
+        tag: &TypeTag,
+        client: &C,
+    ) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "updatable_metadata", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, object_id, tag, "updatable_metadata", |_| Ok(vec![])).await
     }
 
     /// Lock metadata
-    async fn lock_metadata<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn lock_metadata<C>(object_id: ObjectID, tag: &TypeTag, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "lock_metadata", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, object_id, tag, "lock_metadata", |_| Ok(vec![])).await
     }
 
-    async fn state<C>(object_id: ObjectID, client: &C) -> Result<ProgrammableTransaction, Error>
+    async fn state<C>(object_id: ObjectID, tag: &TypeTag, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::build_transaction(client, object_id, "state", |_| Ok(vec![])).await
+        NotarizationImpl::build_transaction(client, object_id, tag, "state", |_| Ok(vec![])).await
     }
 
     async fn transfer_notarization<C>(
         object_id: ObjectID,
         recipient: IotaAddress,
+        reason: Option<String>,
+        clock: ClockObject,
         client: &C,
     ) -> Result<ProgrammableTransaction, Error>
     where
@@ -323,7 +499,19 @@ pub(crate) trait NotarizationOperations {
             .obj(ObjectArg::ImmOrOwnedObject(notarization))
             .map_err(|e| Error::InvalidArgument(format!("Failed to create notarization argument: {e}")))?;
 
-        let clock = move_utils::get_clock_ref(&mut ptb);
+        let clock = move_utils::get_clock_ref(&mut ptb, clock);
+
+        // The reason is recorded in the same transaction, so it cannot diverge from the transfer.
+        if let Some(reason) = reason {
+            let reason = move_utils::ptb_pure(&mut ptb, "reason", Some(reason))?;
+            ptb.programmable_move_call(
+                client.package_id(),
+                ident_str!("notarization").into(),
+                ident_str!("update_metadata").into(),
+                tag.clone(),
+                vec![notarization, reason, clock],
+            );
+        }
 
         ptb.programmable_move_call(
             client.package_id(),
@@ -11,9 +11,10 @@
 
 use async_trait::async_trait;
 use iota_interaction::rpc_types::{
-    IotaData as _, IotaObjectDataOptions, IotaTransactionBlockEffects, IotaTransactionBlockEvents,
+    IotaData as _, IotaObjectDataOptions, IotaRawMoveObject, IotaTransactionBlockEffects, IotaTransactionBlockEvents,
 };
 use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use iota_interaction::types::transaction::ProgrammableTransaction;
 use iota_interaction::{IotaClientTrait, OptionalSend, OptionalSync};
 use product_common::core_client::CoreClientReadOnly;
@@ -22,6 +23,7 @@ use serde::de::DeserializeOwned;
 use tokio::sync::OnceCell;
 
 use super::super::builder::NotarizationBuilder;
+use super::super::move_utils;
 use super::super::operations::{NotarizationImpl, NotarizationOperations};
 use super::super::types::{
     DynamicNotarizationCreated, Event, LockMetadata, LockedNotarizationCreated, NotarizationMethod,
@@ -51,6 +53,9 @@ impl<M: Clone> CreateNotarization<M> {
     /// - Dynamic notarization can only have transfer locking or no `immutable_metadata.locking`. If
     ///   `immutable_metadata.locking` exists, all locks except `transfer_lock` must be `TimeLock::None` and the
     ///   `transfer_lock` must not be `TimeLock::None`.
+    ///
+    /// The contract does not accept a delete lock for dynamic notarizations; it derives the delete
+    /// restriction from the `transfer_lock` (see `notarization::is_destroy_allowed`).
     fn are_dynamic_notarization_invariants_ok(locking: &Option<LockMetadata>) -> bool {
         match locking {
             Some(lock_metadata) => {
@@ -77,35 +82,60 @@ impl<M: Clone> CreateNotarization<M> {
     }
 
     /// Makes a [`ProgrammableTransaction`] for the [`CreateNotarization`] instance.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "create_notarization", skip_all, fields(method = ?self.builder.method), err))]
     async fn make_ptb(&self, client: &impl CoreClientReadOnly) -> Result<ProgrammableTransaction, Error> {
+        let package_id = notarization_package_id(client).await?;
+
+        // The builder is borrowed, so that large states are not copied on every build. Unlock
+        // times are checked and relative locks resolved against the on-chain clock.
+        let now = if self.builder.needs_clock() {
+            Some(move_utils::get_clock_timestamp_ms(client, self.builder.clock).await? / 1000)
+        } else {
+            None
+        };
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        Self::add_create_call(&mut ptb, &self.builder, now, package_id)?;
+
+        Ok(ptb.finish())
+    }
+
+    /// Validates `builder` and adds the move call creating its notarization to `ptb`.
+    ///
+    /// Unlock times are checked and relative locks resolved against `now` (seconds since the
+    /// Unix epoch), if given.
+    pub(super) fn add_create_call(
+        ptb: &mut ProgrammableTransactionBuilder,
+        builder: &NotarizationBuilder<M>,
+        now: Option<u64>,
+        package_id: ObjectID,
+    ) -> Result<(), Error> {
+        builder.validate_at(now)?;
+
+        let (delete_lock, transfer_lock) = builder.resolved_locks(now)?;
+        let immutable_description = builder.recorded_description()?;
         let NotarizationBuilder {
             state,
-            immutable_description,
             updatable_metadata,
             method,
-            delete_lock,
-            transfer_lock,
             ..
-        } = self.builder.clone();
-
-        let package_id = notarization_package_id(client).await?;
-
-        let state = state.ok_or_else(|| Error::InvalidArgument("State is required".to_string()))?;
+        } = builder;
+        let state = state
+            .as_ref()
+            .ok_or_else(|| Error::InvalidArgument("State is required".to_string()))?;
 
         match method {
             NotarizationMethod::Dynamic => {
-                if delete_lock.is_some() {
-                    return Err(Error::InvalidArgument(
-                        "Delete lock cannot be set for dynamic notarizations".to_string(),
-                    ));
-                }
-
-                // Construct the locking metadata for dynamic notarization
-                let locking = transfer_lock.as_ref().map(|t_lock| LockMetadata {
-                    update_lock: TimeLock::None,
-                    delete_lock: TimeLock::None,
-                    transfer_lock: t_lock.clone(),
-                });
+                // Construct the locking metadata for dynamic notarization; like the contract, a
+                // `TimeLock::None` transfer lock means no locking at all
+                let locking = transfer_lock
+                    .as_ref()
+                    .filter(|t_lock| **t_lock != TimeLock::None)
+                    .map(|t_lock| LockMetadata {
+                        update_lock: TimeLock::None,
+                        delete_lock: TimeLock::None,
+                        transfer_lock: t_lock.clone(),
+                    });
 
                 // Check invariants
                 if !Self::are_dynamic_notarization_invariants_ok(&locking) {
@@ -114,21 +144,17 @@ impl<M: Clone> CreateNotarization<M> {
                     ));
                 }
 
-                NotarizationImpl::new_dynamic(
+                NotarizationImpl::add_new_dynamic(
+                    ptb,
                     package_id,
+                    builder.clock,
                     state,
                     immutable_description,
-                    updatable_metadata,
+                    updatable_metadata.clone(),
                     transfer_lock.unwrap_or(TimeLock::None),
                 )
             }
             NotarizationMethod::Locked => {
-                if transfer_lock.is_some() {
-                    return Err(Error::InvalidArgument(
-                        "Transfer lock cannot be set for locked notarizations".to_string(),
-                    ));
-                }
-
                 // Construct the locking metadata for locked notarization
                 let locking = Some(LockMetadata {
                     update_lock: TimeLock::UntilDestroyed,
@@ -143,11 +169,13 @@ impl<M: Clone> CreateNotarization<M> {
                     ));
                 }
 
-                NotarizationImpl::new_locked(
+                NotarizationImpl::add_new_locked(
+                    ptb,
                     package_id,
+                    builder.clock,
                     state,
                     immutable_description,
-                    updatable_metadata,
+                    updatable_metadata.clone(),
                     delete_lock.unwrap_or(TimeLock::None),
                 )
             }
@@ -184,22 +212,9 @@ impl<M: Clone + OptionalSend + OptionalSync> Transaction for CreateNotarization<
             .first()
             .ok_or_else(|| Error::TransactionUnexpectedResponse("events should be provided".to_string()))?;
 
-        let notarization_id = match method {
-            NotarizationMethod::Dynamic => {
-                let event: Event<DynamicNotarizationCreated> = serde_json::from_value(data.parsed_json.clone())
-                    .map_err(|e| Error::TransactionUnexpectedResponse(format!("failed to parse event: {e}")))?;
-
-                event.data.notarization_id
-            }
-            NotarizationMethod::Locked => {
-                let event: Event<LockedNotarizationCreated> = serde_json::from_value(data.parsed_json.clone())
-                    .map_err(|e| Error::TransactionUnexpectedResponse(format!("failed to parse event: {e}")))?;
-
-                event.data.notarization_id
-            }
-        };
+        let notarization_id = parse_created_event(&method, &data.parsed_json)?;
 
-        let notarization = get_object_ref_by_id_with_bcs::<OnChainNotarization>(client, &notarization_id)
+        let notarization = get_notarization_by_id_with_bcs(client, &notarization_id)
             .await
             .map_err(|e| Error::ObjectLookup(e.to_string()))?;
 
@@ -214,11 +229,104 @@ impl<M: Clone + OptionalSend + OptionalSync> Transaction for CreateNotarization<
     }
 }
 
+/// A transaction that creates a new notarization and only returns its [`ObjectID`].
+///
+/// Unlike [`CreateNotarization`], the created object is not read back from the network after
+/// the transaction was executed, which saves a round trip per notarization, e.g. for bulk
+/// ingestion.
+#[derive(Debug, Clone)]
+pub struct CreateNotarizationIdOnly<M>(CreateNotarization<M>);
+
+impl<M: Clone> CreateNotarizationIdOnly<M> {
+    /// Creates a new [`CreateNotarizationIdOnly`] instance.
+    pub fn new(builder: NotarizationBuilder<M>) -> Self {
+        Self(CreateNotarization::new(builder))
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<M: Clone + OptionalSend + OptionalSync> Transaction for CreateNotarizationIdOnly<M> {
+    type Error = Error;
+
+    type Output = ObjectID;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.0.build_programmable_transaction(client).await
+    }
+
+    async fn apply_with_events<C>(
+        mut self,
+        _: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        _: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let data = events
+            .data
+            .first()
+            .ok_or_else(|| Error::TransactionUnexpectedResponse("events should be provided".to_string()))?;
+
+        parse_created_event(&self.0.builder.method, &data.parsed_json)
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        unreachable!()
+    }
+}
+
+/// Extracts the ID of the created notarization from a `*NotarizationCreated` event.
+pub(super) fn parse_created_event(
+    method: &NotarizationMethod,
+    parsed_json: &serde_json::Value,
+) -> Result<ObjectID, Error> {
+    let notarization_id = match method {
+        NotarizationMethod::Dynamic => {
+            let event: Event<DynamicNotarizationCreated> = serde_json::from_value(parsed_json.clone())
+                .map_err(|e| Error::TransactionUnexpectedResponse(format!("failed to parse event: {e}")))?;
+
+            event.data.notarization_id
+        }
+        NotarizationMethod::Locked => {
+            let event: Event<LockedNotarizationCreated> = serde_json::from_value(parsed_json.clone())
+                .map_err(|e| Error::TransactionUnexpectedResponse(format!("failed to parse event: {e}")))?;
+
+            event.data.notarization_id
+        }
+    };
+
+    Ok(notarization_id)
+}
+
 pub(crate) async fn get_object_ref_by_id_with_bcs<T: DeserializeOwned>(
     client: &impl CoreClientReadOnly,
     object_id: &ObjectID,
 ) -> Result<T, Error> {
-    let notarization = client
+    get_move_object(client, object_id)
+        .await?
+        .deserialize()
+        .map_err(|err| Error::ObjectLookup(err.to_string()))
+}
+
+/// Reads a notarization, taking the kind of its state data from the object type.
+pub(crate) async fn get_notarization_by_id_with_bcs(
+    client: &impl CoreClientReadOnly,
+    object_id: &ObjectID,
+) -> Result<OnChainNotarization, Error> {
+    OnChainNotarization::from_move_object(&get_move_object(client, object_id).await?)
+}
+
+#[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", skip(client), err))]
+async fn get_move_object(client: &impl CoreClientReadOnly, object_id: &ObjectID) -> Result<IotaRawMoveObject, Error> {
+    client
         .client_adapter()
         .read_api()
         .get_object_with_options(*object_id, IotaObjectDataOptions::bcs_lossless())
@@ -229,16 +337,16 @@ pub(crate) async fn get_object_ref_by_id_with_bcs<T: DeserializeOwned>(
         .bcs
         .ok_or_else(|| Error::ObjectLookup("missing object content in data".to_string()))?
         .try_into_move()
-        .ok_or_else(|| Error::ObjectLookup("failed to convert data to move object".to_string()))?
-        .deserialize()
-        .map_err(|err| Error::ObjectLookup(err.to_string()))?;
-
-    Ok(notarization)
+        .ok_or_else(|| Error::ObjectLookup("failed to convert data to move object".to_string()))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::time::Duration;
+
     use super::*;
+    use crate::client::GasBudgetPolicy;
+    use crate::core::types::{DEFAULT_MAX_METADATA_BYTES, suggested_gas_budget};
 
     #[test]
     fn test_dynamic_notarization_invariants() {
@@ -273,4 +381,214 @@ mod tests {
             transfer_lock: TimeLock::None,
         })));
     }
+
+    #[test]
+    fn test_delete_lock_on_dynamic_notarization_is_rejected() {
+        // The contract has no delete lock for dynamic notarizations, not even `UntilDestroyed`
+        // paired with a transfer lock.
+        for delete_lock in [
+            TimeLock::None,
+            TimeLock::UnlockAt(1_900_000_000),
+            TimeLock::UntilDestroyed,
+        ] {
+            let mut builder = NotarizationBuilder::non_transferable().with_string_state("Record".to_string(), None);
+            builder.delete_lock = Some(delete_lock);
+
+            let mut ptb = ProgrammableTransactionBuilder::new();
+            let result = CreateNotarization::add_create_call(&mut ptb, &builder, None, ObjectID::ZERO);
+            assert!(matches!(result, Err(Error::InvalidArgument(message)) if message.contains("permanent_record")));
+        }
+    }
+
+    #[test]
+    fn test_until_destroyed_delete_lock_is_rejected() {
+        let builder = NotarizationBuilder::locked()
+            .with_string_state("Record".to_string(), None)
+            .with_delete_lock(TimeLock::UntilDestroyed);
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let result = CreateNotarization::add_create_call(&mut ptb, &builder, None, ObjectID::ZERO);
+        assert!(matches!(result, Err(Error::InvalidArgument(message)) if message.contains("permanent_record")));
+    }
+
+    #[test]
+    fn test_every_transfer_lock_is_legal_on_dynamic_notarization() {
+        for transfer_lock in [
+            TimeLock::None,
+            TimeLock::UnlockAt(1_900_000_000),
+            TimeLock::UntilDestroyed,
+        ] {
+            let builder = NotarizationBuilder::dynamic()
+                .with_string_state("Status".to_string(), None)
+                .with_transfer_lock(transfer_lock);
+
+            let mut ptb = ProgrammableTransactionBuilder::new();
+            assert!(CreateNotarization::add_create_call(&mut ptb, &builder, None, ObjectID::ZERO).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_over_limit_description_is_rejected() {
+        let description = "a".repeat(DEFAULT_MAX_METADATA_BYTES + 1);
+
+        let result = NotarizationBuilder::locked()
+            .with_string_state("Contract".to_string(), None)
+            .with_immutable_description(description.clone())
+            .with_delete_lock(TimeLock::None)
+            .finish();
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        let builder = NotarizationBuilder::dynamic()
+            .with_string_state("Status".to_string(), None)
+            .with_immutable_description(description.clone());
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let result = CreateNotarization::add_create_call(&mut ptb, &builder, None, ObjectID::ZERO);
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        let builder = builder.with_max_metadata_bytes(move_utils::pure_size(&Some(&description)));
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        assert!(CreateNotarization::add_create_call(&mut ptb, &builder, None, ObjectID::ZERO).is_ok());
+    }
+
+    #[test]
+    fn test_checked_unlock_times_reject_the_past() {
+        let result = NotarizationBuilder::locked().with_delete_at_checked(1_000);
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        let result = NotarizationBuilder::dynamic().with_transfer_lock_at_checked(1_000);
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+        let builder = NotarizationBuilder::dynamic()
+            .with_transfer_lock_at_checked(u32::MAX)
+            .unwrap();
+        assert_eq!(builder.transfer_lock, Some(TimeLock::UnlockAt(u32::MAX)));
+    }
+
+    #[test]
+    fn test_presets_configure_valid_locks() {
+        let builder = NotarizationBuilder::permanent_record().with_string_state("Deed".to_string(), None);
+        assert_eq!(builder.delete_lock, Some(TimeLock::UnlockAt(u32::MAX)));
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        assert!(CreateNotarization::add_create_call(&mut ptb, &builder, None, ObjectID::ZERO).is_ok());
+
+        let builder = NotarizationBuilder::destroyable_at(1_900_000_000);
+        assert_eq!(builder.delete_lock, Some(TimeLock::UnlockAt(1_900_000_000)));
+        assert_eq!(
+            NotarizationBuilder::destroyable_anytime().delete_lock,
+            Some(TimeLock::None)
+        );
+
+        let builder = NotarizationBuilder::non_transferable().with_string_state("Status".to_string(), None);
+        assert_eq!(builder.transfer_lock, Some(TimeLock::UntilDestroyed));
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        assert!(CreateNotarization::add_create_call(&mut ptb, &builder, None, ObjectID::ZERO).is_ok());
+
+        let builder = NotarizationBuilder::transferable_at(1_900_000_000);
+        assert_eq!(builder.transfer_lock, Some(TimeLock::UnlockAt(1_900_000_000)));
+        assert_eq!(builder.delete_lock, None);
+    }
+
+    #[test]
+    fn test_gas_budget_follows_the_policy() {
+        let builder = NotarizationBuilder::dynamic().with_bytes_state(vec![0; 64 * 1024], None);
+        assert_eq!(builder.gas_budget(), None);
+
+        let builder = builder.with_gas_budget_policy(&GasBudgetPolicy::new().with_size_scaled_budgets());
+        let scaled = builder.gas_budget().expect("the budget is scaled");
+        assert_eq!(scaled, suggested_gas_budget(builder.stored_bytes()));
+
+        let builder =
+            builder.with_gas_budget_policy(&GasBudgetPolicy::new().with_create(5_000_000).with_size_scaled_budgets());
+        assert_eq!(builder.gas_budget(), Some(5_000_000));
+        assert_ne!(scaled, 5_000_000);
+    }
+
+    #[test]
+    fn test_multi_line_dedupe_tag_is_rejected() {
+        let builder = NotarizationBuilder::dynamic()
+            .with_string_state("Status".to_string(), None)
+            .with_dedupe_tag("invoice\n42".to_string());
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let result = CreateNotarization::add_create_call(&mut ptb, &builder, None, ObjectID::ZERO);
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_dynamic_delete_lock_is_rejected_with_explanation() {
+        let mut builder = NotarizationBuilder::dynamic()
+            .with_string_state("Status".to_string(), None)
+            .with_transfer_lock(TimeLock::UnlockAt(1_900_000_000));
+        builder.delete_lock = Some(TimeLock::UnlockAt(1_900_000_000));
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let result = CreateNotarization::add_create_call(&mut ptb, &builder, None, ObjectID::ZERO);
+        assert!(matches!(result, Err(Error::InvalidArgument(message)) if message.contains("transfer lock")));
+    }
+
+    #[test]
+    fn test_validate_reports_every_problem() {
+        let mut builder = NotarizationBuilder::dynamic()
+            .with_immutable_description("a".repeat(DEFAULT_MAX_METADATA_BYTES + 1))
+            .with_transfer_lock(TimeLock::UnlockAt(1_000));
+        builder.delete_lock = Some(TimeLock::None);
+
+        let Err(Error::InvalidArgument(message)) = builder.validate() else {
+            panic!("the builder should be invalid");
+        };
+        assert!(message.contains("State is required"));
+        assert!(message.contains("immutable description"));
+        assert!(message.contains("permanent_record"));
+        assert!(message.contains("not in the future"));
+
+        let builder = NotarizationBuilder::locked()
+            .with_string_state("Contract".to_string(), None)
+            .with_delete_lock(TimeLock::UnlockAt(u32::MAX));
+        assert!(builder.validate().is_ok());
+    }
+
+    #[test]
+    fn test_unlock_times_are_checked_against_the_given_clock() {
+        let builder = NotarizationBuilder::transferable_at(1_900_000_000).with_string_state("Status".to_string(), None);
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        assert!(CreateNotarization::add_create_call(&mut ptb, &builder, Some(1_800_000_000), ObjectID::ZERO).is_ok());
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let result = CreateNotarization::add_create_call(&mut ptb, &builder, Some(1_900_000_000), ObjectID::ZERO);
+        assert!(matches!(result, Err(Error::InvalidArgument(message)) if message.contains("not in the future")));
+    }
+
+    #[test]
+    fn test_relative_locks_are_resolved_against_the_given_clock() {
+        let builder = NotarizationBuilder::locked()
+            .with_string_state("Contract".to_string(), None)
+            .with_delete_lock_after(Duration::from_secs(3_600));
+        assert!(builder.needs_clock());
+        assert_eq!(
+            builder.resolved_locks(Some(1_800_000_000)).unwrap(),
+            (Some(TimeLock::UnlockAt(1_800_003_600)), None)
+        );
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        let result = CreateNotarization::add_create_call(&mut ptb, &builder, None, ObjectID::ZERO);
+        assert!(matches!(result, Err(Error::TimeLock(_))));
+
+        let builder = builder.with_delete_lock_after(Duration::from_millis(500));
+        assert!(matches!(builder.validate(), Err(Error::InvalidArgument(message)) if message.contains("one second")));
+    }
+
+    #[test]
+    fn test_normalized_json_state_ignores_formatting() {
+        let compact = NotarizationBuilder::dynamic()
+            .with_normalized_json_state(r#"{"status":"active","version":"2.0"}"#, None)
+            .unwrap();
+        let formatted = NotarizationBuilder::dynamic()
+            .with_normalized_json_state("{\n  \"version\": \"2.0\",\n  \"status\": \"active\"\n}  \n", None)
+            .unwrap();
+        assert_eq!(compact.state, formatted.state);
+
+        let result = NotarizationBuilder::dynamic().with_normalized_json_state("{\"status\":", None);
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
 }
@@ -0,0 +1,138 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Create Notarization Batch
+//!
+//! This module defines the batch notarization creation transaction.
+//!
+//! ## Overview
+//!
+//! The batch creation transaction creates several notarizations of the same method in a single
+//! programmable transaction, saving the overhead of one transaction per notarization.
+
+use async_trait::async_trait;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
+use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use iota_interaction::{OptionalSend, OptionalSync};
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use super::super::builder::NotarizationBuilder;
+use super::super::move_utils;
+use super::super::types::OnChainNotarization;
+use super::create::parse_created_event;
+use super::{CreateNotarization, get_notarization_by_id_with_bcs};
+use crate::error::Error;
+use crate::package::notarization_package_id;
+
+/// The names of the events emitted when a notarization is created.
+const CREATED_EVENT_NAMES: [&str; 2] = ["DynamicNotarizationCreated", "LockedNotarizationCreated"];
+
+/// A transaction that creates several notarizations at once.
+///
+/// The notarizations are created in the order of the provided builders, and the
+/// output of the transaction follows the same order.
+#[derive(Debug, Clone)]
+pub struct CreateNotarizationBatch<M> {
+    builders: Vec<NotarizationBuilder<M>>,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl<M: Clone> CreateNotarizationBatch<M> {
+    /// Creates a new [`CreateNotarizationBatch`] instance.
+    pub fn new(builders: Vec<NotarizationBuilder<M>>) -> Self {
+        Self {
+            builders,
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Makes a [`ProgrammableTransaction`] for the [`CreateNotarizationBatch`] instance.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "create_notarization_batch", skip_all, fields(count = self.builders.len()), err))]
+    async fn make_ptb(&self, client: &impl CoreClientReadOnly) -> Result<ProgrammableTransaction, Error> {
+        if self.builders.is_empty() {
+            return Err(Error::InvalidArgument(
+                "a notarization batch must contain at least one notarization".to_string(),
+            ));
+        }
+
+        let package_id = notarization_package_id(client).await?;
+
+        // All locks of the batch are checked and resolved against the same clock reading, taken
+        // from the clock of the first notarization.
+        let now = if self.builders.iter().any(NotarizationBuilder::needs_clock) {
+            Some(move_utils::get_clock_timestamp_ms(client, self.builders[0].clock).await? / 1000)
+        } else {
+            None
+        };
+
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        for (index, builder) in self.builders.iter().enumerate() {
+            CreateNotarization::add_create_call(&mut ptb, builder, now, package_id)
+                .map_err(|e| Error::InvalidArgument(format!("notarization #{index} of the batch is invalid: {e}")))?;
+        }
+
+        Ok(ptb.finish())
+    }
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl<M: Clone + OptionalSend + OptionalSync> Transaction for CreateNotarizationBatch<M> {
+    type Error = Error;
+
+    type Output = Vec<OnChainNotarization>;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply_with_events<C>(
+        mut self,
+        _: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        // Events are emitted in command order, so the n-th creation event belongs to the n-th builder.
+        let created_events = events
+            .data
+            .iter()
+            .filter(|event| CREATED_EVENT_NAMES.contains(&event.type_.name.as_str()))
+            .collect::<Vec<_>>();
+
+        if created_events.len() != self.builders.len() {
+            return Err(Error::TransactionUnexpectedResponse(format!(
+                "expected {} creation events, got {}",
+                self.builders.len(),
+                created_events.len()
+            )));
+        }
+
+        let mut notarizations = Vec::with_capacity(self.builders.len());
+        for (builder, event) in self.builders.iter().zip(created_events) {
+            let notarization_id = parse_created_event(&builder.method, &event.parsed_json)?;
+
+            let notarization = get_notarization_by_id_with_bcs(client, &notarization_id)
+                .await
+                .map_err(|e| Error::ObjectLookup(e.to_string()))?;
+            notarizations.push(notarization);
+        }
+
+        Ok(notarizations)
+    }
+
+    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        unreachable!()
+    }
+}
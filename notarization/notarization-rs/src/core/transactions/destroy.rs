@@ -10,20 +10,27 @@
 //! The destroy notarization transaction is used to destroy a notarization.
 
 use async_trait::async_trait;
-use iota_interaction::OptionalSync;
-use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
 use iota_interaction::types::base_types::ObjectID;
 use iota_interaction::types::transaction::ProgrammableTransaction;
-use product_common::core_client::CoreClientReadOnly;
+use iota_interaction::{IotaKeySignature, OptionalSync};
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
 use product_common::transaction::transaction_builder::Transaction;
+use secret_storage::Signer;
 use tokio::sync::OnceCell;
 
+use super::super::move_utils::{self, ClockObject};
 use super::super::operations::{NotarizationImpl, NotarizationOperations};
-use crate::error::Error;
+use super::super::types::{NotarizationDestroyed, OnChainNotarization};
+use super::{check_operation, ensure_executed, find_event, precheck, preview};
+use crate::error::{Error, LockedOperation};
 
 /// A transaction that destroys a notarization
 pub struct DestroyNotarization {
     notarization_id: ObjectID,
+    expected_state_hash: Option<[u8; 32]>,
+    precheck: bool,
+    clock: ClockObject,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -32,15 +39,89 @@ impl DestroyNotarization {
     pub fn new(notarization_id: ObjectID) -> Self {
         Self {
             notarization_id,
+            expected_state_hash: None,
+            precheck: false,
+            clock: ClockObject::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Checks that the destruction is valid for the notarization before the transaction is built
+    /// (default: `false`).
+    ///
+    /// The check costs an additional read of the notarization and of the clock, but fails fast
+    /// instead of spending gas on a transaction that is rejected on-chain.
+    ///
+    /// ## Errors
+    ///
+    /// Building the transaction fails with [`Error::Locked`] while the notarization cannot be
+    /// destroyed yet.
+    pub fn with_precheck(mut self, precheck: bool) -> Self {
+        self.precheck = precheck;
+        self
+    }
+
+    /// Sets the clock object the transaction reads the time from (default: the system clock).
+    pub(crate) fn with_clock(mut self, clock: ClockObject) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Only destroys the notarization if the [content hash](crate::core::types::State::content_hash) of its state
+    /// is still `expected_state_hash`.
+    ///
+    /// The state is checked when the transaction is built, and the transaction is bound to the
+    /// object version that was checked: if the notarization is updated in between, the
+    /// transaction fails on-chain instead of destroying the changed record.
+    ///
+    /// ## Errors
+    ///
+    /// Building the transaction fails with [`Error::VersionConflict`] if the current state has a
+    /// different content hash.
+    pub fn with_expected_state_hash(mut self, expected_state_hash: [u8; 32]) -> Self {
+        self.expected_state_hash = Some(expected_state_hash);
+        self
+    }
+
+    /// Simulates the destruction against the current chain state, without submitting it.
+    ///
+    /// The returned effects carry the execution status: a failure indicates that the
+    /// notarization cannot be destroyed yet, e.g. because of its delete lock. The built
+    /// transaction is cached, so the transaction can still be executed afterwards.
+    pub async fn build_and_preview<C, S>(&self, client: &C) -> Result<IotaTransactionBlockEffects, Error>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        preview(self, client).await
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "destroy_notarization", skip_all, fields(notarization_id = %self.notarization_id), err))]
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::destroy(client, self.notarization_id).await
+        let Some(expected_state_hash) = self.expected_state_hash else {
+            if self.precheck {
+                precheck(client, self.notarization_id, LockedOperation::Delete, self.clock).await?;
+            }
+            return NotarizationImpl::destroy(client, self.notarization_id, self.clock).await;
+        };
+
+        // The notarization is read once, for the precheck and for the state check.
+        let (notarization_ref, notarization) =
+            move_utils::get_object_with_ref::<OnChainNotarization>(client, &self.notarization_id).await?;
+        if self.precheck {
+            check_operation(client, &notarization, LockedOperation::Delete, self.clock).await?;
+        }
+        if notarization.state.content_hash() != expected_state_hash {
+            return Err(Error::VersionConflict {
+                expected: None,
+                actual: notarization.state_version_count,
+            });
+        }
+
+        NotarizationImpl::destroy_at(client, notarization_ref, self.clock).await
     }
 }
 
@@ -49,7 +130,7 @@ impl DestroyNotarization {
 impl Transaction for DestroyNotarization {
     type Error = Error;
 
-    type Output = ();
+    type Output = NotarizationDestroyed;
 
     async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
     where
@@ -58,10 +139,33 @@ impl Transaction for DestroyNotarization {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        let event = find_event::<NotarizationDestroyed>(events, self.notarization_id);
+        let applied = self.apply(effects, client).await?;
+
+        Ok(event.unwrap_or(applied))
+    }
+
+    async fn apply<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        ensure_executed(effects, self.notarization_id, client).await?;
+
+        Ok(NotarizationDestroyed {
+            notarization_id: self.notarization_id,
+        })
     }
 }
@@ -0,0 +1,139 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Destroy Notarization Batch
+//!
+//! This module defines the batch notarization destruction transaction.
+//!
+//! ## Overview
+//!
+//! The batch destruction transaction destroys several notarizations in a single programmable
+//! transaction, e.g. to clean up expired notarizations.
+//!
+//! ## Atomicity
+//!
+//! The batch is executed atomically: if a single notarization cannot be destroyed (e.g. because
+//! of an active lock), the whole transaction aborts and none of the notarizations is destroyed.
+//! Filter the notarizations up front, e.g. with
+//! [`NotarizationClient::destroy_batch_allowed`](crate::client::NotarizationClient::destroy_batch_allowed),
+//! to avoid failing the whole batch.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use iota_interaction::OptionalSync;
+use iota_interaction::rpc_types::{IotaExecutionStatus, IotaTransactionBlockEffects, IotaTransactionBlockEffectsAPI};
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::transaction::ProgrammableTransaction;
+use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
+use tokio::sync::OnceCell;
+
+use super::super::move_utils::ClockObject;
+use super::super::operations::{NotarizationImpl, NotarizationOperations};
+use crate::error::Error;
+
+/// A transaction that destroys several notarizations at once.
+///
+/// See the [module docs](self) for the atomicity of the batch.
+pub struct DestroyNotarizationBatch {
+    notarization_ids: Vec<ObjectID>,
+    clock: ClockObject,
+    cached_ptb: OnceCell<ProgrammableTransaction>,
+}
+
+impl DestroyNotarizationBatch {
+    /// Creates a new [`DestroyNotarizationBatch`] instance.
+    pub fn new(notarization_ids: Vec<ObjectID>) -> Self {
+        Self {
+            notarization_ids,
+            clock: ClockObject::default(),
+            cached_ptb: OnceCell::new(),
+        }
+    }
+
+    /// Sets the clock object the transaction reads the time from (default: the system clock).
+    pub(crate) fn with_clock(mut self, clock: ClockObject) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Makes a [`ProgrammableTransaction`] with one `destroy` call per notarization.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "destroy_notarization_batch", skip_all, fields(count = self.notarization_ids.len()), err))]
+    async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        ensure_valid_batch(&self.notarization_ids)?;
+
+        NotarizationImpl::destroy_batch(client, &self.notarization_ids, self.clock).await
+    }
+}
+
+/// Checks that `notarization_ids` is neither empty nor contains duplicates.
+fn ensure_valid_batch(notarization_ids: &[ObjectID]) -> Result<(), Error> {
+    if notarization_ids.is_empty() {
+        return Err(Error::InvalidArgument(
+            "a destroy batch must contain at least one notarization".to_string(),
+        ));
+    }
+
+    let mut seen = HashSet::with_capacity(notarization_ids.len());
+    if let Some(duplicate) = notarization_ids.iter().find(|id| !seen.insert(**id)) {
+        return Err(Error::InvalidArgument(format!(
+            "notarization {duplicate} is contained more than once in the destroy batch"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(not(feature = "send-sync"), async_trait(?Send))]
+#[cfg_attr(feature = "send-sync", async_trait)]
+impl Transaction for DestroyNotarizationBatch {
+    type Error = Error;
+
+    /// The IDs of the destroyed notarizations, in the order they were given.
+    type Output = Vec<ObjectID>;
+
+    async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
+    }
+
+    async fn apply<C>(mut self, effects: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        // The batch is atomic, so a failure means that none of the notarizations was destroyed.
+        if let IotaExecutionStatus::Failure { error } = effects.status() {
+            return Err(Error::from_execution_failure(error));
+        }
+
+        let deleted = effects.deleted();
+        Ok(self
+            .notarization_ids
+            .into_iter()
+            .filter(|id| deleted.iter().any(|object| object.object_id == *id))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_batches_are_rejected() {
+        assert!(matches!(ensure_valid_batch(&[]), Err(Error::InvalidArgument(_))));
+
+        let id = ObjectID::from_single_byte(1);
+        assert!(matches!(
+            ensure_valid_batch(&[id, ObjectID::from_single_byte(2), id]),
+            Err(Error::InvalidArgument(message)) if message.contains(&id.to_string())
+        ));
+        assert!(ensure_valid_batch(&[id, ObjectID::from_single_byte(2)]).is_ok());
+    }
+}
@@ -4,13 +4,215 @@
 //! Transaction operations for notarizations.
 
 mod create;
+mod create_batch;
 mod destroy;
+mod destroy_batch;
 mod transfer;
 mod update_metadata;
 mod update_state;
 
 pub use create::*;
+pub use create_batch::*;
 pub use destroy::*;
+pub use destroy_batch::*;
+use iota_interaction::rpc_types::{
+    IotaExecutionStatus, IotaTransactionBlockEffects, IotaTransactionBlockEffectsAPI, IotaTransactionBlockEvents,
+};
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::transaction::TransactionData;
+use iota_interaction::{IotaClientTrait, IotaKeySignature, OptionalSync};
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
+use secret_storage::Signer;
 pub use transfer::*;
 pub use update_metadata::*;
 pub use update_state::*;
+
+use super::move_utils::{self, ClockObject};
+use super::types::{NotarizationEvent, NotarizationMethod, OnChainNotarization, TimeLock};
+use crate::error::{Error, LockedOperation};
+
+/// The gas budget of a preview.
+///
+/// No gas coins are selected for a dry run; the node executes it with a mock gas coin.
+const PREVIEW_GAS_BUDGET: u64 = 50_000_000_000;
+
+/// Fails if the transaction with `effects` was not executed successfully.
+///
+/// Lock rejections are reported as [`Error::Locked`], enriched with the unlock time of
+/// the lock of `notarization_id` that caused them (if it can still be looked up).
+pub(crate) async fn ensure_executed(
+    effects: &IotaTransactionBlockEffects,
+    notarization_id: ObjectID,
+    client: &impl CoreClientReadOnly,
+) -> Result<(), Error> {
+    let IotaExecutionStatus::Failure { error } = effects.status() else {
+        return Ok(());
+    };
+
+    match Error::from_execution_failure(error) {
+        Error::Locked { operation, .. } => {
+            let unlock_at = get_object_ref_by_id_with_bcs::<OnChainNotarization>(client, &notarization_id)
+                .await
+                .ok()
+                .and_then(|notarization| notarization.immutable_metadata.locking)
+                .map(|locking| match operation {
+                    LockedOperation::Transfer => locking.transfer_lock,
+                    LockedOperation::Update => locking.update_lock,
+                    LockedOperation::Delete => locking.delete_lock,
+                })
+                .and_then(|lock| match lock {
+                    TimeLock::UnlockAt(unlock_at) => Some(u64::from(unlock_at)),
+                    _ => None,
+                });
+
+            Err(Error::Locked { operation, unlock_at })
+        }
+        error => Err(error),
+    }
+}
+
+/// Returns the first lifecycle event of type `E` in `events` that refers to `notarization_id`.
+pub(crate) fn find_event<E>(events: &IotaTransactionBlockEvents, notarization_id: ObjectID) -> Option<E>
+where
+    E: TryFrom<NotarizationEvent>,
+{
+    events
+        .data
+        .iter()
+        .filter_map(|event| {
+            NotarizationEvent::from_move_event(event.type_.name.as_str(), &event.parsed_json)
+                .ok()
+                .flatten()
+        })
+        .filter(|event| event.notarization_id() == Some(notarization_id))
+        .find_map(|event| E::try_from(event).ok())
+}
+
+/// Checks that `operation` is valid for the notarization `notarization_id`, before anything is built.
+///
+/// Updates (of the state or the metadata) and transfers are never valid for locked notarizations
+/// and fail with [`Error::InvalidArgument`]. Deletion fails with [`Error::Locked`] while the
+/// notarization cannot be destroyed yet.
+pub(crate) async fn precheck(
+    client: &impl CoreClientReadOnly,
+    notarization_id: ObjectID,
+    operation: LockedOperation,
+    clock: ClockObject,
+) -> Result<(), Error> {
+    let notarization = get_object_ref_by_id_with_bcs::<OnChainNotarization>(client, &notarization_id)
+        .await
+        .map_err(|e| Error::ObjectLookup(e.to_string()))?;
+
+    check_operation(client, &notarization, operation, clock).await
+}
+
+/// Runs the checks of [`precheck`] on a `notarization` that was already read.
+pub(crate) async fn check_operation(
+    client: &impl CoreClientReadOnly,
+    notarization: &OnChainNotarization,
+    operation: LockedOperation,
+    clock: ClockObject,
+) -> Result<(), Error> {
+    match (operation, &notarization.method) {
+        (LockedOperation::Update, NotarizationMethod::Locked) => Err(Error::InvalidArgument(
+            "locked notarizations cannot be updated".to_string(),
+        )),
+        (LockedOperation::Transfer, NotarizationMethod::Locked) => Err(Error::InvalidArgument(
+            "locked notarizations cannot be transferred".to_string(),
+        )),
+        (LockedOperation::Delete, _) => {
+            let now = move_utils::get_clock_timestamp_ms(client, clock).await? / 1000;
+            if notarization.lock_status_at(now).is_destroy_allowed {
+                return Ok(());
+            }
+
+            let unlock_at = [
+                notarization.update_lock(),
+                notarization.delete_lock(),
+                notarization.transfer_lock(),
+            ]
+            .into_iter()
+            .filter_map(|lock| match lock {
+                TimeLock::UnlockAt(unlock_at) if u64::from(unlock_at) > now => Some(u64::from(unlock_at)),
+                _ => None,
+            })
+            .max();
+
+            Err(Error::Locked { operation, unlock_at })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Starts `tx` with `gas_budget`, if any; otherwise the budget is estimated when building.
+pub(crate) fn with_default_gas_budget<Tx>(tx: TransactionBuilder<Tx>, gas_budget: Option<u64>) -> TransactionBuilder<Tx>
+where
+    Tx: Transaction,
+{
+    match gas_budget {
+        Some(gas_budget) => tx.with_gas_budget(gas_budget),
+        None => tx,
+    }
+}
+
+/// Builds the programmable transaction of `tx` and encodes it as BCS bytes.
+///
+/// This is the native counterpart of `buildProgrammableTransaction` of the WASM bindings: the
+/// bytes can be handed to a different signing flow, e.g. an external or hardware wallet, which
+/// wraps them into transaction data with its own sender and gas payment. Decode them with
+/// `bcs::from_bytes::<ProgrammableTransaction>`.
+///
+/// The built transaction is cached, so `tx` can still be executed through a
+/// [`TransactionBuilder`](product_common::transaction::transaction_builder::TransactionBuilder)
+/// afterwards; it is executed exactly as encoded.
+///
+/// ## Example
+///
+/// ```rust,ignore
+/// # use notarization::core::transactions::{UpdateState, build_programmable_transaction_bcs};
+/// # use notarization::core::types::State;
+/// let update = UpdateState::new(State::from_string("Status: Completed".to_string(), None), object_id);
+/// let ptb_bytes = build_programmable_transaction_bcs(&update, &client).await?;
+/// hardware_wallet.sign_and_submit(ptb_bytes).await?;
+/// ```
+pub async fn build_programmable_transaction_bcs<Tx, C>(tx: &Tx, client: &C) -> Result<Vec<u8>, Error>
+where
+    Tx: Transaction<Error = Error> + OptionalSync,
+    C: CoreClientReadOnly + OptionalSync,
+{
+    let ptb = tx.build_programmable_transaction(client).await?;
+
+    Ok(bcs::to_bytes(&ptb)?)
+}
+
+/// Dry runs `tx` as the sender of `client` against the current chain state.
+///
+/// The effects are returned as reported by the node, including a failed execution status,
+/// e.g. if a lock or the ownership of the notarization would reject the transaction.
+pub(crate) async fn preview<Tx, C, S>(tx: &Tx, client: &C) -> Result<IotaTransactionBlockEffects, Error>
+where
+    Tx: Transaction<Error = Error> + OptionalSync,
+    C: CoreClient<S> + OptionalSync,
+    S: Signer<IotaKeySignature> + OptionalSync,
+{
+    let ptb = tx.build_programmable_transaction(client).await?;
+
+    let gas_price = client
+        .client_adapter()
+        .read_api()
+        .get_reference_gas_price()
+        .await
+        .map_err(|err| Error::RpcError(format!("Failed to get the reference gas price: {err}")))?;
+    let tx_data =
+        TransactionData::new_programmable(client.sender_address(), vec![], ptb, PREVIEW_GAS_BUDGET, gas_price);
+
+    let response = client
+        .client_adapter()
+        .read_api()
+        .dry_run_transaction_block(tx_data)
+        .await
+        .map_err(|err| Error::UnexpectedApiResponse(format!("Failed to dry run transaction block: {err}")))?;
+
+    Ok(response.effects)
+}
@@ -12,21 +12,33 @@
 //! Note that this transaction is only available for dynamic notarizations.
 
 use async_trait::async_trait;
-use iota_interaction::OptionalSync;
 use iota_interaction::rpc_types::IotaTransactionBlockEffects;
 use iota_interaction::types::base_types::{IotaAddress, ObjectID};
 use iota_interaction::types::transaction::ProgrammableTransaction;
-use product_common::core_client::CoreClientReadOnly;
+use iota_interaction::{IotaKeySignature, OptionalSync};
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
 use product_common::transaction::transaction_builder::Transaction;
+use secret_storage::Signer;
 use tokio::sync::OnceCell;
 
+use super::super::move_utils::ClockObject;
 use super::super::operations::{NotarizationImpl, NotarizationOperations};
-use crate::error::Error;
+use super::super::types::metadata::ensure_metadata_max_size;
+use super::super::types::{DEFAULT_MAX_METADATA_BYTES, NotarizationTransferred};
+use super::{ensure_executed, precheck, preview};
+use crate::error::{Error, LockedOperation};
 
 /// A transaction that transfers ownership of a dynamic notarization.
+///
+/// Transfers to [`IotaAddress::ZERO`] and, if the sender is known, to the sender itself are
+/// rejected with [`Error::InvalidArgument`] when the transaction is built.
 pub struct TransferNotarization {
     recipient: IotaAddress,
     notarization_id: ObjectID,
+    sender: Option<IotaAddress>,
+    reason: Option<String>,
+    precheck: bool,
+    clock: ClockObject,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -36,15 +48,100 @@ impl TransferNotarization {
         Self {
             recipient,
             notarization_id,
+            sender: None,
+            reason: None,
+            precheck: false,
+            clock: ClockObject::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Sets the address of the current owner, so that a transfer to the owner itself is rejected.
+    pub fn with_sender(mut self, sender: IotaAddress) -> Self {
+        self.sender = Some(sender);
+        self
+    }
+
+    /// Records `reason` (e.g. an audit note) as the updatable metadata of the notarization.
+    ///
+    /// The contract has no dedicated field for a transfer reason, so the updatable metadata is
+    /// replaced with `reason` in the same transaction as the transfer: either both land or
+    /// neither does. The reason is subject to [`DEFAULT_MAX_METADATA_BYTES`].
+    pub fn with_reason(mut self, reason: String) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    /// Checks that the transfer is valid for the notarization before the transaction is built
+    /// (default: `false`).
+    ///
+    /// The check costs an additional read of the notarization, but fails fast instead of
+    /// spending gas on a transaction that is rejected on-chain.
+    ///
+    /// ## Errors
+    ///
+    /// Building the transaction fails with [`Error::InvalidArgument`] for a locked notarization.
+    pub fn with_precheck(mut self, precheck: bool) -> Self {
+        self.precheck = precheck;
+        self
+    }
+
+    /// Sets the clock object the transaction reads the time from (default: the system clock).
+    pub(crate) fn with_clock(mut self, clock: ClockObject) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Checks that the recipient is neither the zero address nor the sender.
+    fn validate_recipient(&self) -> Result<(), Error> {
+        if self.recipient == IotaAddress::ZERO {
+            return Err(Error::InvalidArgument(
+                "cannot transfer a notarization to the zero address".to_string(),
+            ));
+        }
+        if self.sender == Some(self.recipient) {
+            return Err(Error::InvalidArgument(format!(
+                "cannot transfer a notarization to its current owner {}",
+                self.recipient
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Simulates the transfer against the current chain state, without submitting it.
+    ///
+    /// The returned effects carry the execution status: a failure indicates that the transfer
+    /// would be rejected, e.g. because of an active transfer lock or because the sender of
+    /// `client` does not own the notarization. The built transaction is cached, so the
+    /// transaction can still be executed afterwards.
+    pub async fn build_and_preview<C, S>(&self, client: &C) -> Result<IotaTransactionBlockEffects, Error>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        preview(self, client).await
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "transfer_notarization", skip_all, fields(notarization_id = %self.notarization_id, recipient = %self.recipient), err))]
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::transfer_notarization(self.notarization_id, self.recipient, client).await
+        self.validate_recipient()?;
+        ensure_metadata_max_size("transfer reason", self.reason.as_deref(), DEFAULT_MAX_METADATA_BYTES)?;
+        if self.precheck {
+            precheck(client, self.notarization_id, LockedOperation::Transfer, self.clock).await?;
+        }
+
+        NotarizationImpl::transfer_notarization(
+            self.notarization_id,
+            self.recipient,
+            self.reason.clone(),
+            self.clock,
+            client,
+        )
+        .await
     }
 }
 
@@ -53,7 +150,7 @@ impl TransferNotarization {
 impl Transaction for TransferNotarization {
     type Error = Error;
 
-    type Output = ();
+    type Output = NotarizationTransferred;
 
     async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
     where
@@ -62,10 +159,40 @@ impl Transaction for TransferNotarization {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        ensure_executed(effects, self.notarization_id, client).await?;
+
+        Ok(NotarizationTransferred {
+            notarization_id: self.notarization_id,
+            recipient: self.recipient,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rejects_invalid_recipients() {
+        let notarization_id = ObjectID::from_single_byte(1);
+        let owner = IotaAddress::from(ObjectID::from_single_byte(2));
+        let recipient = IotaAddress::from(ObjectID::from_single_byte(3));
+
+        let to_zero = TransferNotarization::new(IotaAddress::ZERO, notarization_id);
+        assert!(matches!(to_zero.validate_recipient(), Err(Error::InvalidArgument(_))));
+
+        let to_self = TransferNotarization::new(owner, notarization_id).with_sender(owner);
+        assert!(matches!(to_self.validate_recipient(), Err(Error::InvalidArgument(_))));
+
+        let valid = TransferNotarization::new(recipient, notarization_id).with_sender(owner);
+        assert!(valid.validate_recipient().is_ok());
     }
 }
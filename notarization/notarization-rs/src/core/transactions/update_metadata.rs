@@ -10,22 +10,27 @@
 //! The metadata is used to store the metadata for a notarization.
 
 use async_trait::async_trait;
-use iota_interaction::OptionalSync;
 use iota_interaction::rpc_types::IotaTransactionBlockEffects;
 use iota_interaction::types::base_types::ObjectID;
 use iota_interaction::types::transaction::ProgrammableTransaction;
-use product_common::core_client::CoreClientReadOnly;
+use iota_interaction::{IotaKeySignature, OptionalSync};
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
 use product_common::transaction::transaction_builder::Transaction;
+use secret_storage::Signer;
 use tokio::sync::OnceCell;
 
+use super::super::move_utils::ClockObject;
 use super::super::operations::{NotarizationImpl, NotarizationOperations};
-use crate::error::Error;
+use super::{ensure_executed, precheck, preview};
+use crate::error::{Error, LockedOperation};
 
 /// A transaction that updates the metadata of a notarization.
 pub struct UpdateMetadata {
     metadata: Option<String>,
     /// The ID of the notarization to update
     notarization_id: ObjectID,
+    precheck: bool,
+    clock: ClockObject,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -35,17 +40,57 @@ impl UpdateMetadata {
         Self {
             metadata,
             notarization_id,
+            precheck: false,
+            clock: ClockObject::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Checks that the update is valid for the notarization before the transaction is built
+    /// (default: `false`).
+    ///
+    /// The check costs an additional read of the notarization, but fails fast instead of
+    /// spending gas on a transaction that is rejected on-chain.
+    ///
+    /// ## Errors
+    ///
+    /// Building the transaction fails with [`Error::InvalidArgument`] for a locked notarization.
+    pub fn with_precheck(mut self, precheck: bool) -> Self {
+        self.precheck = precheck;
+        self
+    }
+
+    /// Sets the clock object the transaction reads the time from (default: the system clock).
+    pub(crate) fn with_clock(mut self, clock: ClockObject) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Simulates the metadata update against the current chain state, without submitting it.
+    ///
+    /// The returned effects carry the execution status: a failure indicates that the update
+    /// would be rejected, e.g. for a locked notarization. The built transaction is cached, so
+    /// the transaction can still be executed afterwards.
+    pub async fn build_and_preview<C, S>(&self, client: &C) -> Result<IotaTransactionBlockEffects, Error>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        preview(self, client).await
+    }
+
     /// Builds the programmable transaction for updating the metadata of a
     /// notarization.
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "update_metadata", skip_all, fields(notarization_id = %self.notarization_id), err))]
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        NotarizationImpl::update_metadata(client, self.notarization_id, self.metadata.clone()).await
+        if self.precheck {
+            precheck(client, self.notarization_id, LockedOperation::Update, self.clock).await?;
+        }
+
+        NotarizationImpl::update_metadata(client, self.notarization_id, self.metadata.clone(), self.clock).await
     }
 }
 
@@ -63,10 +108,14 @@ impl Transaction for UpdateMetadata {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
-    async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
+    async fn apply<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        ensure_executed(effects, self.notarization_id, client).await
     }
 }
@@ -12,17 +12,32 @@
 //! Note that this transaction is only available for dynamic notarizations.
 
 use async_trait::async_trait;
-use iota_interaction::OptionalSync;
-use iota_interaction::rpc_types::IotaTransactionBlockEffects;
+use iota_interaction::rpc_types::{IotaTransactionBlockEffects, IotaTransactionBlockEvents};
 use iota_interaction::types::base_types::ObjectID;
 use iota_interaction::types::transaction::ProgrammableTransaction;
-use product_common::core_client::CoreClientReadOnly;
+use iota_interaction::{IotaKeySignature, OptionalSync};
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
 use product_common::transaction::transaction_builder::Transaction;
+use secret_storage::Signer;
 use tokio::sync::OnceCell;
 
+use super::super::move_utils::{self, ClockObject};
 use super::super::operations::{NotarizationImpl, NotarizationOperations};
-use super::super::types::State;
-use crate::error::Error;
+use super::super::types::{DEFAULT_MAX_STATE_BYTES, NotarizationEvent, OnChainNotarization, State, StateUpdated};
+use super::{check_operation, ensure_executed, precheck, preview};
+use crate::error::{Error, LockedOperation};
+
+/// The outcome of an [`UpdateState`] transaction, taken from its `NotarizationUpdated` event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpdatedState {
+    /// The state version count after the update.
+    pub new_version: u64,
+    /// The timestamp of the checkpoint that included the update, in milliseconds since the Unix
+    /// epoch, if reported by the node.
+    ///
+    /// It can differ slightly from the `last_state_change_at` recorded by the on-chain clock.
+    pub timestamp_ms: Option<u64>,
+}
 
 /// A transaction that updates the state of an existing notarization.
 ///
@@ -50,6 +65,10 @@ use crate::error::Error;
 pub struct UpdateState {
     state: State,
     object_id: ObjectID,
+    expected_version: Option<u64>,
+    max_state_bytes: usize,
+    precheck: bool,
+    clock: ClockObject,
     cached_ptb: OnceCell<ProgrammableTransaction>,
 }
 
@@ -64,17 +83,103 @@ impl UpdateState {
         Self {
             state,
             object_id,
+            expected_version: None,
+            max_state_bytes: DEFAULT_MAX_STATE_BYTES,
+            precheck: false,
+            clock: ClockObject::default(),
             cached_ptb: OnceCell::new(),
         }
     }
 
+    /// Only applies the update if the notarization is still at `expected_version`.
+    ///
+    /// The state version count is checked when the transaction is built, and the transaction
+    /// is bound to the object version that was checked: if the notarization is updated in
+    /// between, the transaction fails on-chain instead of overwriting the other update.
+    ///
+    /// ## Errors
+    ///
+    /// Building the transaction fails with [`Error::VersionConflict`] if the current state
+    /// version count differs from `expected_version`.
+    pub fn with_expected_version(mut self, expected_version: u64) -> Self {
+        self.expected_version = Some(expected_version);
+        self
+    }
+
+    /// Sets the maximum size of the new state data in bytes (default: [`DEFAULT_MAX_STATE_BYTES`]),
+    /// including its ULEB128 length prefix.
+    ///
+    /// ## Errors
+    ///
+    /// Building the transaction fails with [`Error::InvalidArgument`] if the state exceeds the
+    /// maximum, before anything is submitted.
+    pub fn with_max_state_bytes(mut self, max_state_bytes: usize) -> Self {
+        self.max_state_bytes = max_state_bytes;
+        self
+    }
+
+    /// Checks that the update is valid for the notarization before the transaction is built
+    /// (default: `false`).
+    ///
+    /// The check costs an additional read of the notarization, but fails fast instead of
+    /// spending gas on a transaction that is rejected on-chain.
+    ///
+    /// ## Errors
+    ///
+    /// Building the transaction fails with [`Error::InvalidArgument`] for a locked notarization.
+    pub fn with_precheck(mut self, precheck: bool) -> Self {
+        self.precheck = precheck;
+        self
+    }
+
+    /// Sets the clock object the transaction reads the time from (default: the system clock).
+    pub(crate) fn with_clock(mut self, clock: ClockObject) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Simulates the state update against the current chain state, without submitting it.
+    ///
+    /// The returned effects carry the execution status: a failure indicates that the update
+    /// would be rejected, e.g. because the notarization is update locked or not owned by the
+    /// sender of `client`. The built transaction is cached, so the transaction can still be
+    /// executed afterwards.
+    pub async fn build_and_preview<C, S>(&self, client: &C) -> Result<IotaTransactionBlockEffects, Error>
+    where
+        C: CoreClient<S> + OptionalSync,
+        S: Signer<IotaKeySignature> + OptionalSync,
+    {
+        preview(self, client).await
+    }
+
+    #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", name = "update_state", skip_all, fields(notarization_id = %self.object_id), err))]
     async fn make_ptb<C>(&self, client: &C) -> Result<ProgrammableTransaction, Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        let new_state = self.state.clone();
+        self.state.ensure_max_size(self.max_state_bytes)?;
+
+        let Some(expected) = self.expected_version else {
+            if self.precheck {
+                precheck(client, self.object_id, LockedOperation::Update, self.clock).await?;
+            }
+            return NotarizationImpl::update_state(client, self.object_id, &self.state, self.clock).await;
+        };
 
-        NotarizationImpl::update_state(client, self.object_id, new_state).await
+        // The notarization is read once, for the precheck and for the version check.
+        let (notarization_ref, notarization) =
+            move_utils::get_object_with_ref::<OnChainNotarization>(client, &self.object_id).await?;
+        if self.precheck {
+            check_operation(client, &notarization, LockedOperation::Update, self.clock).await?;
+        }
+        if notarization.state_version_count != expected {
+            return Err(Error::VersionConflict {
+                expected: Some(expected),
+                actual: notarization.state_version_count,
+            });
+        }
+
+        NotarizationImpl::update_state_at(client, notarization_ref, &self.state, self.clock).await
     }
 }
 
@@ -83,7 +188,7 @@ impl UpdateState {
 impl Transaction for UpdateState {
     type Error = Error;
 
-    type Output = ();
+    type Output = UpdatedState;
 
     async fn build_programmable_transaction<C>(&self, client: &C) -> Result<ProgrammableTransaction, Self::Error>
     where
@@ -92,10 +197,46 @@ impl Transaction for UpdateState {
         self.cached_ptb.get_or_try_init(|| self.make_ptb(client)).await.cloned()
     }
 
+    /// Builds the outcome from the effects and the `NotarizationUpdated` event alone, without
+    /// reading the notarization back.
+    async fn apply_with_events<C>(
+        mut self,
+        effects: &mut IotaTransactionBlockEffects,
+        events: &mut IotaTransactionBlockEvents,
+        client: &C,
+    ) -> Result<Self::Output, Self::Error>
+    where
+        C: CoreClientReadOnly + OptionalSync,
+    {
+        ensure_executed(effects, self.object_id, client).await?;
+
+        events
+            .data
+            .iter()
+            .find_map(|event| {
+                let updated = NotarizationEvent::from_move_event(event.type_.name.as_str(), &event.parsed_json)
+                    .ok()
+                    .flatten()
+                    .and_then(|event| StateUpdated::try_from(event).ok())
+                    .filter(|updated| updated.notarization_id == self.object_id)?;
+
+                Some(UpdatedState {
+                    new_version: updated.state_version_count,
+                    timestamp_ms: event.timestamp_ms,
+                })
+            })
+            .ok_or_else(|| {
+                Error::TransactionUnexpectedResponse(format!(
+                    "missing NotarizationUpdated event for notarization {}",
+                    self.object_id
+                ))
+            })
+    }
+
     async fn apply<C>(mut self, _: &mut IotaTransactionBlockEffects, _: &C) -> Result<Self::Output, Self::Error>
     where
         C: CoreClientReadOnly + OptionalSync,
     {
-        Ok(())
+        unreachable!()
     }
 }
@@ -0,0 +1,141 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Cache for the type tags of notarization objects.
+//!
+//! Resolving the type tag of a notarization requires an extra RPC call. As the type of an
+//! object never changes, every [`NotarizationClientReadOnly`](crate::client::NotarizationClientReadOnly)
+//! caches the tags it resolved per [`ObjectID`].
+
+use std::collections::HashMap;
+use std::time::Duration;
+#[cfg(not(target_arch = "wasm32"))]
+use std::time::Instant;
+
+use iota_interaction::types::TypeTag;
+use iota_interaction::types::base_types::ObjectID;
+
+/// The default time a resolved type tag is kept.
+pub(crate) const DEFAULT_TTL: Duration = Duration::from_secs(600);
+
+/// The maximum number of cached type tags.
+const MAX_ENTRIES: usize = 1024;
+
+/// A cached type tag.
+#[derive(Debug, Clone)]
+struct Entry {
+    tag: TypeTag,
+    #[cfg(not(target_arch = "wasm32"))]
+    inserted_at: Instant,
+}
+
+impl Entry {
+    fn new(tag: TypeTag) -> Self {
+        Self {
+            tag,
+            #[cfg(not(target_arch = "wasm32"))]
+            inserted_at: Instant::now(),
+        }
+    }
+
+    /// Indicates if the entry outlived `ttl`.
+    ///
+    /// There is no monotonic clock on `wasm32`, where entries only expire if `ttl` is zero.
+    fn is_expired(&self, ttl: Duration) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.inserted_at.elapsed() >= ttl
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            ttl.is_zero()
+        }
+    }
+}
+
+/// A TTL bound cache of type tags keyed by [`ObjectID`].
+#[derive(Debug)]
+pub(crate) struct TypeTagCache {
+    ttl: Duration,
+    entries: HashMap<ObjectID, Entry>,
+}
+
+impl TypeTagCache {
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Returns the cached type tag of `object_id`, if it is cached and not expired.
+    pub(crate) fn get(&self, object_id: &ObjectID) -> Option<TypeTag> {
+        self.entries
+            .get(object_id)
+            .filter(|entry| !entry.is_expired(self.ttl))
+            .map(|entry| entry.tag.clone())
+    }
+
+    /// Caches the type tag of `object_id`. Nothing is cached if the TTL is zero.
+    pub(crate) fn insert(&mut self, object_id: ObjectID, tag: TypeTag) {
+        if self.ttl.is_zero() {
+            return;
+        }
+
+        if self.entries.len() >= MAX_ENTRIES {
+            let ttl = self.ttl;
+            self.entries.retain(|_, entry| !entry.is_expired(ttl));
+        }
+        if self.entries.len() >= MAX_ENTRIES {
+            // Still full of fresh entries; start over rather than tracking recency.
+            self.entries.clear();
+        }
+
+        self.entries.insert(object_id, Entry::new(tag));
+    }
+
+    /// Removes all cached type tags.
+    pub(crate) fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+impl Default for TypeTagCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_TTL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cached_tag_is_reused() {
+        let mut cache = TypeTagCache::new(DEFAULT_TTL);
+        let object_id = ObjectID::from_single_byte(1);
+
+        assert_eq!(cache.get(&object_id), None);
+        cache.insert(object_id, TypeTag::U8);
+        assert_eq!(cache.get(&object_id), Some(TypeTag::U8));
+    }
+
+    #[test]
+    fn test_expired_tag_is_ignored() {
+        let mut cache = TypeTagCache::new(Duration::from_millis(1));
+        let object_id = ObjectID::from_single_byte(1);
+
+        cache.insert(object_id, TypeTag::U8);
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(cache.get(&object_id), None);
+    }
+
+    #[test]
+    fn test_zero_ttl_disables_cache() {
+        let mut cache = TypeTagCache::new(Duration::ZERO);
+        let object_id = ObjectID::from_single_byte(1);
+
+        cache.insert(object_id, TypeTag::U8);
+        assert!(cache.entries.is_empty());
+    }
+}
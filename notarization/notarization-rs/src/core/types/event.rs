@@ -1,8 +1,16 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::TypeTag;
+use iota_interaction::types::base_types::{IotaAddress, ObjectID};
+use iota_interaction::types::digests::TransactionDigest;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Data, NotarizationMethod, State};
+use crate::error::Error;
+
 /// An event emitted by notarization operations.
 ///
 /// Generic wrapper for different event data types.
@@ -23,3 +31,334 @@ pub(crate) struct DynamicNotarizationCreated {
 pub(crate) struct LockedNotarizationCreated {
     pub notarization_id: ObjectID,
 }
+
+/// An event that is emitted when the state of a dynamic notarization is updated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateUpdated {
+    /// The ID of the updated notarization.
+    pub notarization_id: ObjectID,
+    /// The state version count after the update.
+    pub state_version_count: u64,
+}
+
+/// The outcome of a transfer of a dynamic notarization to a new owner.
+///
+/// Unlike the other outcomes, it is not taken from an event: the `DynamicNotarizationTransferred`
+/// event of the contract does not identify the transferred notarization (see
+/// [`NotarizationEvent::Transferred`]), so the outcome is built from the inputs of the executed
+/// transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotarizationTransferred {
+    /// The ID of the transferred notarization.
+    pub notarization_id: ObjectID,
+    /// The address of the new owner.
+    pub recipient: IotaAddress,
+}
+
+/// An event that is emitted when a notarization is destroyed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotarizationDestroyed {
+    /// The ID of the destroyed notarization.
+    pub notarization_id: ObjectID,
+}
+
+/// The names of the Move modules that emit notarization lifecycle events.
+pub(crate) const EVENT_MODULES: [&str; 3] = ["notarization", "dynamic_notarization", "locked_notarization"];
+
+/// A lifecycle event of a notarization, as observed on-chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NotarizationEvent {
+    /// A notarization was created.
+    Created {
+        notarization_id: ObjectID,
+        method: NotarizationMethod,
+    },
+    /// The state of a notarization was updated.
+    StateUpdated {
+        notarization_id: ObjectID,
+        /// The state version count after the update.
+        state_version_count: u64,
+    },
+    /// A dynamic notarization was transferred to a new owner.
+    ///
+    /// The on-chain event does not identify the transferred notarization, only its new owner.
+    Transferred { recipient: IotaAddress },
+    /// A notarization was destroyed.
+    Destroyed { notarization_id: ObjectID },
+}
+
+impl NotarizationEvent {
+    /// Returns the [`ObjectID`] of the notarization the event refers to, or `None` for
+    /// [`NotarizationEvent::Transferred`], which does not carry it.
+    pub fn notarization_id(&self) -> Option<ObjectID> {
+        match self {
+            Self::Created { notarization_id, .. }
+            | Self::StateUpdated { notarization_id, .. }
+            | Self::Destroyed { notarization_id } => Some(*notarization_id),
+            Self::Transferred { .. } => None,
+        }
+    }
+
+    /// Parses a Move event, given the name of its struct and its JSON rendering.
+    ///
+    /// Returns `Ok(None)` for events that are not notarization lifecycle events.
+    pub(crate) fn from_move_event(name: &str, parsed_json: &Value) -> Result<Option<Self>, Error> {
+        let event = match name {
+            "DynamicNotarizationCreated" => Self::Created {
+                notarization_id: parse_field(parsed_json, "notarization_id")?,
+                method: NotarizationMethod::Dynamic,
+            },
+            "LockedNotarizationCreated" => Self::Created {
+                notarization_id: parse_field(parsed_json, "notarization_id")?,
+                method: NotarizationMethod::Locked,
+            },
+            "NotarizationUpdated" => {
+                // Move `u64`s are rendered as JSON strings.
+                let state_version_count = parse_field::<String>(parsed_json, "state_version_count")?
+                    .parse()
+                    .map_err(|e| Error::UnexpectedApiResponse(format!("invalid state_version_count in event: {e}")))?;
+                Self::StateUpdated {
+                    notarization_id: parse_field(parsed_json, "notarization_id")?,
+                    state_version_count,
+                }
+            }
+            // The `notarization_id` of the Move event holds the recipient, not the notarization.
+            "DynamicNotarizationTransferred" => Self::Transferred {
+                recipient: parse_field(parsed_json, "recipient")?,
+            },
+            "NotarizationDestroyed" => Self::Destroyed {
+                notarization_id: parse_field(parsed_json, "notarization_id")?,
+            },
+            _ => return Ok(None),
+        };
+
+        Ok(Some(event))
+    }
+}
+
+impl TryFrom<NotarizationEvent> for StateUpdated {
+    type Error = NotarizationEvent;
+
+    /// Converts a [`NotarizationEvent::StateUpdated`], returning any other event as error.
+    fn try_from(event: NotarizationEvent) -> Result<Self, Self::Error> {
+        match event {
+            NotarizationEvent::StateUpdated {
+                notarization_id,
+                state_version_count,
+            } => Ok(Self {
+                notarization_id,
+                state_version_count,
+            }),
+            event => Err(event),
+        }
+    }
+}
+
+impl TryFrom<NotarizationEvent> for NotarizationDestroyed {
+    type Error = NotarizationEvent;
+
+    /// Converts a [`NotarizationEvent::Destroyed`], returning any other event as error.
+    fn try_from(event: NotarizationEvent) -> Result<Self, Self::Error> {
+        match event {
+            NotarizationEvent::Destroyed { notarization_id } => Ok(Self { notarization_id }),
+            event => Err(event),
+        }
+    }
+}
+
+/// A past state of a notarization, reconstructed from the `NotarizationUpdated` event of the
+/// update that set it.
+///
+/// The update events carry the complete new state, so the data and metadata of every version
+/// set by an update can be recovered. The initial state (version `0`) is not part of any event
+/// and cannot be recovered once it was replaced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateVersion {
+    /// The state version count after the update, starting at `1` for the first update.
+    pub version: u64,
+    /// The state set by the update.
+    pub state: State,
+    /// The [content hash](State::content_hash) of the state.
+    pub content_hash: [u8; 32],
+    /// The timestamp of the checkpoint that included the update, in milliseconds since the Unix
+    /// epoch, if reported by the node.
+    ///
+    /// It can differ slightly from the `last_state_change_at` recorded by the on-chain clock.
+    pub timestamp_ms: Option<u64>,
+    /// The digest of the transaction that performed the update.
+    pub transaction_digest: TransactionDigest,
+}
+
+impl StateVersion {
+    /// Parses the JSON rendering of a `NotarizationUpdated<D>` event, where `data_tag` is the
+    /// Move type `D` of the state data.
+    ///
+    /// Returns the [`ObjectID`] of the updated notarization along with the state version.
+    pub(crate) fn from_updated_event(
+        parsed_json: &Value,
+        data_tag: &TypeTag,
+        timestamp_ms: Option<u64>,
+        transaction_digest: TransactionDigest,
+    ) -> Result<(ObjectID, Self), Error> {
+        let notarization_id = parse_field(parsed_json, "notarization_id")?;
+        // Move `u64`s are rendered as JSON strings.
+        let version = parse_field::<String>(parsed_json, "state_version_count")?
+            .parse()
+            .map_err(|e| Error::UnexpectedApiResponse(format!("invalid state_version_count in event: {e}")))?;
+
+        let updated_state = parse_field::<Value>(parsed_json, "updated_state")?;
+        let data = match parse_field::<Value>(&updated_state, "data")? {
+            Value::String(text) => Data::Text(text),
+            bytes => Data::Bytes(
+                serde_json::from_value(bytes)
+                    .map_err(|e| Error::UnexpectedApiResponse(format!("invalid state data in event: {e}")))?,
+            ),
+        };
+        let state = State {
+            data: data.with_type_tag(data_tag),
+            metadata: parse_field(&updated_state, "metadata")?,
+        };
+
+        Ok((
+            notarization_id,
+            Self {
+                version,
+                content_hash: state.content_hash(),
+                state,
+                timestamp_ms,
+                transaction_digest,
+            },
+        ))
+    }
+}
+
+fn parse_field<T: DeserializeOwned>(parsed_json: &Value, field: &str) -> Result<T, Error> {
+    let value = parsed_json
+        .get(field)
+        .ok_or_else(|| Error::UnexpectedApiResponse(format!("event is missing field '{field}'")))?;
+    serde_json::from_value(value.clone())
+        .map_err(|e| Error::UnexpectedApiResponse(format!("invalid field '{field}' in event: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_lifecycle_events() {
+        let id = ObjectID::from_single_byte(1);
+        let recipient = IotaAddress::ZERO;
+
+        let created =
+            NotarizationEvent::from_move_event("LockedNotarizationCreated", &json!({ "notarization_id": id }));
+        assert_eq!(
+            created.unwrap(),
+            Some(NotarizationEvent::Created {
+                notarization_id: id,
+                method: NotarizationMethod::Locked,
+            })
+        );
+
+        let updated = NotarizationEvent::from_move_event(
+            "NotarizationUpdated",
+            &json!({ "notarization_id": id, "state_version_count": "2", "updated_state": {} }),
+        );
+        assert_eq!(
+            updated.unwrap(),
+            Some(NotarizationEvent::StateUpdated {
+                notarization_id: id,
+                state_version_count: 2,
+            })
+        );
+
+        let transferred = NotarizationEvent::from_move_event(
+            "DynamicNotarizationTransferred",
+            &json!({ "notarization_id": recipient, "recipient": recipient }),
+        );
+        let transferred = transferred.unwrap().unwrap();
+        assert_eq!(transferred, NotarizationEvent::Transferred { recipient });
+        assert_eq!(transferred.notarization_id(), None);
+
+        let destroyed = NotarizationEvent::from_move_event("NotarizationDestroyed", &json!({ "notarization_id": id }));
+        assert_eq!(destroyed.unwrap().and_then(|event| event.notarization_id()), Some(id));
+    }
+
+    #[test]
+    fn test_typed_lifecycle_events() {
+        let id = ObjectID::from_single_byte(1);
+        let recipient = IotaAddress::ZERO;
+
+        let updated = NotarizationEvent::StateUpdated {
+            notarization_id: id,
+            state_version_count: 2,
+        };
+        assert_eq!(
+            StateUpdated::try_from(updated.clone()),
+            Ok(StateUpdated {
+                notarization_id: id,
+                state_version_count: 2,
+            })
+        );
+        assert_eq!(NotarizationDestroyed::try_from(updated.clone()), Err(updated));
+
+        let transferred = NotarizationEvent::Transferred { recipient };
+        assert_eq!(NotarizationDestroyed::try_from(transferred.clone()), Err(transferred));
+
+        let destroyed = NotarizationEvent::Destroyed { notarization_id: id };
+        assert_eq!(
+            NotarizationDestroyed::try_from(destroyed),
+            Ok(NotarizationDestroyed { notarization_id: id })
+        );
+    }
+
+    #[test]
+    fn test_unrelated_and_malformed_events() {
+        assert_eq!(
+            NotarizationEvent::from_move_event("CoinMinted", &json!({})).unwrap(),
+            None
+        );
+        assert!(NotarizationEvent::from_move_event("NotarizationDestroyed", &json!({})).is_err());
+    }
+
+    #[test]
+    fn test_parse_state_version() {
+        let id = ObjectID::from_single_byte(1);
+        let digest = TransactionDigest::new([0; 32]);
+        let text_tag = Data::Text(String::new()).tag();
+
+        let (notarization_id, version) = StateVersion::from_updated_event(
+            &json!({
+                "notarization_id": id,
+                "state_version_count": "3",
+                "updated_state": { "data": "café", "metadata": "v3" },
+            }),
+            &text_tag,
+            Some(1_700_000_000_000),
+            digest,
+        )
+        .unwrap();
+        assert_eq!(notarization_id, id);
+        assert_eq!(version.version, 3);
+        assert_eq!(
+            version.state,
+            State::from_string("café".to_string(), Some("v3".to_string()))
+        );
+        assert_eq!(version.content_hash, version.state.content_hash());
+
+        let (_, version) = StateVersion::from_updated_event(
+            &json!({
+                "notarization_id": id,
+                "state_version_count": "1",
+                "updated_state": { "data": [1, 2, 3], "metadata": null },
+            }),
+            &Data::Bytes(vec![]).tag(),
+            None,
+            digest,
+        )
+        .unwrap();
+        assert_eq!(version.state, State::from_bytes(vec![1, 2, 3], None));
+    }
+}
@@ -0,0 +1,27 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Notarization fixtures shared by the unit tests.
+
+use iota_interaction::types::base_types::ObjectID;
+use iota_interaction::types::id::UID;
+
+use super::{ImmutableMetadata, LockMetadata, NotarizationMethod, OnChainNotarization, State};
+
+/// Returns a notarization `0x1` with the text state `test`, no description and the given
+/// `method` and `locking`; tests adjust the remaining fields as needed.
+pub(crate) fn notarization(method: NotarizationMethod, locking: Option<LockMetadata>) -> OnChainNotarization {
+    OnChainNotarization {
+        id: UID::new(ObjectID::from_single_byte(1)),
+        state: State::from_string("test".to_string(), None),
+        immutable_metadata: ImmutableMetadata {
+            created_at: 0,
+            description: None,
+            locking,
+        },
+        updatable_metadata: None,
+        last_state_change_at: 0,
+        state_version_count: 0,
+        method,
+    }
+}
@@ -3,7 +3,21 @@
 
 use serde::{Deserialize, Serialize};
 
+use super::super::move_utils;
 use super::timelock::LockMetadata;
+use crate::error::Error;
+
+/// The default maximum size of the immutable description and of the updatable metadata, in bytes.
+///
+/// Both strings are passed to the contract as optional pure transaction arguments, whose size is
+/// limited to 16 KiB on IOTA. The limit applies to the BCS encoding of the argument, so the option
+/// tag and the ULEB128 length prefix count towards it.
+pub const DEFAULT_MAX_METADATA_BYTES: usize = 16 * 1024;
+
+/// The prefix of the description line that records an auto-destroy intent.
+const AUTO_DESTROY_INTENT_PREFIX: &str = "auto-destroy-after:";
+/// The prefix of the description line that records a dedupe tag.
+const DEDUPE_TAG_PREFIX: &str = "dedupe-tag:";
 
 /// The immutable metadata of a notarization.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -15,3 +29,137 @@ pub struct ImmutableMetadata {
     /// Optional lock metadata for `Notarization`
     pub locking: Option<LockMetadata>,
 }
+
+impl ImmutableMetadata {
+    /// Returns the intended expiry (seconds since the Unix epoch) recorded in the description,
+    /// if the notarization was created with an auto-destroy intent.
+    ///
+    /// The intent is purely informational; whether the notarization can actually be destroyed
+    /// is decided by its locks.
+    pub fn auto_destroy_after(&self) -> Option<u32> {
+        self.description
+            .as_deref()?
+            .lines()
+            .last()?
+            .strip_prefix(AUTO_DESTROY_INTENT_PREFIX)?
+            .parse()
+            .ok()
+    }
+
+    /// Returns the dedupe tag recorded in the description, if the notarization was created with one.
+    ///
+    /// See `NotarizationClient::create_if_absent`.
+    pub fn dedupe_tag(&self) -> Option<&str> {
+        self.description
+            .as_deref()?
+            .lines()
+            .find_map(|line| line.strip_prefix(DEDUPE_TAG_PREFIX))
+    }
+}
+
+/// Appends the dedupe tag `tag` as a line of `description`.
+///
+/// The tag must be a non-empty single line, so that it can be read back unambiguously.
+pub(crate) fn record_dedupe_tag(description: Option<String>, tag: &str) -> Result<String, Error> {
+    if tag.is_empty() || tag.contains(['\n', '\r']) {
+        return Err(Error::InvalidArgument(
+            "a dedupe tag must be a non-empty single line".to_string(),
+        ));
+    }
+
+    let line = format!("{DEDUPE_TAG_PREFIX}{tag}");
+    Ok(match description {
+        Some(description) if !description.is_empty() => format!("{description}\n{line}"),
+        _ => line,
+    })
+}
+
+/// Appends an auto-destroy intent expiring at `expires_at` as the last line of `description`.
+pub(crate) fn record_auto_destroy_intent(description: Option<String>, expires_at: u32) -> String {
+    let intent = format!("{AUTO_DESTROY_INTENT_PREFIX}{expires_at}");
+    match description {
+        Some(description) if !description.is_empty() => format!("{description}\n{intent}"),
+        _ => intent,
+    }
+}
+
+/// Checks that the metadata `field` does not exceed `max_bytes` (measured by the BCS encoding of
+/// the optional argument passed to the contract), so that oversized metadata is rejected before a
+/// transaction is submitted.
+pub(crate) fn ensure_metadata_max_size(field: &str, value: Option<&str>, max_bytes: usize) -> Result<(), Error> {
+    let size = move_utils::pure_size(&value);
+    if size > max_bytes {
+        return Err(Error::InvalidArgument(format!(
+            "{field} is {size} bytes as encoded argument, which exceeds the maximum of {max_bytes} bytes"
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata(description: Option<String>) -> ImmutableMetadata {
+        ImmutableMetadata {
+            created_at: 0,
+            description,
+            locking: None,
+        }
+    }
+
+    #[test]
+    fn test_auto_destroy_intent_round_trip() {
+        let description = record_auto_destroy_intent(Some("Session token".to_string()), 1_000);
+        assert_eq!(description, "Session token\nauto-destroy-after:1000");
+        assert_eq!(metadata(Some(description)).auto_destroy_after(), Some(1_000));
+
+        let description = record_auto_destroy_intent(None, 1_000);
+        assert_eq!(metadata(Some(description)).auto_destroy_after(), Some(1_000));
+    }
+
+    #[test]
+    fn test_auto_destroy_intent_missing() {
+        assert_eq!(metadata(None).auto_destroy_after(), None);
+        assert_eq!(metadata(Some("Contract".to_string())).auto_destroy_after(), None);
+        assert_eq!(
+            metadata(Some("auto-destroy-after:soon".to_string())).auto_destroy_after(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_dedupe_tag_round_trip() {
+        let description = record_dedupe_tag(Some("Invoice".to_string()), "invoice-42").unwrap();
+        assert_eq!(description, "Invoice\ndedupe-tag:invoice-42");
+        assert_eq!(metadata(Some(description.clone())).dedupe_tag(), Some("invoice-42"));
+
+        // The tag is still found when an auto-destroy intent follows it.
+        let description = record_auto_destroy_intent(Some(description), 1_000);
+        assert_eq!(metadata(Some(description)).dedupe_tag(), Some("invoice-42"));
+
+        assert_eq!(metadata(Some("Invoice".to_string())).dedupe_tag(), None);
+    }
+
+    #[test]
+    fn test_dedupe_tag_must_be_a_single_line() {
+        assert!(matches!(record_dedupe_tag(None, ""), Err(Error::InvalidArgument(_))));
+        assert!(matches!(
+            record_dedupe_tag(None, "a\nb"),
+            Err(Error::InvalidArgument(_))
+        ));
+    }
+
+    #[test]
+    fn test_metadata_max_size() {
+        // The option tag of a missing value
+        assert!(ensure_metadata_max_size("description", None, 1).is_ok());
+        // The option tag, the length prefix and five bytes of UTF-8
+        assert!(ensure_metadata_max_size("description", Some("café"), 7).is_ok());
+
+        let error = ensure_metadata_max_size("description", Some("café"), 6).unwrap_err();
+        assert!(matches!(error, Error::InvalidArgument(_)));
+        assert!(error.to_string().contains("7 bytes"));
+    }
+}
@@ -4,17 +4,25 @@
 //! Core data types for notarization.
 
 pub mod event;
+#[cfg(test)]
+pub(crate) mod fixtures;
 pub mod metadata;
 pub mod notarization;
+pub mod reference;
+pub mod schema_check;
 pub mod state;
 pub mod timelock;
+pub mod verification;
 
 pub use event::*;
 pub use metadata::*;
 pub use notarization::*;
+pub use reference::*;
+pub use schema_check::*;
 use serde::{Deserialize, Serialize};
 pub use state::*;
 pub use timelock::*;
+pub use verification::*;
 
 /// Indicates the used Notarization method.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -1,12 +1,22 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::fmt;
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use iota_interaction::rpc_types::IotaRawMoveObject;
+use iota_interaction::types::base_types::ObjectID;
 use iota_interaction::types::id::UID;
+use product_common::network_name::NetworkName;
 use serde::{Deserialize, Serialize};
+use serde_json::{Value, json};
+use sha2::{Digest, Sha256};
 
-use super::NotarizationMethod;
 use super::metadata::ImmutableMetadata;
-use super::state::State;
+use super::state::{State, TaggedState, decode_versioned, encode_versioned};
+use super::{Data, LockMetadata, NotarizationMethod, TimeLock};
+use crate::error::Error;
 
 /// A notarization record stored on the blockchain.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -26,3 +36,706 @@ pub struct OnChainNotarization {
     /// The method of the notarization.
     pub method: NotarizationMethod,
 }
+
+/// [`OnChainNotarization`] with explicitly tagged state data, as used by the cache encoding.
+#[derive(Serialize, Deserialize)]
+struct CachedNotarization {
+    id: UID,
+    state: TaggedState,
+    immutable_metadata: ImmutableMetadata,
+    updatable_metadata: Option<String>,
+    last_state_change_at: u64,
+    state_version_count: u64,
+    method: NotarizationMethod,
+}
+
+/// A page of notarizations, as returned by paginated queries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotarizationPage {
+    /// The notarizations of this page.
+    pub data: Vec<OnChainNotarization>,
+    /// The cursor to pass to the next query to get the following page.
+    pub next_cursor: Option<ObjectID>,
+    /// Indicates if there are more pages after this one.
+    pub has_next_page: bool,
+}
+
+/// Domain separator prepended to the canonical encoding hashed by
+/// [`OnChainNotarization::anchor_fingerprint`].
+const ANCHOR_FINGERPRINT_DOMAIN: &[u8] = b"iota-notarization-anchor-v1";
+
+/// The canonical, BCS encoded input of [`OnChainNotarization::anchor_fingerprint`].
+#[derive(Serialize)]
+struct AnchorFingerprintInput<'a> {
+    network: &'a str,
+    package_id: ObjectID,
+    object_id: ObjectID,
+    content_hash: [u8; 32],
+    created_at: u64,
+}
+
+/// The operations that would currently succeed on a notarization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AvailableActions {
+    /// The state and the updatable metadata can be updated.
+    pub can_update: bool,
+    /// The notarization can be transferred to another owner.
+    pub can_transfer: bool,
+    /// The notarization can be destroyed.
+    pub can_destroy: bool,
+}
+
+/// The lock configuration of a notarization together with its resolved lock flags.
+///
+/// All fields are derived from the same object read and clock reading, so they are
+/// consistent with each other.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockStatus {
+    /// The lock metadata of the notarization, if it has any.
+    pub lock_metadata: Option<LockMetadata>,
+    /// The notarization is locked for updates.
+    pub is_update_locked: bool,
+    /// The notarization is locked for transfers.
+    pub is_transfer_locked: bool,
+    /// The notarization can be destroyed.
+    pub is_destroy_allowed: bool,
+}
+
+impl From<&LockStatus> for AvailableActions {
+    fn from(status: &LockStatus) -> Self {
+        AvailableActions {
+            can_update: !status.is_update_locked,
+            can_transfer: !status.is_transfer_locked,
+            can_destroy: status.is_destroy_allowed,
+        }
+    }
+}
+
+/// A human-facing classification of how well a notarization is protected against changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProtectionStatus {
+    /// Some operations are restricted and none of the restrictions will ever expire.
+    ///
+    /// Permanent restrictions are only lifted by destroying the notarization, which may still be
+    /// allowed; see [`AvailableActions`] for the individual operations.
+    FullyProtected,
+    /// Some operations are restricted until a point in time.
+    TimeLimited {
+        /// The earliest time (seconds since the Unix epoch) at which a restriction expires.
+        expires_at: u32,
+    },
+    /// The notarization can be updated, transferred and destroyed.
+    FreelyMutable,
+}
+
+impl OnChainNotarization {
+    /// Decodes a notarization from its raw Move object.
+    ///
+    /// The type parameter of the object (`Notarization<D>`) decides whether the state data is
+    /// text or bytes, as both share the same BCS encoding.
+    pub(crate) fn from_move_object(object: &IotaRawMoveObject) -> Result<Self, Error> {
+        let mut notarization: Self = object
+            .deserialize()
+            .map_err(|err| Error::ObjectLookup(err.to_string()))?;
+        if let Some(tag) = object.type_.type_params.first() {
+            notarization.state.data = notarization.state.data.with_type_tag(tag);
+        }
+
+        Ok(notarization)
+    }
+
+    /// Computes a stable fingerprint of this notarization, suitable for anchoring it in another
+    /// ledger or a timestamping service.
+    ///
+    /// The fingerprint is the SHA-256 hash of a canonical (BCS) encoding of the network name,
+    /// the notarization package ID, the object ID, the [content hash](State::content_hash) of the
+    /// state and the creation timestamp. It only changes if the notarized content changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `network`: The name of the network the notarization lives on.
+    /// * `package_id`: The [`ObjectID`] of the notarization package that owns the object.
+    pub fn anchor_fingerprint(&self, network: &NetworkName, package_id: ObjectID) -> [u8; 32] {
+        let input = AnchorFingerprintInput {
+            network: network.as_ref(),
+            package_id,
+            object_id: *self.id.object_id(),
+            content_hash: self.state.content_hash(),
+            created_at: self.immutable_metadata.created_at,
+        };
+        let encoded = bcs::to_bytes(&input).expect("fingerprint input is BCS serializable");
+
+        Sha256::new()
+            .chain_update(ANCHOR_FINGERPRINT_DOMAIN)
+            .chain_update(encoded)
+            .finalize()
+            .into()
+    }
+
+    /// Encodes the notarization into a stable, versioned binary representation, e.g. for caching
+    /// it off-chain.
+    ///
+    /// This is not the on-chain BCS layout of the object: the kind of the state data is recorded
+    /// explicitly (see [`State::to_bcs`]), so [`OnChainNotarization::from_bcs`] restores the
+    /// notarization exactly.
+    pub fn to_bcs(&self) -> Result<Vec<u8>, Error> {
+        let notarization = self.clone();
+        encode_versioned(&CachedNotarization {
+            id: notarization.id,
+            state: notarization.state.into(),
+            immutable_metadata: notarization.immutable_metadata,
+            updatable_metadata: notarization.updatable_metadata,
+            last_state_change_at: notarization.last_state_change_at,
+            state_version_count: notarization.state_version_count,
+            method: notarization.method,
+        })
+    }
+
+    /// Decodes a notarization encoded with [`OnChainNotarization::to_bcs`].
+    pub fn from_bcs(bytes: &[u8]) -> Result<Self, Error> {
+        let notarization = decode_versioned::<CachedNotarization>(bytes)?;
+
+        Ok(Self {
+            id: notarization.id,
+            state: notarization.state.into(),
+            immutable_metadata: notarization.immutable_metadata,
+            updatable_metadata: notarization.updatable_metadata,
+            last_state_change_at: notarization.last_state_change_at,
+            state_version_count: notarization.state_version_count,
+            method: notarization.method,
+        })
+    }
+
+    /// Returns the update lock of the notarization, or [`TimeLock::None`] if it has no lock metadata.
+    pub fn update_lock(&self) -> TimeLock {
+        self.lock(|locking| &locking.update_lock)
+    }
+
+    /// Returns the delete lock of the notarization, or [`TimeLock::None`] if it has no lock metadata.
+    pub fn delete_lock(&self) -> TimeLock {
+        self.lock(|locking| &locking.delete_lock)
+    }
+
+    /// Returns the transfer lock of the notarization, or [`TimeLock::None`] if it has no lock metadata.
+    pub fn transfer_lock(&self) -> TimeLock {
+        self.lock(|locking| &locking.transfer_lock)
+    }
+
+    fn lock(&self, select: impl FnOnce(&LockMetadata) -> &TimeLock) -> TimeLock {
+        self.immutable_metadata
+            .locking
+            .as_ref()
+            .map_or(TimeLock::None, |locking| select(locking).clone())
+    }
+
+    /// Resolves the [`LockStatus`] at `now` (seconds since the Unix epoch), without any network
+    /// access.
+    ///
+    /// Defers to [`LockMetadata::evaluate`], which mirrors the lock checks of the Move contract.
+    /// Convert the result with `AvailableActions::from` for the [`AvailableActions`].
+    pub fn lock_status_at(&self, now: u64) -> LockStatus {
+        match &self.immutable_metadata.locking {
+            Some(locking) => locking.evaluate(now),
+            // Only dynamic notarizations are created without lock metadata; locked ones are
+            // never updatable.
+            None => LockStatus {
+                lock_metadata: None,
+                is_update_locked: self.method == NotarizationMethod::Locked,
+                is_transfer_locked: false,
+                is_destroy_allowed: true,
+            },
+        }
+    }
+
+    /// Indicates if the notarization is locked for updates at `now` (seconds since the Unix
+    /// epoch); see [`Self::lock_status_at`].
+    pub fn is_update_locked_at(&self, now: u64) -> bool {
+        self.lock_status_at(now).is_update_locked
+    }
+
+    /// Indicates if the notarization is locked for transfers at `now` (seconds since the Unix
+    /// epoch); see [`Self::lock_status_at`].
+    pub fn is_transfer_locked_at(&self, now: u64) -> bool {
+        self.lock_status_at(now).is_transfer_locked
+    }
+
+    /// Indicates if the notarization can be destroyed at `now` (seconds since the Unix epoch);
+    /// see [`Self::lock_status_at`].
+    pub fn is_destroy_allowed_at(&self, now: u64) -> bool {
+        self.lock_status_at(now).is_destroy_allowed
+    }
+
+    /// Converts the notarization into a JSON object for frontends, with its locks resolved at
+    /// `now` (seconds since the Unix epoch).
+    ///
+    /// Unlike the serde representation, the object uses camelCase keys and:
+    /// - renders the state data as text or base64, as told by its `encoding` (`"utf8"` or `"base64"`),
+    /// - renders timestamps as ISO-8601 strings in UTC,
+    /// - renders each lock as `"none"`, `"untilDestroyed"` or `{ "unlockAt": <ISO-8601> }`,
+    /// - adds the resolved `updateLocked`, `transferLocked` and `destroyAllowed` flags.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    /// let body = notarization.to_json(now);
+    /// assert_eq!(body["state"]["encoding"], "utf8");
+    /// ```
+    pub fn to_json(&self, now: u64) -> Value {
+        let lock_status = self.lock_status_at(now);
+        let (data, encoding) = match &self.state.data {
+            Data::Text(text) => (text.clone(), "utf8"),
+            Data::Bytes(bytes) => (BASE64.encode(bytes), "base64"),
+        };
+        let method = match self.method {
+            NotarizationMethod::Dynamic => "dynamic",
+            NotarizationMethod::Locked => "locked",
+        };
+
+        json!({
+            "id": self.id.object_id().to_string(),
+            "method": method,
+            "state": {
+                "data": data,
+                "encoding": encoding,
+                "metadata": self.state.metadata,
+            },
+            "description": self.immutable_metadata.description,
+            "updatableMetadata": self.updatable_metadata,
+            "createdAt": iso_8601(self.immutable_metadata.created_at),
+            "lastStateChangeAt": iso_8601(self.last_state_change_at),
+            "stateVersionCount": self.state_version_count,
+            "updateLock": lock_to_json(&self.update_lock()),
+            "deleteLock": lock_to_json(&self.delete_lock()),
+            "transferLock": lock_to_json(&self.transfer_lock()),
+            "updateLocked": lock_status.is_update_locked,
+            "transferLocked": lock_status.is_transfer_locked,
+            "destroyAllowed": lock_status.is_destroy_allowed,
+        })
+    }
+
+    /// Resolves the [`ProtectionStatus`] at `now` (seconds since the Unix epoch).
+    pub(crate) fn protection_status_at(&self, now: u64) -> ProtectionStatus {
+        let actions = AvailableActions::from(&self.lock_status_at(now));
+        if actions.can_update && actions.can_transfer && actions.can_destroy {
+            return ProtectionStatus::FreelyMutable;
+        }
+
+        let earliest_expiry = self.immutable_metadata.locking.as_ref().and_then(|locking| {
+            [&locking.update_lock, &locking.delete_lock, &locking.transfer_lock]
+                .into_iter()
+                .filter_map(|lock| match lock {
+                    TimeLock::UnlockAt(unlock_time) if u64::from(*unlock_time) > now => Some(*unlock_time),
+                    _ => None,
+                })
+                .min()
+        });
+
+        match earliest_expiry {
+            Some(expires_at) => ProtectionStatus::TimeLimited { expires_at },
+            None => ProtectionStatus::FullyProtected,
+        }
+    }
+}
+
+impl fmt::Display for OnChainNotarization {
+    /// Renders a one-line summary for logs and command line tools, e.g.
+    /// `0x… (dynamic, version 3): "Service Monitor", 14 bytes of text, transfer lock until 2025-01-01T00:00:00.000Z`.
+    ///
+    /// Use the `Debug` output for all fields.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let method = match self.method {
+            NotarizationMethod::Dynamic => "dynamic",
+            NotarizationMethod::Locked => "locked",
+        };
+        write!(
+            f,
+            "{} ({method}, version {}): ",
+            self.id.object_id(),
+            self.state_version_count
+        )?;
+
+        match &self.immutable_metadata.description {
+            // Debug formatting quotes the description and keeps multi-line descriptions on one line.
+            Some(description) => write!(f, "{description:?}, ")?,
+            None => write!(f, "no description, ")?,
+        }
+
+        let kind = match &self.state.data {
+            Data::Text(_) => "text",
+            Data::Bytes(_) => "binary data",
+        };
+        write!(f, "{} bytes of {kind}, ", self.state.data.len())?;
+
+        // Locked notarizations can never be updated or transferred, dynamic ones only carry a
+        // transfer lock; the remaining lock is the one worth reporting.
+        match (&self.immutable_metadata.locking, &self.method) {
+            (None, _) => write!(f, "no locks"),
+            (Some(locking), NotarizationMethod::Dynamic) => {
+                write!(f, "transfer lock {}", lock_summary(&locking.transfer_lock))
+            }
+            (Some(locking), NotarizationMethod::Locked) => {
+                write!(f, "delete lock {}", lock_summary(&locking.delete_lock))
+            }
+        }
+    }
+}
+
+/// Renders a lock for the [`Display`](fmt::Display) output of [`OnChainNotarization`].
+fn lock_summary(lock: &TimeLock) -> String {
+    match lock {
+        TimeLock::None => "none".to_string(),
+        TimeLock::UntilDestroyed => "until destroyed".to_string(),
+        TimeLock::UnlockAt(unlock_at) => format!("until {}", iso_8601(u64::from(*unlock_at) * 1000)),
+    }
+}
+
+/// Renders a lock for [`OnChainNotarization::to_json`].
+fn lock_to_json(lock: &TimeLock) -> Value {
+    match lock {
+        TimeLock::None => json!("none"),
+        TimeLock::UntilDestroyed => json!("untilDestroyed"),
+        TimeLock::UnlockAt(unlock_at) => json!({ "unlockAt": iso_8601(u64::from(*unlock_at) * 1000) }),
+    }
+}
+
+/// Formats `timestamp_ms` (milliseconds since the Unix epoch) as an ISO-8601 date-time in UTC,
+/// e.g. `2025-01-01T00:00:00.000Z`.
+fn iso_8601(timestamp_ms: u64) -> String {
+    let millis = timestamp_ms % 1000;
+    let secs = timestamp_ms / 1000;
+    let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+    // Converts days since 1970-01-01 into a proleptic Gregorian date, counting in eras of
+    // 400 years that start on March 1st.
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days % 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{millis:03}Z",
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::fixtures::notarization;
+
+    const NOW: u32 = 1_000;
+
+    fn available_actions(notarization: &OnChainNotarization, now: u32) -> AvailableActions {
+        AvailableActions::from(&notarization.lock_status_at(now.into()))
+    }
+
+    #[test]
+    fn test_bcs_round_trip() {
+        let mut notarization = notarization(
+            NotarizationMethod::Locked,
+            Some(LockMetadata {
+                update_lock: TimeLock::UntilDestroyed,
+                delete_lock: TimeLock::UnlockAt(NOW),
+                transfer_lock: TimeLock::UntilDestroyed,
+            }),
+        );
+        notarization.state = State::from_bytes(b"valid utf-8".to_vec(), Some("metadata".to_string()));
+
+        let decoded = OnChainNotarization::from_bcs(&notarization.to_bcs().unwrap()).unwrap();
+        assert_eq!(decoded, notarization);
+    }
+
+    #[test]
+    fn test_available_actions_locked() {
+        let locked = notarization(
+            NotarizationMethod::Locked,
+            Some(LockMetadata {
+                update_lock: TimeLock::UntilDestroyed,
+                delete_lock: TimeLock::UnlockAt(NOW + 1),
+                transfer_lock: TimeLock::UntilDestroyed,
+            }),
+        );
+
+        assert_eq!(
+            available_actions(&locked, NOW),
+            AvailableActions {
+                can_update: false,
+                can_transfer: false,
+                can_destroy: false,
+            }
+        );
+        assert!(available_actions(&locked, NOW + 1).can_destroy);
+    }
+
+    #[test]
+    fn test_available_actions_dynamic_unlocked() {
+        let dynamic = notarization(NotarizationMethod::Dynamic, None);
+
+        assert_eq!(
+            available_actions(&dynamic, NOW),
+            AvailableActions {
+                can_update: true,
+                can_transfer: true,
+                can_destroy: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_available_actions_dynamic_transfer_locked() {
+        let dynamic = notarization(
+            NotarizationMethod::Dynamic,
+            Some(LockMetadata {
+                update_lock: TimeLock::None,
+                delete_lock: TimeLock::None,
+                transfer_lock: TimeLock::UnlockAt(NOW + 1),
+            }),
+        );
+
+        assert_eq!(
+            available_actions(&dynamic, NOW),
+            AvailableActions {
+                can_update: true,
+                can_transfer: false,
+                can_destroy: false,
+            }
+        );
+        assert_eq!(
+            available_actions(&dynamic, NOW + 1),
+            AvailableActions {
+                can_update: true,
+                can_transfer: true,
+                can_destroy: true,
+            }
+        );
+    }
+
+    #[test]
+    fn test_lock_status_locked() {
+        let locking = locks(TimeLock::UnlockAt(NOW + 1), TimeLock::UnlockAt(NOW + 1), TimeLock::None);
+        let locked = notarization(NotarizationMethod::Locked, locking.clone());
+
+        assert_eq!(
+            locked.lock_status_at(NOW.into()),
+            LockStatus {
+                lock_metadata: locking,
+                is_update_locked: true,
+                is_transfer_locked: false,
+                is_destroy_allowed: false,
+            }
+        );
+
+        let status = locked.lock_status_at((NOW + 1).into());
+        assert!(!status.is_update_locked);
+        assert!(status.is_destroy_allowed);
+
+        assert!(locked.is_update_locked_at(NOW.into()));
+        assert!(!locked.is_transfer_locked_at(NOW.into()));
+        assert!(!locked.is_destroy_allowed_at(NOW.into()));
+        assert!(locked.is_destroy_allowed_at((NOW + 1).into()));
+    }
+
+    fn locks(update_lock: TimeLock, delete_lock: TimeLock, transfer_lock: TimeLock) -> Option<LockMetadata> {
+        Some(LockMetadata {
+            update_lock,
+            delete_lock,
+            transfer_lock,
+        })
+    }
+
+    #[test]
+    fn test_protection_status_locked() {
+        let permanent = notarization(
+            NotarizationMethod::Locked,
+            locks(TimeLock::UntilDestroyed, TimeLock::None, TimeLock::UntilDestroyed),
+        );
+        assert_eq!(
+            permanent.protection_status_at(NOW.into()),
+            ProtectionStatus::FullyProtected
+        );
+
+        let time_limited = notarization(
+            NotarizationMethod::Locked,
+            locks(
+                TimeLock::UntilDestroyed,
+                TimeLock::UnlockAt(NOW + 10),
+                TimeLock::UntilDestroyed,
+            ),
+        );
+        assert_eq!(
+            time_limited.protection_status_at(NOW.into()),
+            ProtectionStatus::TimeLimited { expires_at: NOW + 10 }
+        );
+        // Once the delete lock expired, only the permanent locks remain.
+        assert_eq!(
+            time_limited.protection_status_at((NOW + 10).into()),
+            ProtectionStatus::FullyProtected
+        );
+    }
+
+    #[test]
+    fn test_protection_status_dynamic() {
+        let unlocked = notarization(NotarizationMethod::Dynamic, None);
+        assert_eq!(
+            unlocked.protection_status_at(NOW.into()),
+            ProtectionStatus::FreelyMutable
+        );
+
+        let transfer_locked = notarization(
+            NotarizationMethod::Dynamic,
+            locks(TimeLock::None, TimeLock::None, TimeLock::UnlockAt(NOW + 5)),
+        );
+        assert_eq!(
+            transfer_locked.protection_status_at(NOW.into()),
+            ProtectionStatus::TimeLimited { expires_at: NOW + 5 }
+        );
+        assert_eq!(
+            transfer_locked.protection_status_at((NOW + 5).into()),
+            ProtectionStatus::FreelyMutable
+        );
+
+        let never_transferable = notarization(
+            NotarizationMethod::Dynamic,
+            locks(TimeLock::None, TimeLock::None, TimeLock::UntilDestroyed),
+        );
+        assert_eq!(
+            never_transferable.protection_status_at(NOW.into()),
+            ProtectionStatus::FullyProtected
+        );
+    }
+
+    #[test]
+    fn test_anchor_fingerprint_tracks_content() {
+        let network = NetworkName::try_from("testnet").unwrap();
+        let notarization = notarization(NotarizationMethod::Dynamic, None);
+        let fingerprint = notarization.anchor_fingerprint(&network, ObjectID::ZERO);
+
+        let mut same_content = notarization.clone();
+        same_content.state = State::from_bytes(b"test".to_vec(), Some("metadata".to_string()));
+        same_content.updatable_metadata = Some("updated".to_string());
+        same_content.state_version_count = 1;
+        assert_eq!(same_content.anchor_fingerprint(&network, ObjectID::ZERO), fingerprint);
+
+        let mut changed_content = notarization.clone();
+        changed_content.state = State::from_string("changed".to_string(), None);
+        assert_ne!(
+            changed_content.anchor_fingerprint(&network, ObjectID::ZERO),
+            fingerprint
+        );
+
+        let devnet = NetworkName::try_from("devnet").unwrap();
+        assert_ne!(notarization.anchor_fingerprint(&devnet, ObjectID::ZERO), fingerprint);
+    }
+
+    #[test]
+    fn test_iso_8601() {
+        assert_eq!(iso_8601(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(iso_8601(951_782_400_000), "2000-02-29T00:00:00.000Z");
+        assert_eq!(iso_8601(1_735_689_599_999), "2024-12-31T23:59:59.999Z");
+        assert_eq!(iso_8601(1_735_689_600_000), "2025-01-01T00:00:00.000Z");
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut locked = notarization(
+            NotarizationMethod::Locked,
+            Some(LockMetadata {
+                update_lock: TimeLock::UntilDestroyed,
+                delete_lock: TimeLock::UnlockAt(NOW + 1),
+                transfer_lock: TimeLock::UntilDestroyed,
+            }),
+        );
+        locked.state = State::from_bytes(vec![0xFF, 0xD8, 0xFF], Some("photo".to_string()));
+
+        let json = locked.to_json(NOW.into());
+        assert_eq!(json["method"], "locked");
+        assert_eq!(json["state"]["data"], "/9j/");
+        assert_eq!(json["state"]["encoding"], "base64");
+        assert_eq!(json["state"]["metadata"], "photo");
+        assert_eq!(json["createdAt"], "1970-01-01T00:00:00.000Z");
+        assert_eq!(json["updateLock"], "untilDestroyed");
+        assert_eq!(json["deleteLock"]["unlockAt"], "1970-01-01T00:16:41.000Z");
+        assert_eq!(json["updateLocked"], true);
+        assert_eq!(json["transferLocked"], true);
+        assert_eq!(json["destroyAllowed"], false);
+
+        let json = notarization(NotarizationMethod::Dynamic, None).to_json(0);
+        assert_eq!(json["state"]["data"], "test");
+        assert_eq!(json["state"]["encoding"], "utf8");
+        assert_eq!(json["transferLock"], "none");
+        assert_eq!(json["destroyAllowed"], true);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut locked = notarization(
+            NotarizationMethod::Locked,
+            Some(LockMetadata {
+                update_lock: TimeLock::UntilDestroyed,
+                delete_lock: TimeLock::UnlockAt(NOW + 1),
+                transfer_lock: TimeLock::UntilDestroyed,
+            }),
+        );
+        locked.state = State::from_bytes(vec![0xFF, 0xD8, 0xFF], None);
+        locked.immutable_metadata.description = Some("Deed\ndedupe-tag:42".to_string());
+        assert_eq!(
+            locked.to_string(),
+            format!(
+                "{} (locked, version 0): \"Deed\\ndedupe-tag:42\", 3 bytes of binary data, delete lock until 1970-01-01T00:16:41.000Z",
+                locked.id.object_id()
+            )
+        );
+
+        let mut dynamic = notarization(
+            NotarizationMethod::Dynamic,
+            Some(LockMetadata {
+                update_lock: TimeLock::None,
+                delete_lock: TimeLock::None,
+                transfer_lock: TimeLock::UntilDestroyed,
+            }),
+        );
+        dynamic.state_version_count = 3;
+        assert_eq!(
+            dynamic.to_string(),
+            format!(
+                "{} (dynamic, version 3): no description, 4 bytes of text, transfer lock until destroyed",
+                dynamic.id.object_id()
+            )
+        );
+
+        let unlocked = notarization(NotarizationMethod::Dynamic, None);
+        assert!(unlocked.to_string().ends_with("no locks"));
+    }
+
+    #[test]
+    fn test_lock_accessors() {
+        let locked = notarization(
+            NotarizationMethod::Locked,
+            Some(LockMetadata {
+                update_lock: TimeLock::UntilDestroyed,
+                delete_lock: TimeLock::UnlockAt(NOW + 1),
+                transfer_lock: TimeLock::UntilDestroyed,
+            }),
+        );
+        assert_eq!(locked.update_lock(), TimeLock::UntilDestroyed);
+        assert_eq!(locked.delete_lock(), TimeLock::UnlockAt(NOW + 1));
+        assert_eq!(locked.transfer_lock(), TimeLock::UntilDestroyed);
+
+        let dynamic = notarization(NotarizationMethod::Dynamic, None);
+        assert_eq!(dynamic.update_lock(), TimeLock::None);
+        assert_eq!(dynamic.delete_lock(), TimeLock::None);
+        assert_eq!(dynamic.transfer_lock(), TimeLock::None);
+    }
+}
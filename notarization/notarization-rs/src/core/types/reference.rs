@@ -0,0 +1,120 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Notarization Reference
+//!
+//! A compact, self-describing pointer to a notarization on a specific network.
+//!
+//! ## Overview
+//!
+//! A reference bundles the network name and the object ID of a notarization into a
+//! single string, e.g. `notarization:testnet:0x1234...`. It is small enough to be
+//! embedded into a QR code or a link and carries everything a verifier needs to
+//! locate the notarized object.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::str::FromStr;
+//!
+//! use notarization::core::types::NotarizationReference;
+//!
+//! let reference = NotarizationReference::from_str(
+//!     "notarization:testnet:0x0000000000000000000000000000000000000000000000000000000000000001",
+//! )?;
+//! assert_eq!(reference.network().as_ref(), "testnet");
+//! # Ok::<(), notarization::error::Error>(())
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use iota_interaction::types::base_types::ObjectID;
+use product_common::network_name::NetworkName;
+
+use crate::error::Error;
+
+/// The scheme prefix of a serialized [`NotarizationReference`].
+const REFERENCE_SCHEME: &str = "notarization";
+
+/// A compact reference to a notarization object on a specific network.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotarizationReference {
+    network: NetworkName,
+    object_id: ObjectID,
+}
+
+impl NotarizationReference {
+    /// Creates a new reference to the notarization `object_id` on `network`.
+    pub fn new(network: NetworkName, object_id: ObjectID) -> Self {
+        Self { network, object_id }
+    }
+
+    /// Returns the name of the network the notarization lives on.
+    pub fn network(&self) -> &NetworkName {
+        &self.network
+    }
+
+    /// Returns the [`ObjectID`] of the referenced notarization.
+    pub fn object_id(&self) -> ObjectID {
+        self.object_id
+    }
+}
+
+impl fmt::Display for NotarizationReference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{REFERENCE_SCHEME}:{}:{}", self.network, self.object_id)
+    }
+}
+
+impl FromStr for NotarizationReference {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().splitn(3, ':');
+        let (Some(scheme), Some(network), Some(object_id)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(Error::InvalidArgument(format!(
+                "invalid notarization reference '{s}'; expected '{REFERENCE_SCHEME}:<network>:<object_id>'"
+            )));
+        };
+
+        if scheme != REFERENCE_SCHEME {
+            return Err(Error::InvalidArgument(format!(
+                "invalid notarization reference scheme '{scheme}'; expected '{REFERENCE_SCHEME}'"
+            )));
+        }
+
+        let network = NetworkName::try_from(network)
+            .map_err(|e| Error::InvalidArgument(format!("invalid network name '{network}' in reference: {e}")))?;
+        let object_id = ObjectID::from_str(object_id)
+            .map_err(|e| Error::InvalidArgument(format!("invalid object id '{object_id}' in reference: {e}")))?;
+
+        Ok(Self { network, object_id })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference_round_trip() {
+        let network = NetworkName::try_from("testnet").unwrap();
+        let reference = NotarizationReference::new(network, ObjectID::ZERO);
+
+        let parsed = NotarizationReference::from_str(&reference.to_string()).unwrap();
+        assert_eq!(parsed, reference);
+    }
+
+    #[test]
+    fn test_reference_invalid_scheme() {
+        let reference = format!("identity:testnet:{}", ObjectID::ZERO);
+        assert!(NotarizationReference::from_str(&reference).is_err());
+    }
+
+    #[test]
+    fn test_reference_missing_parts() {
+        assert!(NotarizationReference::from_str("notarization:testnet").is_err());
+        assert!(NotarizationReference::from_str("notarization:testnet:not-an-id").is_err());
+    }
+}
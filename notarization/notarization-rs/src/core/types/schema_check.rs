@@ -0,0 +1,141 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Schema Check
+//!
+//! Cross-validation of the BCS decoded [`OnChainNotarization`] against the JSON representation
+//! of the same object returned by the node.
+//!
+//! ## Overview
+//!
+//! The BCS layout of [`OnChainNotarization`] is hard-coded on the client side. If the Move
+//! struct changes (e.g. after a package upgrade), BCS decoding may still succeed while yielding
+//! wrong values. Comparing key fields with the node's own JSON rendering of the object detects
+//! such drift.
+
+use serde_json::Value;
+
+use super::{NotarizationMethod, OnChainNotarization};
+
+/// A field whose decoded value differs from the on-chain representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaMismatch {
+    /// The name of the Move struct field.
+    pub field: String,
+    /// The value as rendered by the node, or `<missing>` if the field is absent.
+    pub on_chain: String,
+    /// The value as decoded from BCS.
+    pub decoded: String,
+}
+
+/// The result of a schema check.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaCheck {
+    /// The fields that did not match.
+    pub mismatches: Vec<SchemaMismatch>,
+}
+
+impl SchemaCheck {
+    /// Indicates if the decoded object matches the on-chain representation.
+    pub fn is_consistent(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// Compares the key fields of `decoded` with `on_chain`, the JSON rendering of the Move
+    /// struct fields of the same object.
+    pub(crate) fn compare(on_chain: &Value, decoded: &OnChainNotarization) -> Self {
+        let mut check = Self::default();
+
+        let id = on_chain
+            .get("id")
+            .map(|id| id.get("id").unwrap_or(id))
+            .and_then(Value::as_str);
+        check.expect(
+            "id",
+            id.map(normalize_hex),
+            normalize_hex(&decoded.id.object_id().to_string()),
+        );
+
+        let method = on_chain
+            .get("method")
+            .and_then(|method| method.get("variant").unwrap_or(method).as_str().map(str::to_string));
+        let decoded_method = match decoded.method {
+            NotarizationMethod::Dynamic => "Dynamic",
+            NotarizationMethod::Locked => "Locked",
+        };
+        check.expect("method", method, decoded_method.to_string());
+
+        let version_count = on_chain.get("state_version_count").and_then(|count| match count {
+            Value::String(count) => Some(count.clone()),
+            Value::Number(count) => Some(count.to_string()),
+            _ => None,
+        });
+        check.expect(
+            "state_version_count",
+            version_count,
+            decoded.state_version_count.to_string(),
+        );
+
+        check
+    }
+
+    fn expect(&mut self, field: &str, on_chain: Option<String>, decoded: String) {
+        if on_chain.as_ref() != Some(&decoded) {
+            self.mismatches.push(SchemaMismatch {
+                field: field.to_string(),
+                on_chain: on_chain.unwrap_or_else(|| "<missing>".to_string()),
+                decoded,
+            });
+        }
+    }
+}
+
+/// Strips the `0x` prefix and leading zeros so that short and long hex forms compare equal.
+fn normalize_hex(hex: &str) -> String {
+    hex.trim_start_matches("0x").trim_start_matches('0').to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::base_types::ObjectID;
+    use serde_json::json;
+
+    use super::*;
+    use crate::core::types::fixtures;
+
+    fn notarization() -> OnChainNotarization {
+        let mut notarization = fixtures::notarization(NotarizationMethod::Dynamic, None);
+        notarization.state_version_count = 3;
+        notarization
+    }
+
+    #[test]
+    fn test_consistent_object_passes() {
+        let on_chain = json!({
+            "id": { "id": ObjectID::from_single_byte(1).to_string() },
+            "method": { "variant": "Dynamic", "fields": {} },
+            "state_version_count": "3",
+        });
+
+        assert!(SchemaCheck::compare(&on_chain, &notarization()).is_consistent());
+    }
+
+    #[test]
+    fn test_inconsistent_object_fails() {
+        let on_chain = json!({
+            "id": { "id": "0x1" },
+            "method": { "variant": "Locked", "fields": {} },
+        });
+
+        let check = SchemaCheck::compare(&on_chain, &notarization());
+        assert!(!check.is_consistent());
+
+        let fields = check
+            .mismatches
+            .iter()
+            .map(|mismatch| mismatch.field.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(fields, ["method", "state_version_count"]);
+        assert_eq!(check.mismatches[1].on_chain, "<missing>");
+    }
+}
@@ -39,19 +39,71 @@
 //! let pdf_content = vec![0x25, 0x50, 0x44, 0x46]; // PDF header
 //! let state = State::from_bytes(pdf_content, Some("Signed contract PDF".to_string()));
 //! ```
+//!
+//! ### Creating State from JSON
+//!
+//! ```rust
+//! use notarization::core::types::State;
+//! use serde_json::json;
+//!
+//! let state = State::from_json(&json!({ "version": "1.0", "enabled": true }), None)?;
+//! assert_eq!(state.data.as_text()?, r#"{"enabled":true,"version":"1.0"}"#);
+//! # Ok::<(), notarization::error::Error>(())
+//! ```
 
+use std::io::Read;
 use std::str::FromStr;
+use std::string::FromUtf8Error;
 
 use iota_interaction::ident_str;
 use iota_interaction::types::base_types::ObjectID;
 use iota_interaction::types::programmable_transaction_builder::ProgrammableTransactionBuilder;
 use iota_interaction::types::transaction::Argument;
 use iota_interaction::types::{MOVE_STDLIB_PACKAGE_ID, TypeTag};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
 
 use super::super::move_utils;
 use crate::error::Error;
 
+/// The default maximum size of the notarized data, in bytes.
+///
+/// The data is passed to the contract as a pure transaction argument, whose size is limited to
+/// 16 KiB on IOTA. The limit applies to the BCS encoding of the argument, so the ULEB128 length
+/// prefix of the data counts towards it.
+pub const DEFAULT_MAX_STATE_BYTES: usize = 16 * 1024;
+
+/// The gas budget suggested for a notarization transaction, before the storage of the state is
+/// accounted for.
+const BASE_GAS_BUDGET: u64 = 50_000_000;
+
+/// The gas budget suggested per stored byte: the storage cost of a byte at the reference storage
+/// price (76 NANOS per storage unit, 100 units per byte), with a margin of 100%.
+const GAS_BUDGET_PER_STORED_BYTE: u64 = 15_200;
+
+/// The upper bound of a suggested gas budget, i.e. the maximum gas budget of a transaction.
+pub const MAX_SUGGESTED_GAS_BUDGET: u64 = 50_000_000_000;
+
+/// Returns the gas budget suggested for a transaction that stores `stored_bytes` bytes of
+/// notarized content, capped at [`MAX_SUGGESTED_GAS_BUDGET`].
+pub fn suggested_gas_budget(stored_bytes: usize) -> u64 {
+    let stored_bytes = u64::try_from(stored_bytes).unwrap_or(u64::MAX);
+
+    stored_bytes
+        .saturating_mul(GAS_BUDGET_PER_STORED_BYTE)
+        .saturating_add(BASE_GAS_BUDGET)
+        .min(MAX_SUGGESTED_GAS_BUDGET)
+}
+
+/// The version of the cache encoding produced by [`State::to_bcs`] and
+/// [`OnChainNotarization::to_bcs`](super::OnChainNotarization::to_bcs).
+const CACHE_ENCODING_VERSION: u8 = 1;
+
+/// The size of the chunks in which streamed content is hashed.
+const HASH_CHUNK_SIZE: usize = 64 * 1024;
+
 /// Represents the state of a notarization.
 ///
 /// State encapsulates the data being notarized along with optional metadata.
@@ -84,31 +136,121 @@ impl<'de> Deserialize<'de> for Data {
     where
         D: Deserializer<'de>,
     {
-        // Handle both raw bytes and string representations from BCS
-        let bytes = Vec::<u8>::deserialize(deserializer)?;
-
-        if let Ok(text) = String::from_utf8(bytes.clone()) {
-            // Additional check: if it looks like actual text (not just valid UTF-8 bytes)
-            if text.chars().all(|c| c.is_ascii_graphic() || c.is_ascii_whitespace()) {
-                Ok(Data::Text(text))
-            } else {
-                Ok(Data::Bytes(bytes))
-            }
-        } else {
-            Ok(Data::Bytes(bytes))
+        // `vector<u8>` and `string::String` share the same BCS encoding, so the kind of data
+        // cannot be derived from the bytes. They are always decoded as bytes; readers convert
+        // them to the kind of the Move type of the state with `Data::with_type_tag`.
+        Vec::<u8>::deserialize(deserializer).map(Data::Bytes)
+    }
+}
+
+/// [`Data`] with an explicit variant tag, as used by the cache encoding.
+///
+/// Unlike the on-chain encoding of [`Data`], the kind of data is encoded explicitly instead of
+/// being taken from the Move type of the state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum TaggedData {
+    Bytes(Vec<u8>),
+    Text(String),
+}
+
+/// [`State`] with explicitly tagged data, as used by the cache encoding.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TaggedState {
+    data: TaggedData,
+    metadata: Option<String>,
+}
+
+impl From<State> for TaggedState {
+    fn from(state: State) -> Self {
+        let data = match state.data {
+            Data::Bytes(bytes) => TaggedData::Bytes(bytes),
+            Data::Text(text) => TaggedData::Text(text),
+        };
+
+        Self {
+            data,
+            metadata: state.metadata,
+        }
+    }
+}
+
+impl From<TaggedState> for State {
+    fn from(state: TaggedState) -> Self {
+        let data = match state.data {
+            TaggedData::Bytes(bytes) => Data::Bytes(bytes),
+            TaggedData::Text(text) => Data::Text(text),
+        };
+
+        Self {
+            data,
+            metadata: state.metadata,
         }
     }
 }
 
+/// Encodes `value` with BCS, prefixed with the [cache encoding version](CACHE_ENCODING_VERSION).
+pub(crate) fn encode_versioned<T: Serialize>(value: &T) -> Result<Vec<u8>, Error> {
+    let encoded = bcs::to_bytes(value).map_err(|e| Error::GenericError(format!("failed to encode with BCS: {e}")))?;
+
+    let mut bytes = Vec::with_capacity(encoded.len() + 1);
+    bytes.push(CACHE_ENCODING_VERSION);
+    bytes.extend(encoded);
+
+    Ok(bytes)
+}
+
+/// Decodes a value encoded with [`encode_versioned`].
+///
+/// Fails with [`Error::InvalidArgument`] for an unknown encoding version and with
+/// [`Error::DeserializationError`] for malformed or trailing bytes.
+pub(crate) fn decode_versioned<T: DeserializeOwned>(bytes: &[u8]) -> Result<T, Error> {
+    match bytes.split_first() {
+        Some((&CACHE_ENCODING_VERSION, encoded)) => Ok(bcs::from_bytes(encoded)?),
+        Some((version, _)) => Err(Error::InvalidArgument(format!(
+            "unsupported cache encoding version {version}, expected {CACHE_ENCODING_VERSION}"
+        ))),
+        None => Err(Error::InvalidArgument("cannot decode empty bytes".to_string())),
+    }
+}
+
 impl Data {
     /// Returns the Move type tag for this data type.
     ///
     /// Used internally for blockchain transaction construction.
     pub(crate) fn tag(&self) -> TypeTag {
         match self {
-            Data::Bytes(_) => TypeTag::Vector(Box::new(TypeTag::U8)),
-            Data::Text(_) => TypeTag::from_str(&format!("{MOVE_STDLIB_PACKAGE_ID}::string::String"))
-                .expect("should be valid type tag"),
+            Data::Bytes(_) => bytes_tag(),
+            Data::Text(_) => text_tag(),
+        }
+    }
+
+    /// Indicates if `tag` is the Move type of one of the kinds of data, i.e. `vector<u8>` or
+    /// `string::String`.
+    pub(crate) fn is_supported_type_tag(tag: &TypeTag) -> bool {
+        *tag == bytes_tag() || *tag == text_tag()
+    }
+
+    /// Converts the data to the kind of the Move type `tag` of the on-chain state.
+    ///
+    /// Bytes of a `string::String` state become [`Data::Text`], text of a `vector<u8>` state
+    /// becomes [`Data::Bytes`]. Data of any other type is returned as is.
+    pub(crate) fn with_type_tag(self, tag: &TypeTag) -> Self {
+        match self {
+            Data::Bytes(bytes) if *tag == text_tag() => match String::from_utf8(bytes) {
+                Ok(text) => Data::Text(text),
+                Err(err) => Data::Bytes(err.into_bytes()),
+            },
+            Data::Text(text) if *tag == bytes_tag() => Data::Bytes(text.into_bytes()),
+            data => data,
+        }
+    }
+
+    /// Like [`Data::with_type_tag`], but fails if the bytes of a `string::String` state are not
+    /// valid UTF-8 instead of keeping them as [`Data::Bytes`].
+    pub(crate) fn try_with_type_tag(self, tag: &TypeTag) -> Result<Self, FromUtf8Error> {
+        match self {
+            Data::Bytes(bytes) if *tag == text_tag() => String::from_utf8(bytes).map(Data::Text),
+            data => Ok(data.with_type_tag(tag)),
         }
     }
 
@@ -157,6 +299,71 @@ impl Data {
             Data::Text(data) => Ok(data),
         }
     }
+
+    /// Parses the data as JSON.
+    ///
+    /// Text is parsed as is; bytes are parsed if they contain UTF-8 encoded JSON.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the data is not valid JSON for `T`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use notarization::core::types::State;
+    /// # use notarization::error::Error;
+    /// let state = State::from_string(r#"{"version":1}"#.to_string(), None);
+    /// let value: serde_json::Value = state.data.as_json()?;
+    /// assert_eq!(value["version"], 1);
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn as_json<T: DeserializeOwned>(self) -> Result<T, Error> {
+        serde_json::from_value(self.as_json_value()?)
+            .map_err(|e| Error::GenericError(format!("Data is not valid JSON: {e}")))
+    }
+
+    /// Parses the data as an untyped JSON value, e.g. to inspect single fields.
+    ///
+    /// Text is parsed as is; bytes are parsed if they contain UTF-8 encoded JSON. Unlike
+    /// [`Data::as_json`], which converts the value returned here, the data is borrowed.
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if the data is not valid JSON.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # use notarization::core::types::State;
+    /// # use notarization::error::Error;
+    /// let state = State::from_string(r#"{"owner":{"name":"Alice"}}"#.to_string(), None);
+    /// let value = state.data.as_json_value()?;
+    /// assert_eq!(value["owner"]["name"], "Alice");
+    /// # Ok::<(), Error>(())
+    /// ```
+    pub fn as_json_value(&self) -> Result<Value, Error> {
+        let parsed = match self {
+            Data::Bytes(data) => serde_json::from_slice(data),
+            Data::Text(data) => serde_json::from_str(data),
+        };
+
+        parsed.map_err(|e| Error::GenericError(format!("Data is not valid JSON: {e}")))
+    }
+
+    /// Returns the size of the data in bytes, i.e. the number of raw bytes for [`Data::Bytes`]
+    /// and the length of the UTF-8 encoding for [`Data::Text`].
+    pub fn len(&self) -> usize {
+        match self {
+            Data::Bytes(data) => data.len(),
+            Data::Text(data) => data.len(),
+        }
+    }
+
+    /// Indicates if the data is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
 }
 
 impl State {
@@ -165,11 +372,99 @@ impl State {
         &self.data
     }
 
+    /// Checks that the data, as passed to the contract, does not exceed `max_bytes`, so that
+    /// oversized states are rejected before a transaction is submitted.
+    ///
+    /// The data is measured by its BCS encoding, i.e. including its ULEB128 length prefix.
+    pub(crate) fn ensure_max_size(&self, max_bytes: usize) -> Result<(), Error> {
+        let size = match &self.data {
+            Data::Bytes(data) => move_utils::pure_size(data),
+            Data::Text(data) => move_utils::pure_size(data),
+        };
+        if size > max_bytes {
+            return Err(Error::InvalidArgument(format!(
+                "state data is {size} bytes including its length prefix, which exceeds the maximum of {max_bytes} bytes"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of bytes the state occupies in storage, i.e. the size of the data and
+    /// of the metadata.
+    pub fn stored_bytes(&self) -> usize {
+        self.data.len() + self.metadata.as_ref().map_or(0, String::len)
+    }
+
+    /// Returns the gas budget suggested for storing this state, scaled with its
+    /// [size](Self::stored_bytes) (see [`suggested_gas_budget`]).
+    pub fn suggested_gas_budget(&self) -> u64 {
+        suggested_gas_budget(self.stored_bytes())
+    }
+
+    /// Encodes the state into a stable, versioned binary representation, e.g. for caching it
+    /// off-chain.
+    ///
+    /// Unlike the on-chain BCS layout, the encoding records whether the data is
+    /// [`Data::Bytes`] or [`Data::Text`], so [`State::from_bcs`] restores the state exactly,
+    /// whatever its content.
+    pub fn to_bcs(&self) -> Result<Vec<u8>, Error> {
+        encode_versioned(&TaggedState::from(self.clone()))
+    }
+
+    /// Decodes a state encoded with [`State::to_bcs`].
+    pub fn from_bcs(bytes: &[u8]) -> Result<Self, Error> {
+        decode_versioned::<TaggedState>(bytes).map(Self::from)
+    }
+
     /// Returns a reference to the metadata.
     pub fn metadata(&self) -> &Option<String> {
         &self.metadata
     }
 
+    /// Indicates if both states notarize the same data, ignoring their metadata.
+    ///
+    /// Unlike `==`, the metadata is not compared. The kind of data is, though: [`Data::Text`]
+    /// and [`Data::Bytes`] are never the same data, even if the bytes match, as they are stored
+    /// as different types on-chain.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use notarization::core::types::State;
+    ///
+    /// let current = State::from_string("Status: Active".to_string(), Some("v1".to_string()));
+    /// let proposed = State::from_string("Status: Active".to_string(), Some("v2".to_string()));
+    /// assert!(current.same_data(&proposed));
+    /// assert_ne!(current, proposed);
+    /// ```
+    pub fn same_data(&self, other: &State) -> bool {
+        self.data == other.data
+    }
+
+    /// Returns the SHA-256 hash of the notarized data.
+    ///
+    /// The hash is computed over the raw bytes for [`Data::Bytes`] and over the
+    /// UTF-8 bytes for [`Data::Text`]. The metadata is not part of the hash.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use notarization::core::types::State;
+    ///
+    /// let text_state = State::from_string("hello".to_string(), Some("greeting".to_string()));
+    /// let bytes_state = State::from_bytes(b"hello".to_vec(), None);
+    /// assert_eq!(text_state.content_hash(), bytes_state.content_hash());
+    /// ```
+    pub fn content_hash(&self) -> [u8; 32] {
+        let bytes = match &self.data {
+            Data::Bytes(bytes) => bytes.as_slice(),
+            Data::Text(text) => text.as_bytes(),
+        };
+
+        Sha256::digest(bytes).into()
+    }
+
     /// Creates a new state from raw bytes.
     ///
     /// Use this for binary data like files, images, or serialized content.
@@ -223,26 +518,208 @@ impl State {
         }
     }
 
+    /// Creates a new text state from UTF-8 encoded bytes.
+    ///
+    /// The state is stored as `string::String` on-chain, like a state created with
+    /// [`from_string`](State::from_string). Use [`from_bytes`](State::from_bytes) to store the
+    /// bytes as `vector<u8>` instead, whether they are valid UTF-8 or not.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::InvalidArgument`] if `data` is not valid UTF-8.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use notarization::core::types::{Data, State};
+    ///
+    /// let state = State::text_from_bytes(b"Status: Active".to_vec(), None)?;
+    /// assert_eq!(state.data, Data::Text("Status: Active".to_string()));
+    /// # Ok::<(), notarization::error::Error>(())
+    /// ```
+    pub fn text_from_bytes(data: Vec<u8>, metadata: Option<String>) -> Result<Self, Error> {
+        let text = String::from_utf8(data)
+            .map_err(|e| Error::InvalidArgument(format!("text state is not valid UTF-8: {e}")))?;
+
+        Ok(Self::from_string(text, metadata))
+    }
+
+    /// Creates a new state from a value serialized as canonical JSON.
+    ///
+    /// The JSON is stored as [`Data::Text`]. Object keys are sorted and no whitespace is
+    /// emitted, so the same logical value always produces the same notarized bytes and
+    /// thus the same [content hash](State::content_hash).
+    ///
+    /// ## Parameters
+    ///
+    /// - `value`: The value to serialize
+    /// - `metadata`: Optional description of the value
+    ///
+    /// ## Errors
+    ///
+    /// Returns an error if `value` cannot be serialized to JSON.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use notarization::core::types::State;
+    /// use serde_json::json;
+    ///
+    /// let a = State::from_json(&json!({ "b": 1, "a": 2 }), None)?;
+    /// let b = State::from_json(&json!({ "a": 2, "b": 1 }), None)?;
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    /// # Ok::<(), notarization::error::Error>(())
+    /// ```
+    pub fn from_json<T: Serialize>(value: &T, metadata: Option<String>) -> Result<Self, Error> {
+        let value = serde_json::to_value(value)
+            .map_err(|e| Error::InvalidArgument(format!("failed to serialize state to JSON: {e}")))?;
+        let json = serde_json::to_string(&canonicalize_json(value))
+            .map_err(|e| Error::InvalidArgument(format!("failed to serialize state to JSON: {e}")))?;
+
+        Ok(Self::from_string(json, metadata))
+    }
+
+    /// Creates a new state holding the SHA-256 hash of the content read from `reader`.
+    ///
+    /// The content is hashed in chunks, so memory stays bounded regardless of its size. The
+    /// state stores the 32 hash bytes, not the content itself.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// use notarization::core::types::State;
+    ///
+    /// let state = State::from_reader(&b"hello"[..], Some("greeting".to_string()))?;
+    /// assert_eq!(state.data.as_bytes()?.len(), 32);
+    /// # Ok::<(), notarization::error::Error>(())
+    /// ```
+    pub fn from_reader<R: Read>(reader: R, metadata: Option<String>) -> Result<Self, Error> {
+        let (hash, _) = hash_reader(reader)?;
+
+        Ok(Self::from_bytes(hash.to_vec(), metadata))
+    }
+
+    /// Creates a new state holding the SHA-256 hash of the file at `path`.
+    ///
+    /// The file is streamed, so multi-GB files can be notarized without loading them into
+    /// memory. A line recording the file name and size is appended to `metadata`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,ignore
+    /// use notarization::core::types::State;
+    ///
+    /// let state = State::from_file("contract.pdf", Some("Signed contract".to_string()))?;
+    /// // metadata: "Signed contract\nsha256 of contract.pdf (52817 bytes)"
+    /// ```
+    #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+    pub fn from_file(path: impl AsRef<std::path::Path>, metadata: Option<String>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)
+            .map_err(|e| Error::InvalidArgument(format!("failed to open file {}: {e}", path.display())))?;
+        let (hash, size) = hash_reader(file)?;
+
+        let file_name = path.file_name().unwrap_or(path.as_os_str()).to_string_lossy();
+        let fingerprint = format!("sha256 of {file_name} ({size} bytes)");
+        let metadata = match metadata {
+            Some(metadata) if !metadata.is_empty() => format!("{metadata}\n{fingerprint}"),
+            _ => fingerprint,
+        };
+
+        Ok(Self::from_bytes(hash.to_vec(), Some(metadata)))
+    }
+
     /// Creates a new `Argument` from the `State`.
     ///
-    /// To be used when creating a new `Notarization` object on the ledger.
-    pub(in crate::core) fn into_ptb(
-        self,
+    /// To be used when creating a new `Notarization` object on the ledger. The data is
+    /// serialized by reference, so building a transaction does not copy large states.
+    pub(in crate::core) fn build_into_ptb(
+        &self,
         ptb: &mut ProgrammableTransactionBuilder,
         package_id: ObjectID,
     ) -> Result<Argument, Error> {
-        match self.data {
-            Data::Bytes(data) => state_from_bytes(ptb, data, self.metadata, package_id),
-            Data::Text(data) => state_from_string(ptb, data, self.metadata, package_id),
+        let metadata = self.metadata.as_deref();
+        match &self.data {
+            Data::Bytes(data) => state_from_bytes(ptb, data, metadata, package_id),
+            Data::Text(data) => state_from_string(ptb, data, metadata, package_id),
+        }
+    }
+}
+
+/// Creates a text state without metadata, see [`State::from_string`].
+impl From<String> for State {
+    fn from(text: String) -> Self {
+        Self::from_string(text, None)
+    }
+}
+
+/// Creates a text state without metadata, see [`State::from_string`].
+impl From<&str> for State {
+    fn from(text: &str) -> Self {
+        Self::from_string(text.to_string(), None)
+    }
+}
+
+/// Creates a byte state without metadata, see [`State::from_bytes`].
+impl From<Vec<u8>> for State {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::from_bytes(bytes, None)
+    }
+}
+
+/// Creates a byte state without metadata, see [`State::from_bytes`].
+impl From<&[u8]> for State {
+    fn from(bytes: &[u8]) -> Self {
+        Self::from_bytes(bytes.to_vec(), None)
+    }
+}
+
+/// Streams `reader` through SHA-256, returning the hash and the number of bytes read.
+fn hash_reader<R: Read>(mut reader: R) -> Result<([u8; 32], u64), Error> {
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; HASH_CHUNK_SIZE];
+    let mut size = 0;
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(Error::InvalidArgument(format!("failed to read content to hash: {e}"))),
+        };
+        hasher.update(&buffer[..read]);
+        size += read as u64;
+    }
+
+    Ok((hasher.finalize().into(), size))
+}
+
+/// Recursively sorts the keys of all JSON objects in `value`.
+///
+/// Rebuilding the maps in key order keeps the output sorted regardless of whether
+/// `serde_json` preserves insertion order.
+fn canonicalize_json(value: Value) -> Value {
+    match value {
+        Value::Object(map) => {
+            let mut entries = map.into_iter().collect::<Vec<_>>();
+            entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(key, value)| (key, canonicalize_json(value)))
+                    .collect::<Map<_, _>>(),
+            )
         }
+        Value::Array(values) => Value::Array(values.into_iter().map(canonicalize_json).collect()),
+        value => value,
     }
 }
 
 /// Helper function to create a new state from bytes.
 fn state_from_bytes(
     ptb: &mut ProgrammableTransactionBuilder,
-    data: Vec<u8>,
-    metadata: Option<String>,
+    data: &[u8],
+    metadata: Option<&str>,
     package_id: ObjectID,
 ) -> Result<Argument, Error> {
     let data = move_utils::ptb_pure(ptb, "data", data)?;
@@ -260,8 +737,8 @@ fn state_from_bytes(
 /// Helper function to create a new state from bytes.
 fn state_from_string(
     ptb: &mut ProgrammableTransactionBuilder,
-    data: String,
-    metadata: Option<String>,
+    data: &str,
+    metadata: Option<&str>,
     package_id: ObjectID,
 ) -> Result<Argument, Error> {
     let data = move_utils::ptb_pure(ptb, "data", data)?;
@@ -275,3 +752,285 @@ fn state_from_string(
         vec![data, metadata],
     ))
 }
+
+/// The Move type tag of byte data.
+fn bytes_tag() -> TypeTag {
+    TypeTag::Vector(Box::new(TypeTag::U8))
+}
+
+/// The Move type tag of text data.
+fn text_tag() -> TypeTag {
+    TypeTag::from_str(&format!("{MOVE_STDLIB_PACKAGE_ID}::string::String")).expect("should be valid type tag")
+}
+
+#[cfg(test)]
+mod tests {
+    use iota_interaction::types::transaction::CallArg;
+    use proptest::prelude::*;
+    use serde_json::json;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Document {
+        title: String,
+        tags: Vec<String>,
+        revision: u32,
+    }
+
+    proptest! {
+        #[test]
+        fn test_bcs_round_trip_of_bytes(data in proptest::collection::vec(any::<u8>(), 0..512), metadata in proptest::option::of(".*")) {
+            let state = State::from_bytes(data, metadata);
+            prop_assert_eq!(State::from_bcs(&state.to_bcs().unwrap()).unwrap(), state);
+        }
+
+        #[test]
+        fn test_bcs_round_trip_of_text(data in ".*", metadata in proptest::option::of(".*")) {
+            let state = State::from_string(data, metadata);
+            prop_assert_eq!(State::from_bcs(&state.to_bcs().unwrap()).unwrap(), state);
+        }
+    }
+
+    #[test]
+    fn test_bcs_keeps_the_kind_of_data() {
+        // Valid UTF-8 bytes would be decoded as text from the on-chain layout.
+        let state = State::from_bytes(b"hello".to_vec(), None);
+        assert_eq!(State::from_bcs(&state.to_bcs().unwrap()).unwrap(), state);
+
+        let mut encoded = state.to_bcs().unwrap();
+        encoded[0] = CACHE_ENCODING_VERSION + 1;
+        assert!(matches!(State::from_bcs(&encoded), Err(Error::InvalidArgument(_))));
+        assert!(matches!(State::from_bcs(&[]), Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_same_data_ignores_metadata() {
+        let state = State::from_string("Status: Active".to_string(), Some("v1".to_string()));
+        assert!(state.same_data(&State::from_string(
+            "Status: Active".to_string(),
+            Some("v2".to_string())
+        )));
+        assert!(state.same_data(&State::from_string("Status: Active".to_string(), None)));
+        assert!(!state.same_data(&State::from_string("Status: Done".to_string(), Some("v1".to_string()))));
+    }
+
+    #[test]
+    fn test_same_data_distinguishes_text_from_bytes() {
+        let text = State::from_string("hello".to_string(), None);
+        let bytes = State::from_bytes(b"hello".to_vec(), None);
+        assert!(!text.same_data(&bytes));
+        assert!(!bytes.same_data(&text));
+    }
+
+    #[test]
+    fn test_conversions_keep_the_kind_of_data() {
+        for state in [State::from("hello"), State::from("hello".to_string())] {
+            assert_eq!(state.data, Data::Text("hello".to_string()));
+            assert_eq!(state.metadata, None);
+        }
+
+        for state in [State::from(b"hello".to_vec()), State::from(&b"hello"[..])] {
+            assert_eq!(state.data, Data::Bytes(b"hello".to_vec()));
+            assert_eq!(state.metadata, None);
+        }
+    }
+
+    #[test]
+    fn test_suggested_gas_budget_scales_with_size() {
+        let small = State::from_string("a".to_string(), None);
+        let large = State::from_bytes(vec![0; DEFAULT_MAX_STATE_BYTES], Some("b".repeat(100)));
+
+        assert_eq!(large.stored_bytes(), DEFAULT_MAX_STATE_BYTES + 100);
+        assert_eq!(
+            small.suggested_gas_budget(),
+            BASE_GAS_BUDGET + GAS_BUDGET_PER_STORED_BYTE
+        );
+        assert!(large.suggested_gas_budget() > small.suggested_gas_budget());
+        assert_eq!(suggested_gas_budget(usize::MAX), MAX_SUGGESTED_GAS_BUDGET);
+    }
+
+    #[test]
+    fn test_from_json_sorts_keys_recursively() {
+        let state = State::from_json(&json!({ "z": { "b": 1, "a": [{ "y": 0, "x": 1 }] }, "a": null }), None).unwrap();
+
+        assert_eq!(
+            state.data.as_text().unwrap(),
+            r#"{"a":null,"z":{"a":[{"x":1,"y":0}],"b":1}}"#
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let document = Document {
+            title: "Contract".to_string(),
+            tags: vec!["legal".to_string()],
+            revision: 2,
+        };
+
+        let state = State::from_json(&document, Some("metadata".to_string())).unwrap();
+        assert_eq!(state.data.clone().as_json::<Document>().unwrap(), document);
+
+        // JSON that was decoded as bytes can be parsed as well
+        let bytes = Data::Bytes(state.data.as_text().unwrap().into_bytes());
+        assert_eq!(bytes.as_json::<Document>().unwrap(), document);
+    }
+
+    #[test]
+    fn test_multibyte_text_is_decoded_as_text() {
+        let encoded = bcs::to_bytes("café ✓").unwrap();
+
+        let data: Data = bcs::from_bytes(&encoded).unwrap();
+        assert_eq!(data.with_type_tag(&text_tag()), Data::Text("café ✓".to_string()));
+    }
+
+    #[test]
+    fn test_ascii_bytes_stay_bytes() {
+        let encoded = bcs::to_bytes(&b"hello".to_vec()).unwrap();
+
+        // The kind of data is never inferred from the content.
+        let data: Data = bcs::from_bytes(&encoded).unwrap();
+        assert_eq!(data, Data::Bytes(b"hello".to_vec()));
+        assert_eq!(data.clone().with_type_tag(&bytes_tag()), Data::Bytes(b"hello".to_vec()));
+        assert_eq!(data.with_type_tag(&text_tag()), Data::Text("hello".to_string()));
+
+        assert!(Data::is_supported_type_tag(&bytes_tag()));
+        assert!(!Data::is_supported_type_tag(&TypeTag::U64));
+    }
+
+    #[test]
+    fn test_type_tag_decides_the_kind_of_data() {
+        let empty: Data = bcs::from_bytes(&bcs::to_bytes(&Vec::<u8>::new()).unwrap()).unwrap();
+        assert_eq!(empty.with_type_tag(&bytes_tag()), Data::Bytes(vec![]));
+
+        let text = Data::Bytes("café".as_bytes().to_vec()).with_type_tag(&text_tag());
+        assert_eq!(text, Data::Text("café".to_string()));
+
+        let bytes = Data::Text("abc".to_string()).with_type_tag(&bytes_tag());
+        assert_eq!(bytes, Data::Bytes(b"abc".to_vec()));
+
+        let binary: Data = bcs::from_bytes(&bcs::to_bytes(&vec![0xffu8, 0x00]).unwrap()).unwrap();
+        assert_eq!(binary.with_type_tag(&text_tag()), Data::Bytes(vec![0xff, 0x00]));
+    }
+
+    #[test]
+    fn test_text_from_bytes_decides_the_move_type() {
+        let text = State::text_from_bytes("café".as_bytes().to_vec(), None).unwrap();
+        assert_eq!(text.data.tag(), text_tag());
+
+        let bytes = State::from_bytes("café".as_bytes().to_vec(), None);
+        assert_eq!(bytes.data.tag(), bytes_tag());
+
+        let result = State::text_from_bytes(vec![0xff, 0x00], None);
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_invalid_utf8_text_state_is_rejected() {
+        let binary: Data = bcs::from_bytes(&bcs::to_bytes(&vec![0xffu8, 0x00]).unwrap()).unwrap();
+        assert!(binary.clone().try_with_type_tag(&text_tag()).is_err());
+        assert_eq!(
+            binary.try_with_type_tag(&bytes_tag()).unwrap(),
+            Data::Bytes(vec![0xff, 0x00])
+        );
+
+        let text = Data::Bytes("café".as_bytes().to_vec()).try_with_type_tag(&text_tag());
+        assert_eq!(text.unwrap(), Data::Text("café".to_string()));
+    }
+
+    #[test]
+    fn test_build_into_ptb_serializes_like_owned_values() {
+        let state = State::from_bytes(vec![0xFF, 0xD8, 0xFF], Some("Profile photo".to_string()));
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        state.build_into_ptb(&mut ptb, ObjectID::ZERO).unwrap();
+
+        let inputs = ptb.finish().inputs;
+        assert_eq!(
+            inputs[0],
+            CallArg::Pure(bcs::to_bytes(&vec![0xFFu8, 0xD8, 0xFF]).unwrap())
+        );
+        assert_eq!(
+            inputs[1],
+            CallArg::Pure(bcs::to_bytes(&Some("Profile photo".to_string())).unwrap())
+        );
+
+        let state = State::from_string("Status: Active".to_string(), None);
+        let mut ptb = ProgrammableTransactionBuilder::new();
+        state.build_into_ptb(&mut ptb, ObjectID::ZERO).unwrap();
+        assert_eq!(
+            ptb.finish().inputs[0],
+            CallArg::Pure(bcs::to_bytes(&"Status: Active".to_string()).unwrap())
+        );
+    }
+
+    /// Compares building a transaction from a borrowed state with cloning the state first, as
+    /// the transactions did before. Run with `cargo test --release -- --ignored --nocapture`.
+    #[test]
+    #[ignore = "benchmark"]
+    fn bench_build_into_ptb_without_clone() {
+        const ROUNDS: u32 = 20;
+        let state = State::from_bytes(vec![0x5A; 8 * 1024 * 1024], None);
+
+        let started = std::time::Instant::now();
+        for _ in 0..ROUNDS {
+            let owned = state.clone();
+            let mut ptb = ProgrammableTransactionBuilder::new();
+            owned.build_into_ptb(&mut ptb, ObjectID::ZERO).unwrap();
+        }
+        let with_clone = started.elapsed() / ROUNDS;
+
+        let started = std::time::Instant::now();
+        for _ in 0..ROUNDS {
+            let mut ptb = ProgrammableTransactionBuilder::new();
+            state.build_into_ptb(&mut ptb, ObjectID::ZERO).unwrap();
+        }
+        let borrowed = started.elapsed() / ROUNDS;
+
+        println!("8 MiB state: {with_clone:?} per build with clone, {borrowed:?} borrowed");
+    }
+
+    #[test]
+    fn test_state_size_limit() {
+        // "é" takes two bytes in UTF-8, plus one byte for the length prefix
+        let text = State::from_string("é".repeat(3), None);
+        assert_eq!(text.data.len(), 6);
+        assert!(text.ensure_max_size(7).is_ok());
+        assert!(matches!(text.ensure_max_size(6), Err(Error::InvalidArgument(_))));
+
+        // Data of exactly the maximum size exceeds it with its two byte length prefix
+        let bytes = State::from_bytes(vec![0; DEFAULT_MAX_STATE_BYTES], None);
+        assert!(bytes.ensure_max_size(DEFAULT_MAX_STATE_BYTES).is_err());
+        let bytes = State::from_bytes(vec![0; DEFAULT_MAX_STATE_BYTES - 3], None);
+        assert!(bytes.ensure_max_size(DEFAULT_MAX_STATE_BYTES).is_ok());
+    }
+
+    #[test]
+    fn test_from_reader_hashes_in_chunks() {
+        let content = vec![7u8; HASH_CHUNK_SIZE * 2 + 1];
+
+        let state = State::from_reader(content.as_slice(), None).unwrap();
+        let expected: [u8; 32] = Sha256::digest(&content).into();
+        assert_eq!(state.data, Data::Bytes(expected.to_vec()));
+
+        let (_, size) = hash_reader(content.as_slice()).unwrap();
+        assert_eq!(size, content.len() as u64);
+    }
+
+    #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+    #[test]
+    fn test_from_file_records_name_and_size() {
+        let path = std::env::temp_dir().join(format!("notarization-from-file-{}.txt", std::process::id()));
+        std::fs::write(&path, b"hello").unwrap();
+
+        let state = State::from_file(&path, Some("greeting".to_string()));
+        std::fs::remove_file(&path).unwrap();
+
+        let state = state.unwrap();
+        assert_eq!(state.content_hash(), Sha256::digest(Sha256::digest(b"hello")).into());
+        let file_name = path.file_name().unwrap().to_string_lossy();
+        assert_eq!(
+            state.metadata,
+            Some(format!("greeting\nsha256 of {file_name} (5 bytes)"))
+        );
+    }
+}
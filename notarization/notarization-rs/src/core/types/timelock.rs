@@ -16,7 +16,7 @@
 //! - `None`: The lock is not applied.
 
 use std::str::FromStr;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime};
 
 use iota_interaction::types::TypeTag;
 use iota_interaction::types::base_types::ObjectID;
@@ -25,7 +25,8 @@ use iota_interaction::types::transaction::Argument;
 use iota_interaction::{MoveType, ident_str};
 use serde::{Deserialize, Serialize};
 
-use super::super::move_utils;
+use super::super::move_utils::{self, ClockObject};
+use super::LockStatus;
 use crate::error::Error;
 
 /// Metadata containing time-based access restrictions for a notarization.
@@ -36,6 +37,25 @@ pub struct LockMetadata {
     pub transfer_lock: TimeLock,
 }
 
+impl LockMetadata {
+    /// Resolves the [`LockStatus`] of a notarization with this lock metadata at `now` (seconds
+    /// since the Unix epoch), without any network access.
+    ///
+    /// Mirrors `notarization::is_update_locked`, `is_transfer_locked` and `is_destroy_allowed`
+    /// of the Move contract: destruction is only prevented by `UnlockAt` locks that are still
+    /// active, while `UntilDestroyed` locks are lifted by the destruction itself.
+    pub fn evaluate(&self, now: u64) -> LockStatus {
+        let locks = [&self.update_lock, &self.delete_lock, &self.transfer_lock];
+
+        LockStatus {
+            lock_metadata: Some(self.clone()),
+            is_update_locked: self.update_lock.is_locked_at(now),
+            is_transfer_locked: self.transfer_lock.is_locked_at(now),
+            is_destroy_allowed: !locks.into_iter().any(|lock| lock.is_unlock_at_locked_at(now)),
+        }
+    }
+}
+
 /// Represents different types of time-based locks that can be applied to
 /// notarizations.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -65,21 +85,86 @@ impl TimeLock {
         Ok(TimeLock::UnlockAt(unlock_time))
     }
 
+    /// Returns the number of seconds until the lock is released, counting from `now` (seconds since
+    /// the Unix epoch).
+    ///
+    /// Returns `Some(0)` for an expired `UnlockAt` lock and `None` for locks that are not released
+    /// at a point in time (`None` and `UntilDestroyed`).
+    pub fn remaining_secs(&self, now: u64) -> Option<u64> {
+        match self {
+            TimeLock::UnlockAt(unlock_time) => Some(u64::from(*unlock_time).saturating_sub(now)),
+            TimeLock::UntilDestroyed | TimeLock::None => None,
+        }
+    }
+
+    /// Indicates if the lock is active at `now` (seconds since the Unix epoch).
+    ///
+    /// `UntilDestroyed` locks are always active and `None` locks never are. An `UnlockAt` lock
+    /// is active strictly before its unlock time, i.e. it is released at `now == unlock_time`,
+    /// like `timelock::is_timelocked` of the Move contract.
+    pub fn is_locked_at(&self, now: u64) -> bool {
+        match self {
+            TimeLock::UnlockAt(unlock_time) => u64::from(*unlock_time) > now,
+            TimeLock::UntilDestroyed => true,
+            TimeLock::None => false,
+        }
+    }
+
+    /// Indicates if the lock is released at `now` (seconds since the Unix epoch).
+    ///
+    /// The opposite of [`TimeLock::is_locked_at`].
+    pub fn is_unlocked_at(&self, now: u64) -> bool {
+        !self.is_locked_at(now)
+    }
+
+    /// Indicates if the lock is an `UnlockAt` lock that is active at `now`.
+    ///
+    /// Mirrors `timelock::is_timelocked_unlock_at` of the Move contract.
+    fn is_unlock_at_locked_at(&self, now: u64) -> bool {
+        match self {
+            TimeLock::UnlockAt(_) => self.is_locked_at(now),
+            _ => false,
+        }
+    }
+
     /// Creates a new `Argument` from the `TimeLock`.
     ///
     /// To be used when creating a new `Notarization` object on the ledger.
-    pub(in crate::core) fn to_ptb(&self, ptb: &mut Ptb, package_id: ObjectID) -> Result<Argument, Error> {
+    pub(in crate::core) fn to_ptb(
+        &self,
+        ptb: &mut Ptb,
+        package_id: ObjectID,
+        clock: ClockObject,
+    ) -> Result<Argument, Error> {
         match self {
-            TimeLock::UnlockAt(unlock_time) => new_unlock_at(ptb, *unlock_time, package_id),
+            TimeLock::UnlockAt(unlock_time) => new_unlock_at(ptb, *unlock_time, package_id, clock),
             TimeLock::UntilDestroyed => new_until_destroyed(ptb, package_id),
             TimeLock::None => new_none(ptb, package_id),
         }
     }
 }
 
+/// Returns the `TimeLock::UnlockAt` lock released once `duration` has passed, counting from `now`
+/// (seconds since the Unix epoch). Only whole seconds are taken into account.
+pub(crate) fn unlock_after(now: u64, duration: Duration) -> Result<TimeLock, Error> {
+    if duration.as_secs() == 0 {
+        return Err(Error::TimeLock("duration must be at least one second".to_string()));
+    }
+
+    now.checked_add(duration.as_secs())
+        .and_then(|unlock_time| u32::try_from(unlock_time).ok())
+        .map(TimeLock::UnlockAt)
+        .ok_or_else(|| Error::TimeLock(format!("unlock time {now} + {duration:?} is out of range")))
+}
+
 /// Creates a new `Argument` for the `unlock_at` function.
-pub(super) fn new_unlock_at(ptb: &mut Ptb, unlock_time: u32, package_id: ObjectID) -> Result<Argument, Error> {
-    let clock = move_utils::get_clock_ref(ptb);
+pub(super) fn new_unlock_at(
+    ptb: &mut Ptb,
+    unlock_time: u32,
+    package_id: ObjectID,
+    clock: ClockObject,
+) -> Result<Argument, Error> {
+    let clock = move_utils::get_clock_ref(ptb, clock);
     let unlock_time = move_utils::ptb_pure(ptb, "unlock_time", unlock_time)?;
 
     Ok(ptb.programmable_move_call(
@@ -118,3 +203,81 @@ impl MoveType for TimeLock {
         TypeTag::from_str(format!("{package}::timelock::TimeLock").as_str()).expect("failed to create type tag")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlock_after() {
+        assert_eq!(
+            unlock_after(1_000, Duration::from_secs(60)).unwrap(),
+            TimeLock::UnlockAt(1_060)
+        );
+        assert!(matches!(
+            unlock_after(u64::from(u32::MAX) - 1, Duration::from_secs(60)),
+            Err(Error::TimeLock(_))
+        ));
+        assert!(matches!(
+            unlock_after(1_000, Duration::from_millis(500)),
+            Err(Error::TimeLock(_))
+        ));
+    }
+
+    #[test]
+    fn test_remaining_secs() {
+        assert_eq!(TimeLock::UnlockAt(1_060).remaining_secs(1_000), Some(60));
+        assert_eq!(TimeLock::UnlockAt(1_000).remaining_secs(1_060), Some(0));
+        assert_eq!(TimeLock::UntilDestroyed.remaining_secs(1_000), None);
+        assert_eq!(TimeLock::None.remaining_secs(1_000), None);
+        // Times beyond the `u32` range of the contract are compared without truncation
+        assert_eq!(
+            TimeLock::UnlockAt(1_000).remaining_secs(u64::from(u32::MAX) + 1),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_is_locked_at_boundaries() {
+        let lock = TimeLock::UnlockAt(1_000);
+
+        assert!(lock.is_locked_at(999));
+        // Released at the unlock time, like `timelock::is_timelocked`
+        assert!(lock.is_unlocked_at(1_000));
+        assert!(lock.is_unlocked_at(1_001));
+        // Times beyond the `u32` range of the contract are compared without truncation
+        assert!(lock.is_unlocked_at(u64::from(u32::MAX) + 1_000));
+
+        assert!(TimeLock::UntilDestroyed.is_locked_at(u64::MAX));
+        assert!(TimeLock::None.is_unlocked_at(0));
+    }
+
+    #[test]
+    fn test_evaluate_lock_metadata() {
+        let metadata = LockMetadata {
+            update_lock: TimeLock::UntilDestroyed,
+            delete_lock: TimeLock::UnlockAt(1_000),
+            transfer_lock: TimeLock::UntilDestroyed,
+        };
+
+        let before = metadata.evaluate(999);
+        assert!(before.is_update_locked);
+        assert!(before.is_transfer_locked);
+        assert!(!before.is_destroy_allowed);
+        assert_eq!(before.lock_metadata, Some(metadata.clone()));
+
+        let at_unlock_time = metadata.evaluate(1_000);
+        assert!(at_unlock_time.is_update_locked);
+        assert!(at_unlock_time.is_destroy_allowed);
+
+        let dynamic = LockMetadata {
+            update_lock: TimeLock::None,
+            delete_lock: TimeLock::None,
+            transfer_lock: TimeLock::UnlockAt(1_000),
+        }
+        .evaluate(500);
+        assert!(!dynamic.is_update_locked);
+        assert!(dynamic.is_transfer_locked);
+        assert!(!dynamic.is_destroy_allowed);
+    }
+}
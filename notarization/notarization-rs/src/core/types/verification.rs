@@ -0,0 +1,240 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! # Verification
+//!
+//! Checks of a notarization against the expectations of a verifier.
+//!
+//! ## Overview
+//!
+//! [`NotarizationExpectations`] describe what a verifier expects of a notarization: the hash of
+//! the notarized content, the method, the description and the state of its locks. Evaluating them
+//! against a fetched [`OnChainNotarization`] yields a [`VerificationReport`] that lists every
+//! check with its outcome, so that failures can be shown to the user one by one.
+
+use std::fmt::Debug;
+
+use super::{LockStatus, NotarizationMethod, OnChainNotarization};
+
+/// The expectations a notarization is verified against.
+///
+/// Only the expectations that are set are checked.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NotarizationExpectations {
+    /// The expected SHA-256 [content hash](super::State::content_hash) of the state.
+    pub content_hash: Option<[u8; 32]>,
+    /// The expected notarization method.
+    pub method: Option<NotarizationMethod>,
+    /// The expected immutable description.
+    pub description: Option<String>,
+    /// Whether the notarization is expected to be locked for updates.
+    pub update_locked: Option<bool>,
+    /// Whether the notarization is expected to be locked for transfers.
+    pub transfer_locked: Option<bool>,
+    /// Whether the notarization is expected to be destroyable.
+    pub destroy_allowed: Option<bool>,
+}
+
+impl NotarizationExpectations {
+    /// Creates expectations without any checks.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expects the state to have the SHA-256 content hash `content_hash`.
+    pub fn with_content_hash(mut self, content_hash: [u8; 32]) -> Self {
+        self.content_hash = Some(content_hash);
+        self
+    }
+
+    /// Expects the notarization to use `method`.
+    pub fn with_method(mut self, method: NotarizationMethod) -> Self {
+        self.method = Some(method);
+        self
+    }
+
+    /// Expects the immutable description to be `description`.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Expects the notarization to be (or not to be) locked for updates.
+    pub fn with_update_locked(mut self, update_locked: bool) -> Self {
+        self.update_locked = Some(update_locked);
+        self
+    }
+
+    /// Expects the notarization to be (or not to be) locked for transfers.
+    pub fn with_transfer_locked(mut self, transfer_locked: bool) -> Self {
+        self.transfer_locked = Some(transfer_locked);
+        self
+    }
+
+    /// Expects the notarization to be (or not to be) destroyable.
+    pub fn with_destroy_allowed(mut self, destroy_allowed: bool) -> Self {
+        self.destroy_allowed = Some(destroy_allowed);
+        self
+    }
+}
+
+/// The outcome of a single check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationCheck {
+    /// The name of the check, e.g. `content_hash`.
+    pub name: &'static str,
+    /// Indicates if the notarization met the expectation.
+    pub passed: bool,
+    /// The expected value.
+    pub expected: String,
+    /// The actual value of the notarization.
+    pub actual: String,
+}
+
+/// The result of verifying a notarization against [`NotarizationExpectations`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VerificationReport {
+    /// The performed checks, in the order of the fields of [`NotarizationExpectations`].
+    pub checks: Vec<VerificationCheck>,
+}
+
+impl VerificationReport {
+    /// Indicates if all checks passed.
+    pub fn is_verified(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+
+    /// Returns the checks that failed.
+    pub fn failed(&self) -> impl Iterator<Item = &VerificationCheck> {
+        self.checks.iter().filter(|check| !check.passed)
+    }
+
+    /// Checks `notarization` against `expectations`, resolving its locks at `now` (seconds since
+    /// the Unix epoch).
+    pub(crate) fn evaluate(
+        notarization: &OnChainNotarization,
+        expectations: &NotarizationExpectations,
+        now: u64,
+    ) -> Self {
+        let mut report = Self::default();
+        let LockStatus {
+            is_update_locked,
+            is_transfer_locked,
+            is_destroy_allowed,
+            ..
+        } = notarization.lock_status_at(now);
+
+        if let Some(content_hash) = &expectations.content_hash {
+            report.check(
+                "content_hash",
+                hex(content_hash),
+                hex(&notarization.state.content_hash()),
+            );
+        }
+        if let Some(method) = &expectations.method {
+            report.expect("method", method, &notarization.method);
+        }
+        if let Some(description) = &expectations.description {
+            report.expect(
+                "description",
+                &Some(description),
+                &notarization.immutable_metadata.description.as_ref(),
+            );
+        }
+        if let Some(update_locked) = &expectations.update_locked {
+            report.expect("update_locked", update_locked, &is_update_locked);
+        }
+        if let Some(transfer_locked) = &expectations.transfer_locked {
+            report.expect("transfer_locked", transfer_locked, &is_transfer_locked);
+        }
+        if let Some(destroy_allowed) = &expectations.destroy_allowed {
+            report.expect("destroy_allowed", destroy_allowed, &is_destroy_allowed);
+        }
+
+        report
+    }
+
+    fn expect<T: Debug + PartialEq>(&mut self, name: &'static str, expected: &T, actual: &T) {
+        self.checks.push(VerificationCheck {
+            name,
+            passed: expected == actual,
+            expected: format!("{expected:?}"),
+            actual: format!("{actual:?}"),
+        });
+    }
+
+    fn check(&mut self, name: &'static str, expected: String, actual: String) {
+        self.checks.push(VerificationCheck {
+            name,
+            passed: expected == actual,
+            expected,
+            actual,
+        });
+    }
+}
+
+/// Formats `bytes` as lowercase hex.
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::types::{LockMetadata, State, TimeLock, fixtures};
+
+    fn notarization() -> OnChainNotarization {
+        let mut notarization = fixtures::notarization(
+            NotarizationMethod::Locked,
+            Some(LockMetadata {
+                update_lock: TimeLock::UntilDestroyed,
+                delete_lock: TimeLock::UnlockAt(100),
+                transfer_lock: TimeLock::UntilDestroyed,
+            }),
+        );
+        notarization.state = State::from_string("contract".to_string(), None);
+        notarization.immutable_metadata.description = Some("Sales contract".to_string());
+        notarization
+    }
+
+    #[test]
+    fn test_matching_expectations_pass() {
+        let notarization = notarization();
+        let expectations = NotarizationExpectations::new()
+            .with_content_hash(notarization.state.content_hash())
+            .with_method(NotarizationMethod::Locked)
+            .with_description("Sales contract")
+            .with_update_locked(true)
+            .with_destroy_allowed(false);
+
+        let report = VerificationReport::evaluate(&notarization, &expectations, 50);
+        assert_eq!(report.checks.len(), 5);
+        assert!(report.is_verified());
+    }
+
+    #[test]
+    fn test_mismatches_are_reported() {
+        let expectations = NotarizationExpectations::new()
+            .with_content_hash([0; 32])
+            .with_description("Purchase contract")
+            .with_destroy_allowed(true);
+
+        let report = VerificationReport::evaluate(&notarization(), &expectations, 50);
+        assert!(!report.is_verified());
+
+        let failed = report.failed().map(|check| check.name).collect::<Vec<_>>();
+        assert_eq!(failed, ["content_hash", "description", "destroy_allowed"]);
+
+        // Once the delete lock expired, the notarization can be destroyed.
+        let report = VerificationReport::evaluate(&notarization(), &expectations, 100);
+        assert_eq!(report.failed().count(), 2);
+    }
+
+    #[test]
+    fn test_no_expectations_verify_trivially() {
+        let report = VerificationReport::evaluate(&notarization(), &NotarizationExpectations::new(), 0);
+
+        assert!(report.checks.is_empty());
+        assert!(report.is_verified());
+    }
+}
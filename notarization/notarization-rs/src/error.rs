@@ -1,6 +1,8 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use iota_interaction::types::base_types::IotaAddress;
+
 use crate::iota_interaction_adapter::AdapterError;
 
 /// Errors that can occur when managing Notarizations
@@ -43,9 +45,307 @@ pub enum Error {
     /// Failed to get object with options
     #[error("Failed to get object with options: {0}")]
     ObjectLookup(String),
+    /// The notarization was updated concurrently.
+    #[error(
+        "version conflict: {}found state version {actual}",
+        expected.map(|expected| format!("expected state version {expected}, ")).unwrap_or_default()
+    )]
+    VersionConflict {
+        /// The state version the operation was based on, or `None` if the operation was based on
+        /// the content of the state, e.g. its content hash.
+        expected: Option<u64>,
+        /// The current state version of the notarization.
+        actual: u64,
+    },
+    /// The IOTA coins of the gas owner do not cover the gas budget.
+    #[error(
+        "insufficient gas: the coins of {owner} hold {available} NANOS{}",
+        required.map(|required| format!(", but the gas budget is {required} NANOS")).unwrap_or_default()
+    )]
+    InsufficientGas {
+        /// The owner of the coins.
+        owner: IotaAddress,
+        /// The gas budget, or `None` if it is not known, e.g. if it was to be estimated when the
+        /// transaction was built.
+        required: Option<u64>,
+        /// The balance of the coins that can be used for a gas payment.
+        available: u64,
+    },
+    /// The operation was rejected by the Move contract because the notarization is locked.
+    #[error("{operation} rejected: the notarization is locked{}", unlock_at.map(|t| format!(" until {t}")).unwrap_or_default())]
+    Locked {
+        /// The rejected operation.
+        operation: LockedOperation,
+        /// The unix timestamp (in seconds) at which the lock is released, if it is known and time based.
+        unlock_at: Option<u64>,
+    },
+    /// The operation did not complete within the configured time.
+    #[error("operation timed out after {0:?}")]
+    Timeout(std::time::Duration),
+    /// The transaction was aborted by a known assertion of the Notarization contract.
+    #[error("transaction aborted with {name} ({code}): {message}")]
+    MoveAbort {
+        /// The abort code raised by the contract.
+        code: u64,
+        /// The name of the error constant in the Move module, e.g. `ELockTimeNotSatisfied`.
+        name: &'static str,
+        /// The description of the error constant.
+        message: &'static str,
+    },
+}
+
+/// The abort codes of the Notarization contract, as `(module, code, name, message)`.
+///
+/// Must be kept in sync with the error constants of the Move modules in `notarization-move`.
+const MOVE_ABORT_CODES: [(&str, u64, &str, &str); 9] = [
+    (
+        "dynamic_notarization",
+        0,
+        "ECannotTransferLocked",
+        "cannot transfer a locked notarization",
+    ),
+    (
+        "notarization",
+        0,
+        "EUpdateWhileLocked",
+        "cannot update state while notarization is locked for updates",
+    ),
+    (
+        "notarization",
+        1,
+        "EDestroyWhileLocked",
+        "cannot destroy while notarization is locked for deletion",
+    ),
+    (
+        "notarization",
+        2,
+        "ELockTimeNotSatisfied",
+        "a lock time is not satisfied",
+    ),
+    (
+        "notarization",
+        3,
+        "EUntilDestroyedLockNotAllowed",
+        "delete lock cannot be TimeLock::UntilDestroyed",
+    ),
+    (
+        "notarization",
+        4,
+        "EDynamicNotarizationInvariants",
+        "invariants for dynamic notarization are broken by the specified notarization configuration",
+    ),
+    (
+        "notarization",
+        5,
+        "ELockedNotarizationInvariants",
+        "invariants for locked notarization are broken by the specified notarization configuration",
+    ),
+    (
+        "timelock",
+        0,
+        "EPastTimestamp",
+        "cannot create a timelock with a timestamp in the past",
+    ),
+    (
+        "timelock",
+        1,
+        "ETimelockNotExpired",
+        "cannot destroy a timelock that is still locked",
+    ),
+];
+
+/// An operation that can be rejected because of a lock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "lowercase")]
+pub enum LockedOperation {
+    /// Transferring a dynamic notarization.
+    Transfer,
+    /// Updating the state or the updatable metadata.
+    Update,
+    /// Destroying a notarization.
+    Delete,
+}
+
+impl Error {
+    /// Converts the error message of a failed transaction execution into an [`Error`].
+    ///
+    /// Move aborts raised by the lock checks of the Notarization contract are mapped to
+    /// [`Error::Locked`]:
+    ///
+    /// | Module                 | Abort code                   | Operation                    |
+    /// |------------------------|------------------------------|------------------------------|
+    /// | `dynamic_notarization` | `0` (`ECannotTransferLocked`)| [`LockedOperation::Transfer`]|
+    /// | `notarization`         | `0` (`EUpdateWhileLocked`)   | [`LockedOperation::Update`]  |
+    /// | `notarization`         | `1` (`EDestroyWhileLocked`)  | [`LockedOperation::Delete`]  |
+    /// | `timelock`             | `1` (`ETimelockNotExpired`)  | [`LockedOperation::Delete`]  |
+    ///
+    /// Any other abort of the contract is mapped to [`Error::MoveAbort`], and any other failure
+    /// is returned as [`Error::TransactionUnexpectedResponse`].
+    pub(crate) fn from_execution_failure(error: &str) -> Self {
+        let Some((module, code)) = parse_move_abort(error) else {
+            return Error::TransactionUnexpectedResponse(format!("transaction failed on-chain: {error}"));
+        };
+
+        if let Some(operation) = locked_operation(module, code) {
+            return Error::Locked {
+                operation,
+                unlock_at: None,
+            };
+        }
+
+        match MOVE_ABORT_CODES
+            .iter()
+            .find(|(abort_module, abort_code, ..)| *abort_module == module && *abort_code == code)
+        {
+            Some(&(_, code, name, message)) => Error::MoveAbort { code, name, message },
+            None => Error::TransactionUnexpectedResponse(format!("transaction failed on-chain: {error}")),
+        }
+    }
+}
+
+/// Extracts the module name and the abort code from a Move abort message such as
+/// `MoveAbort(MoveLocation { module: ModuleId { address: 0x.., name: Identifier("notarization") }, .. }, 0) in command
+/// 0`.
+fn parse_move_abort(error: &str) -> Option<(&str, u64)> {
+    let abort = error.strip_prefix("MoveAbort(")?;
+
+    let module_start = abort.find("Identifier(\"")? + "Identifier(\"".len();
+    let module = &abort[module_start..];
+    let module = &module[..module.find('"')?];
+
+    let code_start = abort.rfind("}, ")? + "}, ".len();
+    let code = &abort[code_start..];
+    let code: u64 = code[..code.find(')')?].trim().parse().ok()?;
+
+    Some((module, code))
+}
+
+/// Returns the [`LockedOperation`] rejected by the abort `code` of `module`, if it is lock related.
+fn locked_operation(module: &str, code: u64) -> Option<LockedOperation> {
+    match (module, code) {
+        ("dynamic_notarization", 0) => Some(LockedOperation::Transfer),
+        ("notarization", 0) => Some(LockedOperation::Update),
+        ("notarization", 1) | ("timelock", 1) => Some(LockedOperation::Delete),
+        _ => None,
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
 use product_common::impl_wasm_error_from;
 #[cfg(target_arch = "wasm32")]
 impl_wasm_error_from!(Error);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn abort(module: &str, code: u64) -> String {
+        format!(
+            "MoveAbort(MoveLocation {{ module: ModuleId {{ address: 0x42, name: Identifier(\"{module}\") }}, function: 9, instruction: 21, function_name: Some(\"f\") }}, {code}) in command 0"
+        )
+    }
+
+    #[test]
+    fn test_lock_aborts_map_to_locked() {
+        let cases = [
+            ("dynamic_notarization", 0, LockedOperation::Transfer),
+            ("notarization", 0, LockedOperation::Update),
+            ("notarization", 1, LockedOperation::Delete),
+            ("timelock", 1, LockedOperation::Delete),
+        ];
+
+        for (module, code, expected) in cases {
+            assert!(matches!(
+                Error::from_execution_failure(&abort(module, code)),
+                Error::Locked { operation, unlock_at: None } if operation == expected
+            ));
+        }
+    }
+
+    #[test]
+    fn test_other_failures_are_not_locked() {
+        for error in [
+            abort("notarization", 4),
+            abort("timelock", 0),
+            "InsufficientGas".to_string(),
+        ] {
+            assert!(!matches!(Error::from_execution_failure(&error), Error::Locked { .. }));
+        }
+    }
+
+    #[test]
+    fn test_known_aborts_map_to_move_abort() {
+        assert!(matches!(
+            Error::from_execution_failure(&abort("notarization", 2)),
+            Error::MoveAbort {
+                code: 2,
+                name: "ELockTimeNotSatisfied",
+                ..
+            }
+        ));
+        assert!(matches!(
+            Error::from_execution_failure(&abort("timelock", 0)),
+            Error::MoveAbort {
+                code: 0,
+                name: "EPastTimestamp",
+                ..
+            }
+        ));
+
+        for error in [
+            abort("notarization", 42),
+            abort("other_module", 2),
+            "InsufficientGas".to_string(),
+        ] {
+            assert!(matches!(
+                Error::from_execution_failure(&error),
+                Error::TransactionUnexpectedResponse(_)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_abort_codes_match_move_sources() {
+        let sources = [
+            (
+                "dynamic_notarization",
+                include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../notarization-move/sources/dynamic_notarization.move"
+                )),
+            ),
+            (
+                "notarization",
+                include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../notarization-move/sources/notarization.move"
+                )),
+            ),
+            (
+                "timelock",
+                include_str!(concat!(
+                    env!("CARGO_MANIFEST_DIR"),
+                    "/../notarization-move/sources/timelock.move"
+                )),
+            ),
+        ];
+
+        let mut constants = Vec::new();
+        for (module, source) in sources {
+            for line in source.lines() {
+                let Some(constant) = line.trim().strip_prefix("const E") else {
+                    continue;
+                };
+                let (name, code) = constant.split_once(": u64 = ").expect("error constants are u64");
+                let code: u64 = code.trim_end_matches(';').parse().expect("valid abort code");
+                constants.push((module, code, format!("E{name}")));
+            }
+        }
+
+        let table = MOVE_ABORT_CODES
+            .iter()
+            .map(|(module, code, name, _)| (*module, *code, name.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(table, constants);
+    }
+}
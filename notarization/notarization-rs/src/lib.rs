@@ -5,7 +5,7 @@ pub mod client;
 pub mod core;
 pub mod error;
 pub(crate) mod iota_interaction_adapter;
-pub(crate) mod package;
+pub mod package;
 
 pub use client::full_client::NotarizationClient;
 pub use client::read_only::NotarizationClientReadOnly;
@@ -5,6 +5,22 @@
 //!
 //! This module handles package ID resolution and registry management
 //! for the notarization Move contracts.
+//!
+//! ## Custom Deployments
+//!
+//! The registry knows the packages published on the public networks. Packages deployed to
+//! other networks can be registered up front with [`register_package`], before any client is
+//! created:
+//!
+//! ```rust,ignore
+//! # use notarization::package::{register_package, registered_packages};
+//! # use product_common::network_name::NetworkName;
+//! # async fn example(package_id: iota_interaction::types::base_types::ObjectID) -> Result<(), Box<dyn std::error::Error>> {
+//! register_package(&NetworkName::try_from("a1b2c3d4")?, package_id).await;
+//! assert!(registered_packages().await.contains(&("a1b2c3d4".to_string(), package_id)));
+//! # Ok(())
+//! # }
+//! ```
 
 #![allow(dead_code)]
 
@@ -12,7 +28,8 @@ use std::sync::LazyLock;
 
 use iota_interaction::types::base_types::ObjectID;
 use product_common::core_client::CoreClientReadOnly;
-use product_common::package_registry::PackageRegistry;
+use product_common::network_name::NetworkName;
+use product_common::package_registry::{Env, Metadata, PackageRegistry};
 use tokio::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError};
 
 use crate::error::Error;
@@ -26,6 +43,50 @@ static NOTARIZATION_PACKAGE_REGISTRY: LazyLock<RwLock<PackageRegistry>> = LazyLo
     RwLock::new(PackageRegistry::from_move_lock_content(move_lock_content).expect("Move.lock exists and it's valid"))
 });
 
+/// How the Notarization package of a client was chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageSource {
+    /// The package ID was passed when the client was created, overriding the registry (e.g.
+    /// [`NotarizationClientReadOnly::new_with_pkg_id`](crate::NotarizationClientReadOnly::new_with_pkg_id)).
+    Override,
+    /// The registry knew the package of the network.
+    Registry,
+    /// The registry did not know the network and the fallback package ID was used (e.g.
+    /// [`NotarizationClientReadOnly::with_fallback_package_id`](crate::NotarizationClientReadOnly::with_fallback_package_id)).
+    Fallback,
+}
+
+/// How the network name of a client was derived from the chain ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkResolution {
+    /// The chain ID is the one of mainnet, which is always named `iota`.
+    Mainnet,
+    /// The registry knows an alias for the chain ID, e.g. `testnet`.
+    ChainAlias,
+    /// The registry knows no alias, the chain ID serves as network name.
+    ChainId,
+}
+
+/// The package resolution of a client, for debugging which deployment it is connected to.
+///
+/// Returned by [`NotarizationClientReadOnly::package_info`](crate::NotarizationClientReadOnly::package_info).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageInfo {
+    /// The ID of the package the client calls.
+    pub package_id: ObjectID,
+    /// The IDs of all versions of the package, from the original one to `package_id`.
+    pub package_history: Vec<ObjectID>,
+    /// How `package_id` was chosen.
+    pub package_source: PackageSource,
+    /// The chain ID of the connected network, which is also the environment of the network in
+    /// the package registry.
+    pub chain_id: String,
+    /// The name of the connected network.
+    pub network: NetworkName,
+    /// How `network` was derived from `chain_id`.
+    pub network_resolution: NetworkResolution,
+}
+
 /// Returns a read lock to the package registry.
 pub(crate) async fn notarization_package_registry() -> PackageRegistryLock {
     NOTARIZATION_PACKAGE_REGISTRY.read().await
@@ -56,6 +117,32 @@ pub(crate) fn blocking_notarization_registry_mut() -> PackageRegistryLockMut {
     NOTARIZATION_PACKAGE_REGISTRY.blocking_write()
 }
 
+/// Registers `package_id` as the Notarization package of `network`, overriding any package ID
+/// known for it.
+///
+/// `network` is the chain ID of the network, as returned by
+/// [`NotarizationClientReadOnly::chain_id`](crate::NotarizationClientReadOnly::chain_id).
+/// Clients created afterwards for that network use the registered package.
+pub async fn register_package(network: &NetworkName, package_id: ObjectID) {
+    notarization_package_registry_mut()
+        .await
+        .insert_env(Env::new(network.as_ref()), Metadata::from_package_id(package_id));
+}
+
+/// Returns the chain IDs of all networks known to the registry, together with the ID of the
+/// current Notarization package on that network, ordered by chain ID.
+pub async fn registered_packages() -> Vec<(String, ObjectID)> {
+    let mut packages = notarization_package_registry()
+        .await
+        .envs()
+        .iter()
+        .filter_map(|(chain_id, history)| history.last().map(|package_id| (chain_id.clone(), *package_id)))
+        .collect::<Vec<_>>();
+    packages.sort();
+
+    packages
+}
+
 /// Returns the package ID for the notarization package.
 pub(crate) async fn notarization_package_id<C>(client: &C) -> Result<ObjectID, Error>
 where
@@ -67,3 +154,133 @@ where
         .package_id(network)
         .ok_or_else(|| Error::InvalidConfig(format!("cannot find Notarization package ID for network {network}")))
 }
+
+/// Resolves the package ID for `network` from `registry`.
+///
+/// If the registry has no information about `network` (e.g. a freshly deployed network) and a
+/// `fallback_package_id` is provided, the fallback is registered for `network` and returned.
+pub(crate) fn resolve_package_id(
+    registry: &mut PackageRegistry,
+    network: &str,
+    fallback_package_id: Option<ObjectID>,
+) -> Result<ObjectID, Error> {
+    if let Some(package_id) = registry.package_id(network) {
+        return Ok(package_id);
+    }
+
+    let package_id = fallback_package_id.ok_or_else(|| {
+        Error::InvalidConfig(format!(
+            "no information for a published `notarization` package on network {network}; try to use `NotarizationClientReadOnly::new_with_pkg_id` or `NotarizationClientReadOnly::with_fallback_package_id`"
+        ))
+    })?;
+    registry.insert_env(Env::new(network), Metadata::from_package_id(package_id));
+
+    Ok(package_id)
+}
+
+/// Returns the version chain of the package of `network` recorded in `registry`, ordered from
+/// the original package to the latest upgrade.
+///
+/// The chain always ends with `package_id`, the package that is actually used for `network`.
+pub(crate) fn package_history(registry: &PackageRegistry, network: &str, package_id: ObjectID) -> Vec<ObjectID> {
+    let mut history = registry.envs().get(network).cloned().unwrap_or_default();
+    if history.last() != Some(&package_id) {
+        history.push(package_id);
+    }
+
+    history
+}
+
+/// Returns the package that first defined the types and events of a package with the version
+/// chain `history`, falling back to `package_id` if the chain is empty.
+///
+/// Upgrades keep the identity of existing types, so objects and events of every version carry
+/// the ID of the original package, whereas the functions of all versions are called through the
+/// latest one.
+pub(crate) fn original_package_id(history: &[ObjectID], package_id: ObjectID) -> ObjectID {
+    history.first().copied().unwrap_or(package_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn registry() -> PackageRegistry {
+        let move_lock_content = include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/../notarization-move/Move.lock"));
+        PackageRegistry::from_move_lock_content(move_lock_content).expect("Move.lock exists and it's valid")
+    }
+
+    #[test]
+    fn test_resolve_unknown_network_without_fallback_fails() {
+        let mut registry = registry();
+
+        let result = resolve_package_id(&mut registry, "fresh-network", None);
+        assert!(matches!(result, Err(Error::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_resolve_unknown_network_uses_fallback() {
+        let mut registry = registry();
+        let fallback = ObjectID::from_single_byte(42);
+
+        assert!(registry.package_id("fresh-network").is_none());
+        assert_eq!(
+            resolve_package_id(&mut registry, "fresh-network", Some(fallback)).unwrap(),
+            fallback
+        );
+
+        // The fallback is registered, later lookups don't need it anymore.
+        assert_eq!(
+            resolve_package_id(&mut registry, "fresh-network", None).unwrap(),
+            fallback
+        );
+    }
+
+    #[test]
+    fn test_package_history_ends_with_the_used_package() {
+        let mut registry = registry();
+        let original = ObjectID::from_single_byte(1);
+        let upgrade = ObjectID::from_single_byte(2);
+
+        assert_eq!(package_history(&registry, "fresh-network", original), [original]);
+
+        let package_id = resolve_package_id(&mut registry, "fresh-network", Some(original)).unwrap();
+        assert_eq!(package_history(&registry, "fresh-network", package_id), [original]);
+        assert_eq!(
+            package_history(&registry, "fresh-network", upgrade),
+            [original, upgrade]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_registered_package_is_listed() {
+        let network = NetworkName::try_from("c0ffee00").unwrap();
+        let package_id = ObjectID::from_single_byte(7);
+
+        register_package(&network, package_id).await;
+
+        assert_eq!(
+            notarization_package_registry().await.package_id("c0ffee00"),
+            Some(package_id)
+        );
+        assert!(
+            registered_packages()
+                .await
+                .contains(&("c0ffee00".to_string(), package_id))
+        );
+    }
+
+    #[test]
+    fn test_types_of_upgraded_packages_resolve_to_the_original_package() {
+        let mut registry = registry();
+        let original = ObjectID::from_single_byte(1);
+        let upgrade = ObjectID::from_single_byte(2);
+        resolve_package_id(&mut registry, "fresh-network", Some(original)).unwrap();
+
+        // An object created before the upgrade keeps the type of the original package, and so
+        // do objects created after it.
+        let history = package_history(&registry, "fresh-network", upgrade);
+        assert_eq!(original_package_id(&history, upgrade), original);
+        assert_eq!(original_package_id(&[], upgrade), upgrade);
+    }
+}
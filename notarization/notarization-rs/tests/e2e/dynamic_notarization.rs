@@ -1,11 +1,29 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::{SystemTime, UNIX_EPOCH};
-
-use iota_sdk::types::base_types::IotaAddress;
-use notarization::core::types::{NotarizationMethod, State, TimeLock};
-use product_common::core_client::CoreClientReadOnly;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use iota_sdk::rpc_types::IotaTransactionBlockEffectsAPI;
+use iota_sdk::types::base_types::{IotaAddress, ObjectID};
+use iota_sdk::types::crypto::{AccountKeyPair, get_key_pair};
+use iota_sdk::types::object::Owner;
+use iota_sdk::types::transaction::{ProgrammableTransaction, Transaction as SignedTransaction};
+use iota_sdk::{IOTA_LOCAL_NETWORK_URL, IotaClientBuilder};
+use notarization::NotarizationClientReadOnly;
+use notarization::client::{Confirmation, ImportOptions, TransactionBuilderExt};
+use notarization::core::builder::NotarizationBuilder;
+use notarization::core::transactions::{
+    CreateNotarization, DestroyNotarization, TransferNotarization, UpdateState, build_programmable_transaction_bcs,
+};
+use notarization::core::types::{
+    AvailableActions, Data, NotarizationDestroyed, NotarizationMethod, NotarizationTransferred, State, TimeLock,
+};
+use notarization::error::{Error, LockedOperation};
+use notarization::package::PackageSource;
+use product_common::core_client::{CoreClient, CoreClientReadOnly};
+use product_common::network_name::NetworkName;
+use product_common::test_utils::{get_balance, request_funds};
+use product_common::transaction::transaction_builder::{Transaction, TransactionBuilder};
 
 use crate::client::get_funded_test_client;
 
@@ -93,6 +111,81 @@ async fn test_transfer_dynamic_notarization_client_with_transfer_lock_fails() ->
     Ok(())
 }
 
+#[tokio::test]
+async fn test_transfer_to_self_or_zero_address_is_rejected() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let to_self = test_client
+        .transfer_notarization(notarization_id, test_client.sender_address())
+        .build_and_execute(&test_client)
+        .await;
+    assert!(to_self.is_err(), "transfer to the owner itself should be rejected");
+
+    let to_zero = test_client
+        .transfer_notarization(notarization_id, IotaAddress::ZERO)
+        .build_and_execute(&test_client)
+        .await;
+    assert!(to_zero.is_err(), "transfer to the zero address should be rejected");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_wait_until_transfer_unlocked() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs() as u32;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .with_transfer_lock(TimeLock::UnlockAt(now + 5))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    test_client
+        .wait_until_transfer_unlocked(notarization_id, Duration::from_secs(1), Duration::from_secs(60))
+        .await?;
+    assert!(!test_client.is_transfer_locked(notarization_id).await?);
+
+    let permanently_locked_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .with_transfer_lock(TimeLock::UntilDestroyed)
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let result = test_client
+        .wait_until_transfer_unlocked(permanently_locked_id, Duration::from_secs(1), Duration::from_secs(60))
+        .await;
+    assert!(matches!(
+        result,
+        Err(Error::Locked {
+            operation: LockedOperation::Transfer,
+            unlock_at: None
+        })
+    ));
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_transfer_dynamic_notarization_client_with_no_transfer_lock_works() -> anyhow::Result<()> {
     let test_client = get_funded_test_client().await?;
@@ -122,6 +215,169 @@ async fn test_transfer_dynamic_notarization_client_with_no_transfer_lock_works()
     Ok(())
 }
 
+#[tokio::test]
+async fn test_owner_follows_a_transfer() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    assert_eq!(
+        test_client.owner(notarization_id).await?,
+        Owner::AddressOwner(test_client.sender_address())
+    );
+
+    let alice = IotaAddress::random_for_testing_only();
+    test_client
+        .transfer_notarization(notarization_id, alice)
+        .build_and_execute(&test_client)
+        .await?;
+
+    assert_eq!(test_client.owner(notarization_id).await?, Owner::AddressOwner(alice));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_with_reason_records_it_atomically() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .with_updatable_metadata("Owned by team A".to_string())
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    let alice = IotaAddress::random_for_testing_only();
+    test_client
+        .transfer_notarization_with_reason(notarization_id, alice, "Handover to alice".to_string())
+        .build_and_execute(&test_client)
+        .await?;
+
+    let metadata = test_client.updatable_metadata(notarization_id).await?;
+    assert_eq!(metadata, Some("Handover to alice".to_string()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_confirmed_checks_the_new_owner() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let alice = IotaAddress::random_for_testing_only();
+    let transferred = test_client
+        .transfer_notarization_confirmed(notarization_id, alice)
+        .await?
+        .output;
+    assert_eq!(
+        transferred,
+        NotarizationTransferred {
+            notarization_id,
+            recipient: alice,
+        }
+    );
+
+    assert_eq!(test_client.owner(notarization_id).await?, Owner::AddressOwner(alice));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_dynamic_notarization_paid_by_sponsor() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let (sponsor, sponsor_key) = get_key_pair::<AccountKeyPair>();
+    request_funds(&sponsor).await?;
+    let sponsor_balance = get_balance(sponsor).await?;
+    let sender_balance = get_balance(test_client.sender_address()).await?;
+
+    let notarization = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("sponsored".to_string(), None))
+        .finish()
+        .with_sponsorship(sponsor, |tx_data| async move {
+            let signed = SignedTransaction::from_data_and_signer(tx_data, vec![&sponsor_key]);
+            Ok(signed.tx_signatures()[0].clone())
+        })
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+
+    assert_eq!(notarization.state.data.as_text()?, "sponsored");
+    assert!(get_balance(sponsor).await? < sponsor_balance);
+    assert_eq!(get_balance(test_client.sender_address()).await?, sender_balance);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_unfunded_sponsor_is_reported_as_insufficient_gas() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let (sponsor, sponsor_key) = get_key_pair::<AccountKeyPair>();
+    let result = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("sponsored".to_string(), None))
+        .finish()
+        .with_sponsorship(sponsor, |tx_data| async move {
+            let signed = SignedTransaction::from_data_and_signer(tx_data, vec![&sponsor_key]);
+            Ok(signed.tx_signatures()[0].clone())
+        })
+        .build_and_execute(&test_client)
+        .await;
+
+    assert!(matches!(
+        result,
+        Err(Error::InsufficientGas { owner, available: 0, .. }) if owner == sponsor
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_only_client_from_shared_adapter() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("shared".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    // The package of the test network was registered by the test client.
+    let read_only = NotarizationClientReadOnly::from_adapter(test_client.client_adapter().clone()).await?;
+    assert_eq!(read_only.package_id(), test_client.package_id());
+    assert_eq!(read_only.state(notarization_id).await?.data.as_text()?, "shared");
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_update_state_dynamic_notarization() -> anyhow::Result<()> {
     let test_client = get_funded_test_client().await?;
@@ -144,6 +400,8 @@ async fn test_update_state_dynamic_notarization() -> anyhow::Result<()> {
         .await;
 
     assert!(update_result.is_ok(), "State update should succeed");
+    let updated = update_result?.output;
+    assert_eq!(updated.new_version, 1);
 
     let retrieved_state = test_client.state(*notarization_id.object_id()).await?;
     assert_eq!(retrieved_state.data.as_text()?, "updated_state");
@@ -155,6 +413,120 @@ async fn test_update_state_dynamic_notarization() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_update_state_if_version_rejects_stale_update() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("v0".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    test_client
+        .update_state_if_version(State::from_string("v1".to_string(), None), notarization_id, 0)
+        .build_and_execute(&test_client)
+        .await?;
+    assert_eq!(test_client.state_version_count(notarization_id).await?, 1);
+
+    // An update based on the initial version is stale now
+    let stale_update = test_client
+        .update_state_if_version(State::from_string("stale".to_string(), None), notarization_id, 0)
+        .build_and_execute(&test_client)
+        .await;
+    assert!(stale_update.is_err());
+
+    let state = test_client.state(notarization_id).await?;
+    assert_eq!(state.data.as_text()?, "v1");
+    assert_eq!(test_client.state_version_count(notarization_id).await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_destroy_if_state_hash_rejects_changed_state() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("v0".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+    let seen_hash = test_client.state(notarization_id).await?.content_hash();
+
+    test_client
+        .update_state(State::from_string("v1".to_string(), None), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+
+    let stale_destroy = test_client
+        .destroy_if_state_hash(notarization_id, seen_hash)
+        .build_and_execute(&test_client)
+        .await;
+    assert!(matches!(
+        stale_destroy,
+        Err(Error::VersionConflict {
+            expected: None,
+            actual: 1
+        })
+    ));
+    assert!(test_client.exists(notarization_id).await?);
+
+    let current_hash = test_client.state(notarization_id).await?.content_hash();
+    test_client
+        .destroy_if_state_hash(notarization_id, current_hash)
+        .build_and_execute(&test_client)
+        .await?;
+    assert!(!test_client.exists(notarization_id).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_state_history_reconstructs_updates() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("v0".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+    assert!(test_client.state_history(notarization_id).await?.is_empty());
+
+    let updates = [
+        State::from_string("v1".to_string(), Some("first update".to_string())),
+        State::from_string("v2 – café".to_string(), None),
+    ];
+    for update in &updates {
+        test_client
+            .update_state(update.clone(), notarization_id)
+            .build_and_execute(&test_client)
+            .await?;
+    }
+
+    let history = test_client.state_history(notarization_id).await?;
+    assert_eq!(history.len(), 2);
+    for (index, (version, update)) in history.iter().zip(&updates).enumerate() {
+        assert_eq!(version.version, index as u64 + 1);
+        assert_eq!(&version.state, update);
+        assert_eq!(version.content_hash, update.content_hash());
+    }
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_update_metadata_dynamic_notarization() -> anyhow::Result<()> {
     let test_client = get_funded_test_client().await?;
@@ -223,81 +595,181 @@ async fn test_destroy_dynamic_notarization_no_locks() -> anyhow::Result<()> {
 }
 
 #[tokio::test]
-async fn test_destroy_dynamic_notarization_with_transfer_lock_fails() -> anyhow::Result<()> {
+async fn test_destroy_reports_the_destroyed_event() -> anyhow::Result<()> {
     let test_client = get_funded_test_client().await?;
 
-    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
-    let unlock_at = now_ts + 86400;
-
-    let notarization_id = test_client
+    let notarization_id = *test_client
         .create_dynamic_notarization()
         .with_state(State::from_string("test_state".to_string(), None))
-        .with_immutable_description("Test Notarization".to_string())
-        .with_transfer_lock(TimeLock::UnlockAt(unlock_at as u32))
         .finish()
         .build_and_execute(&test_client)
         .await?
         .output
-        .id;
+        .id
+        .object_id();
 
-    let destroy_result = test_client
-        .destroy(*notarization_id.object_id())
+    let destroyed = test_client
+        .destroy(notarization_id)
         .build_and_execute(&test_client)
-        .await;
-
-    assert!(destroy_result.is_err(), "Destroy should fail when transfer is locked");
+        .await?
+        .output;
+    assert_eq!(destroyed, NotarizationDestroyed { notarization_id });
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_read_only_methods_dynamic_notarization() -> anyhow::Result<()> {
+async fn test_exists_and_is_destroyed() -> anyhow::Result<()> {
     let test_client = get_funded_test_client().await?;
 
-    let description = "Test Description".to_string();
-    let updatable_metadata = "Test Metadata".to_string();
-
     let notarization_id = test_client
         .create_dynamic_notarization()
-        .with_state(State::from_string(
-            "test_state".to_string(),
-            Some("state_meta".to_string()),
-        ))
-        .with_immutable_description(description.clone())
-        .with_updatable_metadata(updatable_metadata.clone())
+        .with_state(State::from_string("test_state".to_string(), None))
         .finish()
         .build_and_execute(&test_client)
         .await?
         .output
         .id;
+    let notarization_id = *notarization_id.object_id();
 
-    let retrieved_description = test_client.description(*notarization_id.object_id()).await?;
-    assert_eq!(retrieved_description, Some(description));
-
-    let retrieved_metadata = test_client.updatable_metadata(*notarization_id.object_id()).await?;
-    assert_eq!(retrieved_metadata, Some(updatable_metadata));
-
-    let state = test_client.state(*notarization_id.object_id()).await?;
-    assert_eq!(state.data.as_text()?, "test_state");
-    assert_eq!(state.metadata, Some("state_meta".to_string()));
-
-    let created_at = test_client.created_at_ts(*notarization_id.object_id()).await?;
-    assert!(created_at > 0);
+    assert!(test_client.exists(notarization_id).await?);
+    assert!(!test_client.is_destroyed(notarization_id).await?);
 
-    let last_change = test_client.last_state_change_ts(*notarization_id.object_id()).await?;
-    assert!(last_change > 0);
+    test_client
+        .destroy(notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
 
-    let version_count = test_client.state_version_count(*notarization_id.object_id()).await?;
-    assert_eq!(version_count, 0);
+    assert!(!test_client.exists(notarization_id).await?);
+    assert!(test_client.is_destroyed(notarization_id).await?);
 
-    let method = test_client.notarization_method(*notarization_id.object_id()).await?;
-    assert_eq!(method, NotarizationMethod::Dynamic);
+    let never_existed = ObjectID::random();
+    assert!(!test_client.exists(never_existed).await?);
+    assert!(!test_client.is_destroyed(never_existed).await?);
 
     Ok(())
 }
 
 #[tokio::test]
-async fn test_lock_checking_methods() -> anyhow::Result<()> {
+async fn test_destroy_dynamic_notarization_with_transfer_lock_fails() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let unlock_at = now_ts + 86400;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test_state".to_string(), None))
+        .with_immutable_description("Test Notarization".to_string())
+        .with_transfer_lock(TimeLock::UnlockAt(unlock_at as u32))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+
+    let destroy_result = test_client
+        .destroy(*notarization_id.object_id())
+        .build_and_execute(&test_client)
+        .await;
+
+    assert!(destroy_result.is_err(), "Destroy should fail when transfer is locked");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_destroy_batch() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let create = |transfer_lock: TimeLock| {
+        test_client
+            .create_dynamic_notarization()
+            .with_string_state("test_state".to_string(), None)
+            .with_transfer_lock(transfer_lock)
+            .finish()
+            .build_and_execute(&test_client)
+    };
+    let first = *create(TimeLock::None).await?.output.id.object_id();
+    let second = *create(TimeLock::None).await?.output.id.object_id();
+    let locked = *create(TimeLock::UnlockAt((now_ts + 86400) as u32))
+        .await?
+        .output
+        .id
+        .object_id();
+
+    // One locked notarization fails the whole batch.
+    let result = test_client
+        .destroy_batch(vec![first, locked])
+        .build_and_execute(&test_client)
+        .await;
+    assert!(result.is_err(), "a batch with a locked notarization should fail");
+    assert!(test_client.exists(first).await?);
+
+    let destroyed = test_client
+        .destroy_batch_allowed(vec![first, locked, second])
+        .await?
+        .expect("two notarizations can be destroyed")
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    assert_eq!(destroyed, vec![first, second]);
+    assert!(test_client.is_destroyed(first).await?);
+    assert!(test_client.is_destroyed(second).await?);
+    assert!(test_client.exists(locked).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_read_only_methods_dynamic_notarization() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let description = "Test Description".to_string();
+    let updatable_metadata = "Test Metadata".to_string();
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string(
+            "test_state".to_string(),
+            Some("state_meta".to_string()),
+        ))
+        .with_immutable_description(description.clone())
+        .with_updatable_metadata(updatable_metadata.clone())
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+
+    let retrieved_description = test_client.description(*notarization_id.object_id()).await?;
+    assert_eq!(retrieved_description, Some(description));
+
+    let retrieved_metadata = test_client.updatable_metadata(*notarization_id.object_id()).await?;
+    assert_eq!(retrieved_metadata, Some(updatable_metadata));
+
+    let state = test_client.state(*notarization_id.object_id()).await?;
+    assert_eq!(state.data.as_text()?, "test_state");
+    assert_eq!(state.metadata, Some("state_meta".to_string()));
+
+    let created_at = test_client.created_at_ts(*notarization_id.object_id()).await?;
+    assert!(created_at > 0);
+
+    let last_change = test_client.last_state_change_ts(*notarization_id.object_id()).await?;
+    assert!(last_change > 0);
+
+    let version_count = test_client.state_version_count(*notarization_id.object_id()).await?;
+    assert_eq!(version_count, 0);
+
+    let method = test_client.notarization_method(*notarization_id.object_id()).await?;
+    assert_eq!(method, NotarizationMethod::Dynamic);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lock_checking_methods() -> anyhow::Result<()> {
     let test_client = get_funded_test_client().await?;
 
     let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
@@ -427,3 +899,835 @@ async fn test_bytes_state_operations() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_ascii_bytes_state_is_read_as_bytes() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let data = b"printable ascii".to_vec();
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_bytes(data.clone(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    let state = test_client.state(notarization_id).await?;
+    assert_eq!(state.data, Data::Bytes(data.clone()));
+
+    let notarization = test_client.get_notarization_by_id(notarization_id).await?;
+    assert_eq!(notarization.state.data, Data::Bytes(data));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_readable_confirmation_reads_own_writes() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_string_state("initial".to_string(), None)
+        .finish()
+        .with_confirmation(Confirmation::Readable)
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+    assert_eq!(test_client.state(notarization_id).await?.data.as_text()?, "initial");
+
+    let updated = test_client
+        .update_state(State::from_string("updated".to_string(), None), notarization_id)
+        .with_confirmation(Confirmation::Readable)
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    assert_eq!(
+        test_client.state_version_count(notarization_id).await?,
+        updated.new_version
+    );
+    assert_eq!(test_client.state(notarization_id).await?.data.as_text()?, "updated");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_reads_with_custom_inspection_sender() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_string_state("inspected".to_string(), None)
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+
+    let sender = IotaAddress::random_for_testing_only();
+    let read_client = NotarizationClientReadOnly::clone(&test_client).with_inspection_sender(sender);
+    assert_eq!(read_client.inspection_sender(), sender);
+    assert_eq!(
+        read_client.state(*notarization_id.object_id()).await?.data.as_text()?,
+        "inspected"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_state_convenience_wrappers() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_string_state("initial".to_string(), None)
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    test_client
+        .update_state_string("text".to_string(), Some("text_metadata".to_string()), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+    let state = test_client.state(notarization_id).await?;
+    assert_eq!(state.data.as_text()?, "text");
+    assert_eq!(state.metadata, Some("text_metadata".to_string()));
+
+    test_client
+        .update_state_bytes(vec![1, 2, 3], None, notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+    let state = test_client.state(notarization_id).await?;
+    assert_eq!(state.data.as_bytes()?, vec![1, 2, 3]);
+    assert_eq!(state.metadata, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_available_actions_dynamic_notarization() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+
+    let actions = test_client.available_actions(*notarization_id.object_id()).await?;
+    assert_eq!(
+        actions,
+        AvailableActions {
+            can_update: true,
+            can_transfer: true,
+            can_destroy: true,
+        }
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_available_actions_dynamic_notarization_with_transfer_lock() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    // unlock at tomorrow
+    let unlock_at = now_ts + 86400;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .with_transfer_lock(TimeLock::UnlockAt(unlock_at as u32))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+
+    let actions = test_client.available_actions(*notarization_id.object_id()).await?;
+    assert_eq!(
+        actions,
+        AvailableActions {
+            can_update: true,
+            can_transfer: false,
+            can_destroy: false,
+        }
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_dynamic_notarization_with_idempotent_submission() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let onchain_notarization = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .finish()
+        .with_idempotent_submission()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+
+    let fetched = test_client
+        .get_notarization_by_id(*onchain_notarization.id.object_id())
+        .await?;
+    assert_eq!(fetched, onchain_notarization);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_json_state_round_trip() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let value = serde_json::json!({ "version": 2, "author": "alice", "tags": ["legal", "draft"] });
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_json(&value, Some("json".to_string()))?)
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let state = test_client.state_as_json::<serde_json::Value>(notarization_id).await?;
+    assert_eq!(state.data, value);
+    assert_eq!(state.metadata, Some("json".to_string()));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_state_with_custom_dispatch() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let value = serde_json::json!({ "type": "invoice", "amount": 42 });
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_json(&value, None)?)
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let raw = test_client.state_raw(notarization_id).await?;
+    let state: State<String> = bcs::from_bytes(&raw)?;
+    assert_eq!(state.data, value.to_string());
+
+    let schema = test_client
+        .state_with(notarization_id, |bytes| {
+            let state: State<String> = bcs::from_bytes(bytes)?;
+            let json: serde_json::Value =
+                serde_json::from_str(&state.data).map_err(|e| Error::InvalidArgument(e.to_string()))?;
+            Ok(json["type"].as_str().map(str::to_string))
+        })
+        .await?;
+    assert_eq!(schema.as_deref(), Some("invoice"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_destroy_expired_reaps_expired_intents() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as u32;
+
+    let expired_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("expired".to_string(), None))
+        .with_immutable_description("Session".to_string())
+        .with_auto_destroy_after(now_ts - 60)
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let pending = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("pending".to_string(), None))
+        .with_auto_destroy_after(now_ts + 86400)
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    assert_eq!(pending.immutable_metadata.auto_destroy_after(), Some(now_ts + 86400));
+
+    let destroyed = test_client.destroy_expired(test_client.sender_address()).await?;
+    assert!(destroyed.contains(&expired_id));
+    assert!(!destroyed.contains(pending.id.object_id()));
+
+    assert!(test_client.get_notarization_by_id(expired_id).await.is_err());
+    assert!(
+        test_client
+            .get_notarization_by_id(*pending.id.object_id())
+            .await
+            .is_ok()
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_estimate_gas_before_execution() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let update = UpdateState::new(State::from_string("updated".to_string(), None), notarization_id);
+    let update_estimate = test_client.estimate_gas(&update).await?;
+    assert!(update_estimate.computation_cost > 0);
+    assert!(update_estimate.storage_cost > 0);
+
+    // The estimated transaction can still be executed
+    TransactionBuilder::new(update).build_and_execute(&test_client).await?;
+    assert_eq!(test_client.state_version_count(notarization_id).await?, 1);
+
+    let destroy_estimate = test_client
+        .estimate_gas(&DestroyNotarization::new(notarization_id))
+        .await?;
+    assert!(destroy_estimate.storage_rebate > 0);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_dynamic_batch_preserves_order() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let builders = vec![
+        NotarizationBuilder::dynamic().with_string_state("Sensor A".to_string(), None),
+        NotarizationBuilder::dynamic()
+            .with_string_state("Sensor B".to_string(), None)
+            .with_transfer_lock(TimeLock::UntilDestroyed),
+    ];
+
+    let notarizations = test_client
+        .create_dynamic_batch(builders)
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+
+    assert_eq!(notarizations.len(), 2);
+    assert_eq!(notarizations[0].state.data.clone().as_text()?, "Sensor A");
+    assert_eq!(notarizations[0].immutable_metadata.locking, None);
+    assert_eq!(notarizations[1].state.data.clone().as_text()?, "Sensor B");
+    assert!(notarizations[1].immutable_metadata.locking.is_some());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_notarizations_owned_by() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+    let alice = IotaAddress::random_for_testing_only();
+
+    let mut notarization_ids = Vec::new();
+    for i in 0..2 {
+        let notarization_id = *test_client
+            .create_dynamic_notarization()
+            .with_state(State::from_string(format!("state_{i}"), None))
+            .finish()
+            .build_and_execute(&test_client)
+            .await?
+            .output
+            .id
+            .object_id();
+
+        test_client
+            .transfer_notarization(notarization_id, alice)
+            .build_and_execute(&test_client)
+            .await?;
+        notarization_ids.push(notarization_id);
+    }
+
+    let mut owned_ids = test_client
+        .get_notarizations_owned_by(alice)
+        .await?
+        .into_iter()
+        .map(|notarization| *notarization.id.object_id())
+        .collect::<Vec<_>>();
+    owned_ids.sort();
+    notarization_ids.sort();
+    assert_eq!(owned_ids, notarization_ids);
+
+    let first_page = test_client
+        .get_notarizations_owned_by_paginated(alice, None, Some(1))
+        .await?;
+    assert_eq!(first_page.data.len(), 1);
+    assert!(first_page.has_next_page);
+
+    let second_page = test_client
+        .get_notarizations_owned_by_paginated(alice, first_page.next_cursor, Some(1))
+        .await?;
+    assert_eq!(second_page.data.len(), 1);
+    assert_ne!(second_page.data[0].id, first_page.data[0].id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_transfer_locked_rejection_is_reported_as_locked() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .with_transfer_lock(TimeLock::UnlockAt((now_ts + 86400) as u32))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let transfer = TransferNotarization::new(IotaAddress::random_for_testing_only(), notarization_id);
+    let result = test_client.estimate_gas(&transfer).await;
+
+    assert!(
+        matches!(
+            result,
+            Err(Error::Locked {
+                operation: LockedOperation::Transfer,
+                ..
+            })
+        ),
+        "expected a transfer lock rejection, got {result:?}"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_lock_status_matches_individual_lock_checks() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .with_transfer_lock(TimeLock::UnlockAt((now_ts + 86400) as u32))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let lock_status = test_client.lock_status(notarization_id).await?;
+
+    assert_eq!(
+        lock_status.lock_metadata,
+        test_client.lock_metadata(notarization_id).await?
+    );
+    assert_eq!(
+        lock_status.is_update_locked,
+        test_client.is_update_locked(notarization_id).await?
+    );
+    assert_eq!(
+        lock_status.is_transfer_locked,
+        test_client.is_transfer_locked(notarization_id).await?
+    );
+    assert_eq!(
+        lock_status.is_destroy_allowed,
+        test_client.is_destroy_allowed(notarization_id).await?
+    );
+    assert!(lock_status.is_transfer_locked);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_dynamic_notarization_id_only() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .finish_id_only()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+
+    let notarization = test_client.get_notarization_by_id(notarization_id).await?;
+    assert_eq!(*notarization.id.object_id(), notarization_id);
+    assert_eq!(notarization.state.data.as_text()?, "test");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_preview_reports_lock_rejections_without_submitting() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let notarization_id = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test_state".to_string(), None))
+        .with_transfer_lock(TimeLock::UnlockAt((now_ts + 86400) as u32))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+    let notarization_id = *notarization_id.object_id();
+
+    let destroy = DestroyNotarization::new(notarization_id);
+    let effects = destroy.build_and_preview(&test_client).await?;
+    assert!(
+        !effects.status().is_ok(),
+        "Destroy should be rejected by the transfer lock"
+    );
+
+    let update = UpdateState::new(State::from_string("previewed".to_string(), None), notarization_id);
+    let effects = update.build_and_preview(&test_client).await?;
+    assert!(effects.status().is_ok(), "Update should pass the dry run");
+
+    // Previews are not submitted.
+    assert_eq!(test_client.state_version_count(notarization_id).await?, 0);
+
+    TransactionBuilder::new(update).build_and_execute(&test_client).await?;
+    assert_eq!(test_client.state_version_count(notarization_id).await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_if_absent_returns_the_existing_notarization() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let builder = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("invoice".to_string(), None))
+        .with_immutable_description("Invoice".to_string());
+
+    let first = test_client
+        .create_if_absent(builder.clone(), "invoice-42".to_string())
+        .await?;
+    assert!(first.is_created());
+    assert_eq!(first.notarization().immutable_metadata.dedupe_tag(), Some("invoice-42"));
+
+    let second = test_client.create_if_absent(builder, "invoice-42".to_string()).await?;
+    assert!(!second.is_created());
+    assert_eq!(second.notarization().id, first.notarization().id);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_update_state_if_changed_skips_identical_data() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("Status: Active".to_string(), Some("v1".to_string())))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let unchanged = State::from_string("Status: Active".to_string(), Some("v2".to_string()));
+    assert!(
+        test_client
+            .update_state_if_changed(unchanged, notarization_id)
+            .await?
+            .is_none()
+    );
+
+    let changed = State::from_string("Status: Done".to_string(), None);
+    test_client
+        .update_state_if_changed(changed, notarization_id)
+        .await?
+        .expect("the data changed")
+        .build_and_execute(&test_client)
+        .await?;
+    assert_eq!(test_client.state_version_count(notarization_id).await?, 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_state_metadata_follows_the_state() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_bytes(vec![0; 64 * 1024], Some("Snapshot 1".to_string())))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+    assert_eq!(
+        test_client.state_metadata(notarization_id).await?,
+        Some("Snapshot 1".to_string())
+    );
+
+    test_client
+        .update_state(State::from_bytes(vec![1; 64 * 1024], None), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+    assert_eq!(test_client.state_metadata(notarization_id).await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_programmable_transaction_bcs_matches_the_executed_transaction() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let update = UpdateState::new(
+        State::from_string("signed elsewhere".to_string(), None),
+        notarization_id,
+    );
+    let ptb_bytes = build_programmable_transaction_bcs(&update, &test_client).await?;
+    let ptb: ProgrammableTransaction = bcs::from_bytes(&ptb_bytes)?;
+    assert_eq!(ptb, update.build_programmable_transaction(&test_client).await?);
+
+    TransactionBuilder::new(update).build_and_execute(&test_client).await?;
+    assert_eq!(
+        test_client.state(notarization_id).await?.data.as_text()?,
+        "signed elsewhere"
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_touch_bumps_the_version_without_changing_the_state() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization = test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("still valid".to_string(), Some("audit".to_string())))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    let notarization_id = *notarization.id.object_id();
+
+    let touched = test_client
+        .touch(notarization_id)
+        .await?
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+    assert_eq!(touched.new_version, 1);
+    assert!(test_client.last_state_change_ts(notarization_id).await? >= notarization.last_state_change_at);
+    assert_eq!(test_client.state(notarization_id).await?, notarization.state);
+
+    let locked_id = *test_client
+        .create_locked_notarization()
+        .with_state(State::from_string("final".to_string(), None))
+        .with_delete_lock(TimeLock::None)
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+    assert!(matches!(
+        test_client.touch(locked_id).await,
+        Err(Error::InvalidArgument(_))
+    ));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_object_type_includes_the_state_type() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let object_type = test_client.object_type(notarization_id).await?;
+    assert!(object_type.contains("::notarization::Notarization<"));
+    assert!(object_type.ends_with("::string::String>"));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_health_check_reports_the_connected_network() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let report = test_client.health_check().await?;
+    assert_eq!(report.package_id, test_client.package_id());
+    assert_eq!(report.chain_id, test_client.chain_id());
+    assert_eq!(&report.network, test_client.network());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_offline_client_builds_creations_like_a_connected_one() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let api_endpoint = std::env::var("API_ENDPOINT").unwrap_or_else(|_| IOTA_LOCAL_NETWORK_URL.to_string());
+    let iota_client = IotaClientBuilder::default().build(&api_endpoint).await?;
+    let chain_id = NetworkName::try_from(test_client.chain_id()).expect("chain ID is a valid network name");
+    let offline = NotarizationClientReadOnly::new_offline(iota_client, chain_id, test_client.package_id());
+    assert_eq!(offline.chain_id(), test_client.chain_id());
+    assert_eq!(offline.package_id(), test_client.package_id());
+
+    let create =
+        || CreateNotarization::new(NotarizationBuilder::dynamic().with_string_state("built offline".to_string(), None));
+    assert_eq!(
+        build_programmable_transaction_bcs(&create(), &offline).await?,
+        build_programmable_transaction_bcs(&create(), &test_client).await?
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_selected_gas_coins_pay_for_a_transaction() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let result = test_client.select_gas_coins(u64::MAX).await;
+    assert!(matches!(
+        result,
+        Err(Error::InsufficientGas {
+            required: Some(u64::MAX),
+            ..
+        })
+    ));
+
+    let gas_budget = 50_000_000;
+    let gas_coins = test_client.select_gas_coins(gas_budget).await?;
+    assert!(!gas_coins.is_empty());
+
+    test_client
+        .create_dynamic_notarization()
+        .with_string_state("paid with selected coins".to_string(), None)
+        .finish()
+        .with_gas_budget(gas_budget)
+        .with_gas_payment(gas_coins)
+        .build_and_execute(&test_client)
+        .await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_import_reports_invalid_items_and_resumes() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let items = (0..5)
+        .map(|i| match i {
+            // An item without state cannot be created.
+            2 => test_client.create_dynamic_notarization(),
+            i => test_client
+                .create_dynamic_notarization()
+                .with_string_state(format!("record {i}"), None),
+        })
+        .collect::<Vec<_>>();
+
+    let mut checkpoints = Vec::new();
+    let options = ImportOptions::new().with_chunk_size(2);
+    let report = test_client
+        .import_notarizations(items.clone(), options, |checkpoint| {
+            checkpoints.push(checkpoint.next_index);
+            Ok(())
+        })
+        .await?;
+    assert_eq!(checkpoints, [2, 4, 5]);
+    assert_eq!(
+        report.created.iter().map(|(index, _)| *index).collect::<Vec<_>>(),
+        [0, 1, 3, 4]
+    );
+    assert_eq!(report.failed.len(), 1);
+    assert_eq!(report.failed[0].0, 2);
+
+    // Resuming after the second chunk only imports the last item.
+    let report = test_client
+        .import_notarizations(items, options.with_skip(4), |_| Ok(()))
+        .await?;
+    assert_eq!(report.created.iter().map(|(index, _)| *index).collect::<Vec<_>>(), [4]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_now_ts_follows_the_network_clock() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now = test_client.now_ts().await?;
+    let local_now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    assert!(
+        now.abs_diff(local_now) < 60,
+        "clock of the local network is {now}, local time is {local_now}"
+    );
+    assert!(test_client.now_ts().await? >= now);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_package_info_explains_the_resolution() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let info = test_client.package_info();
+    assert_eq!(info.package_id, test_client.package_id());
+    assert_eq!(info.package_history.last(), Some(&test_client.package_id()));
+    assert_eq!(info.package_source, PackageSource::Override);
+    assert_eq!(info.chain_id, test_client.chain_id());
+    assert_eq!(&info.network, test_client.network());
+
+    // The package passed to the test client is registered for the network from then on.
+    let read_only = NotarizationClientReadOnly::from_adapter(test_client.client_adapter().clone()).await?;
+    let info = read_only.package_info();
+    assert_eq!(info.package_id, test_client.package_id());
+    assert_eq!(info.package_source, PackageSource::Registry);
+
+    Ok(())
+}
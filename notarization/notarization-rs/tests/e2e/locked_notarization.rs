@@ -1,11 +1,15 @@
 // Copyright 2020-2025 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use iota_sdk::types::base_types::IotaAddress;
-use notarization::core::types::{NotarizationMethod, State, TimeLock};
+use notarization::core::builder::NotarizationBuilder;
+use notarization::core::transactions::{DestroyNotarization, TransferNotarization, UpdateMetadata, UpdateState};
+use notarization::core::types::{AvailableActions, NotarizationMethod, ProtectionStatus, State, TimeLock};
+use notarization::error::{Error, LockedOperation};
 use product_common::core_client::CoreClientReadOnly;
+use product_common::transaction::transaction_builder::Transaction;
 
 use crate::client::get_funded_test_client;
 
@@ -67,6 +71,35 @@ async fn create_locked_notarization_with_updatable_metadata() -> anyhow::Result<
     Ok(())
 }
 
+#[tokio::test]
+async fn create_locked_notarization_with_relative_delete_lock() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let onchain_notarization = test_client
+        .create_locked_notarization()
+        .with_state(State::from_string("test".to_string(), None))
+        .with_delete_lock_after(Duration::from_secs(3600))
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+
+    let Some(TimeLock::UnlockAt(unlock_at)) = onchain_notarization
+        .immutable_metadata
+        .locking
+        .map(|locking| locking.delete_lock)
+    else {
+        anyhow::bail!("expected the relative delete lock to be stored as an UnlockAt lock");
+    };
+
+    // The lock is resolved against the clock when the transaction is built, shortly before creation.
+    let created_at = (onchain_notarization.immutable_metadata.created_at / 1000) as u32;
+    assert!(unlock_at <= created_at + 3600);
+    assert!(unlock_at > created_at + 3600 - 60);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn create_locked_notarization_with_none_delete_lock() -> anyhow::Result<()> {
     let test_client = get_funded_test_client().await?;
@@ -341,6 +374,133 @@ async fn test_lock_checking_methods_locked_notarization() -> anyhow::Result<()>
     Ok(())
 }
 
+#[tokio::test]
+async fn test_remaining_lock_time_locked_notarization() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let unlock_at = now_ts + 86400;
+
+    let notarization_id = *test_client
+        .create_locked_notarization()
+        .with_state(State::from_string("test_state".to_string(), None))
+        .with_delete_lock(TimeLock::UnlockAt(unlock_at as u32))
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let remaining_delete = test_client
+        .remaining_delete_lock(notarization_id)
+        .await?
+        .expect("the delete lock is an UnlockAt lock");
+    assert!(remaining_delete > 0 && remaining_delete <= 86400 + 60);
+
+    // Update and transfer locks of locked notarizations are `UntilDestroyed`
+    assert_eq!(test_client.remaining_update_lock(notarization_id).await?, None);
+    assert_eq!(test_client.remaining_transfer_lock(notarization_id).await?, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_available_actions_locked_notarization() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let unlock_at = now_ts + 86400;
+
+    let locked_notarization_id = test_client
+        .create_locked_notarization()
+        .with_state(State::from_string("test_state".to_string(), None))
+        .with_delete_lock(TimeLock::UnlockAt(unlock_at as u32))
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+
+    let unlocked_notarization_id = test_client
+        .create_locked_notarization()
+        .with_state(State::from_string("test_state".to_string(), None))
+        .with_delete_lock(TimeLock::None)
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+
+    let actions = test_client
+        .available_actions(*locked_notarization_id.object_id())
+        .await?;
+    assert_eq!(
+        actions,
+        AvailableActions {
+            can_update: false,
+            can_transfer: false,
+            can_destroy: false,
+        }
+    );
+
+    let actions = test_client
+        .available_actions(*unlocked_notarization_id.object_id())
+        .await?;
+    assert_eq!(
+        actions,
+        AvailableActions {
+            can_update: false,
+            can_transfer: false,
+            can_destroy: true,
+        }
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_protection_status_locked_notarization() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let unlock_at = (now_ts + 86400) as u32;
+
+    let time_limited_id = *test_client
+        .create_locked_notarization()
+        .with_state(State::from_string("test_state".to_string(), None))
+        .with_delete_lock(TimeLock::UnlockAt(unlock_at))
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    // Update and transfer locks of locked notarizations never expire
+    let permanent_id = *test_client
+        .create_locked_notarization()
+        .with_state(State::from_string("test_state".to_string(), None))
+        .with_delete_lock(TimeLock::None)
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    assert_eq!(
+        test_client.protection_status(time_limited_id).await?,
+        ProtectionStatus::TimeLimited { expires_at: unlock_at }
+    );
+    assert_eq!(
+        test_client.protection_status(permanent_id).await?,
+        ProtectionStatus::FullyProtected
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_bytes_state_operations_locked_notarization() -> anyhow::Result<()> {
     let test_client = get_funded_test_client().await?;
@@ -447,3 +607,103 @@ async fn test_locked_notarization_different_delete_lock_times() -> anyhow::Resul
 
     Ok(())
 }
+
+#[tokio::test]
+async fn test_create_locked_batch_preserves_order() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let documents = ["invoice-1.pdf", "invoice-2.pdf", "invoice-3.pdf"];
+    let builders = documents
+        .iter()
+        .map(|document| {
+            NotarizationBuilder::locked()
+                .with_string_state(document.to_string(), None)
+                .with_delete_lock(TimeLock::None)
+        })
+        .collect();
+
+    let notarizations = test_client
+        .create_locked_batch(builders)
+        .build_and_execute(&test_client)
+        .await?
+        .output;
+
+    assert_eq!(notarizations.len(), documents.len());
+    for (notarization, document) in notarizations.iter().zip(documents) {
+        assert_eq!(notarization.method, NotarizationMethod::Locked);
+        assert_eq!(notarization.state.data.clone().as_text()?, document);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_create_empty_locked_batch_fails() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let result = test_client
+        .create_locked_batch(vec![])
+        .build_and_execute(&test_client)
+        .await;
+
+    assert!(result.is_err(), "an empty batch should be rejected");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_precheck_rejects_invalid_operations_on_locked_notarization() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let now_ts = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+    let unlock_at = now_ts + 86400;
+
+    let notarization_id = *test_client
+        .create_locked_notarization()
+        .with_state(State::from_string("test_state".to_string(), None))
+        .with_delete_lock(TimeLock::UnlockAt(unlock_at as u32))
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let update = UpdateState::new(State::from_string("updated".to_string(), None), notarization_id).with_precheck(true);
+    let result = update.build_programmable_transaction(&test_client).await;
+    assert!(
+        matches!(&result, Err(Error::InvalidArgument(message)) if message == "locked notarizations cannot be updated"),
+        "expected an early update rejection, got {result:?}"
+    );
+
+    let update_metadata = UpdateMetadata::new(Some("updated".to_string()), notarization_id).with_precheck(true);
+    let result = update_metadata.build_programmable_transaction(&test_client).await;
+    assert!(matches!(result, Err(Error::InvalidArgument(_))));
+
+    let transfer =
+        TransferNotarization::new(IotaAddress::random_for_testing_only(), notarization_id).with_precheck(true);
+    let result = transfer.build_programmable_transaction(&test_client).await;
+    assert!(
+        matches!(&result, Err(Error::InvalidArgument(message)) if message == "locked notarizations cannot be transferred"),
+        "expected an early transfer rejection, got {result:?}"
+    );
+
+    let destroy = DestroyNotarization::new(notarization_id).with_precheck(true);
+    let result = destroy.build_programmable_transaction(&test_client).await;
+    assert!(
+        matches!(
+            result,
+            Err(Error::Locked {
+                operation: LockedOperation::Delete,
+                unlock_at: Some(at)
+            }) if at == unlock_at
+        ),
+        "expected an early delete rejection, got {result:?}"
+    );
+
+    // Without the pre-check, the transaction is built and only rejected on-chain.
+    let update = UpdateState::new(State::from_string("updated".to_string(), None), notarization_id);
+    assert!(update.build_programmable_transaction(&test_client).await.is_ok());
+
+    Ok(())
+}
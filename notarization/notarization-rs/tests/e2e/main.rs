@@ -4,3 +4,4 @@
 mod client;
 mod dynamic_notarization;
 mod locked_notarization;
+mod verification;
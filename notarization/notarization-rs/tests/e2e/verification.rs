@@ -0,0 +1,189 @@
+// Copyright 2020-2025 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use notarization::NotarizationClientReadOnly;
+use notarization::core::types::{NotarizationExpectations, NotarizationMethod, State, TimeLock};
+use sha2::{Digest, Sha256};
+
+use crate::client::get_funded_test_client;
+
+#[tokio::test]
+async fn test_verify_reference_against_live_object() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let state = State::from_string("Signed diploma".to_string(), Some("PDF hash".to_string()));
+    let expected_hash = state.content_hash();
+
+    let notarization_id = test_client
+        .create_locked_notarization()
+        .with_state(state)
+        .with_immutable_description("Diploma".to_string())
+        .with_delete_lock(TimeLock::None)
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id;
+
+    let reference = test_client.reference(*notarization_id.object_id()).to_string();
+
+    assert!(NotarizationClientReadOnly::verify_reference(&reference, expected_hash).await?);
+    assert!(!NotarizationClientReadOnly::verify_reference(&reference, [0; 32]).await?);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_verify_reports_each_expectation() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let state = State::from_string("Sales contract".to_string(), None);
+    let expected_hash = state.content_hash();
+
+    let notarization_id = *test_client
+        .create_locked_notarization()
+        .with_state(state)
+        .with_immutable_description("Contract #42".to_string())
+        .with_delete_lock(TimeLock::None)
+        .finish()?
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let expectations = NotarizationExpectations::new()
+        .with_content_hash(expected_hash)
+        .with_method(NotarizationMethod::Locked)
+        .with_description("Contract #42")
+        .with_update_locked(true)
+        .with_destroy_allowed(true);
+    let report = test_client.verify(notarization_id, expectations.clone()).await?;
+    assert!(
+        report.is_verified(),
+        "unexpected failures: {:?}",
+        report.failed().collect::<Vec<_>>()
+    );
+
+    let report = test_client
+        .verify(notarization_id, expectations.with_method(NotarizationMethod::Dynamic))
+        .await?;
+    assert_eq!(report.failed().map(|check| check.name).collect::<Vec<_>>(), ["method"]);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_state_hash_matches_local_file() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let file = vec![0x25, 0x50, 0x44, 0x46, 0x2d, 0x31, 0x2e, 0x37];
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_bytes(file.clone(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let local_hash: [u8; 32] = Sha256::digest(&file).into();
+    assert_eq!(test_client.state_hash(notarization_id).await?, local_hash);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_get_notarizations_by_ids_preserves_order() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let mut ids = Vec::new();
+    for index in 0..3 {
+        let notarization = test_client
+            .create_dynamic_notarization()
+            .with_state(State::from_string(format!("state {index}"), None))
+            .finish()
+            .build_and_execute(&test_client)
+            .await?
+            .output;
+        ids.push(*notarization.id.object_id());
+    }
+
+    // A destroyed notarization is reported as missing
+    test_client.destroy(ids[1]).build_and_execute(&test_client).await?;
+
+    let notarizations = test_client.get_notarizations_by_ids(&ids).await?;
+    assert_eq!(notarizations.len(), 3);
+    assert_eq!(notarizations[0].as_ref().map(|n| *n.id.object_id()), Some(ids[0]));
+    assert!(notarizations[1].is_none());
+    assert_eq!(notarizations[2].as_ref().map(|n| *n.id.object_id()), Some(ids[2]));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_verify_reference_rejects_malformed_reference() -> anyhow::Result<()> {
+    let result = NotarizationClientReadOnly::verify_reference("not-a-reference", [0; 32]).await;
+
+    assert!(result.is_err(), "malformed references should be rejected");
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_anchor_fingerprint_changes_with_content_only() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("v1".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    let fingerprint = test_client.anchor_fingerprint(notarization_id).await?;
+    assert_eq!(test_client.anchor_fingerprint(notarization_id).await?, fingerprint);
+
+    test_client
+        .update_metadata(Some("reviewed".to_string()), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+    assert_eq!(test_client.anchor_fingerprint(notarization_id).await?, fingerprint);
+
+    test_client
+        .update_state(State::from_string("v2".to_string(), None), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+    assert_ne!(test_client.anchor_fingerprint(notarization_id).await?, fingerprint);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_self_check_consistent_object() -> anyhow::Result<()> {
+    let test_client = get_funded_test_client().await?;
+
+    let notarization_id = *test_client
+        .create_dynamic_notarization()
+        .with_state(State::from_string("v1".to_string(), None))
+        .finish()
+        .build_and_execute(&test_client)
+        .await?
+        .output
+        .id
+        .object_id();
+
+    test_client
+        .update_state(State::from_string("v2".to_string(), None), notarization_id)
+        .build_and_execute(&test_client)
+        .await?;
+
+    let check = test_client.self_check(notarization_id).await?;
+    assert!(check.is_consistent(), "unexpected schema drift: {:?}", check.mismatches);
+
+    Ok(())
+}